@@ -34,6 +34,10 @@
 
 use anyhow::{bail, Context, Result};
 use clap::{Parser, Subcommand};
+use cntryl_stress::{
+    comparison_markdown, format_benchmark_list, results_to_csv, BenchOrder, BenchmarkInfo,
+    BenchmarkList, CsvFormat, ListFormat, SuiteResult,
+};
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -59,8 +63,12 @@ automatically at runtime by each binary.
 Example:
     cargo stress                        # Run all stress tests
     cargo stress --workload 'fsync*'    # Filter by pattern
+    cargo stress --select 'disk && !slow'  # Select by tags/groups
     cargo stress --runs 5               # Multiple measurement runs
     cargo stress --list                 # List available tests
+    cargo stress --list --format json   # Machine-readable list across binaries
+    cargo stress export --format csv    # Latest results as CSV
+    cargo stress compare base.json latest.json  # Markdown comparison
 "
 )]
 struct Cli {
@@ -77,14 +85,34 @@ enum Commands {
 
 #[derive(Debug, Parser)]
 struct StressArgs {
+    #[command(subcommand)]
+    command: Option<StressCommand>,
+
     // ========================================================================
     // Test Selection
     // ========================================================================
     /// Filter benchmarks by glob pattern (e.g., "database*", "*insert*")
+    /// Patterns without wildcards match as substrings.
     /// Passed through to each stress binary.
     #[arg(long)]
     workload: Option<String>,
 
+    /// Match --workload patterns exactly
+    #[arg(long, conflicts_with = "regex")]
+    exact: bool,
+
+    /// Treat --workload patterns as regular expressions
+    #[arg(long)]
+    regex: bool,
+
+    /// Select benchmarks by tags and groups (e.g., 'disk && !slow')
+    #[arg(long)]
+    select: Option<String>,
+
+    /// Skip benchmarks matching this glob pattern (repeatable)
+    #[arg(long)]
+    exclude: Vec<String>,
+
     /// Include ignored benchmarks (those marked with #[stress_test(ignore)])
     #[arg(long)]
     include_ignored: bool,
@@ -93,6 +121,11 @@ struct StressArgs {
     #[arg(long)]
     list: bool,
 
+    /// Output format for --list: pretty, terse or json.
+    /// terse and json aggregate all binaries into one listing.
+    #[arg(long, default_value = "pretty")]
+    format: ListFormat,
+
     /// Run only a specific stress binary (filename without .rs extension)
     #[arg(long)]
     bin: Option<String>,
@@ -108,6 +141,108 @@ struct StressArgs {
     #[arg(long, default_value_t = 0)]
     warmup: usize,
 
+    /// Fail a benchmark if a single run exceeds this many seconds
+    #[arg(long)]
+    timeout: Option<u64>,
+
+    /// Apply --runs/--warmup/--timeout to every benchmark, ignoring
+    /// per-benchmark settings from #[stress_test(runs = ..)]
+    #[arg(long)]
+    force: bool,
+
+    /// Cap measurement runs for every benchmark; also the maximum in adaptive mode
+    #[arg(long)]
+    max_runs: Option<usize>,
+
+    /// Cap warmup runs for every benchmark
+    #[arg(long)]
+    max_warmup: Option<usize>,
+
+    /// Cap the per-run timeout (seconds) for every benchmark
+    #[arg(long)]
+    max_timeout: Option<u64>,
+
+    /// Keep running each benchmark until its median is stable
+    #[arg(long)]
+    adaptive: bool,
+
+    /// Minimum runs per benchmark in adaptive mode (enables adaptive mode)
+    #[arg(long)]
+    min_runs: Option<usize>,
+
+    /// Target relative confidence interval of the median (e.g. 0.02 for ±2%)
+    #[arg(long)]
+    target_ci: Option<f64>,
+
+    /// Measured-time budget per benchmark in seconds (adaptive mode)
+    #[arg(long)]
+    time_budget: Option<u64>,
+
+    /// Benchmark order within each binary: declared, name or random
+    #[arg(long)]
+    order: Option<BenchOrder>,
+
+    /// Suite seed for --order random and each benchmark's ctx.rng(),
+    /// to reproduce a previous run
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Round-robin measurement runs across benchmarks to spread drift evenly
+    #[arg(long)]
+    interleave: bool,
+
+    /// Run each benchmark in its own process (needed for kill-and-restart faults)
+    #[arg(long)]
+    isolated: bool,
+
+    /// Address space limit per benchmark, e.g. 512M or 2G (implies --isolated)
+    #[arg(long, value_name = "SIZE")]
+    memory_limit: Option<String>,
+
+    /// Number of CPU cores per benchmark (implies --isolated)
+    #[arg(long, value_name = "N")]
+    cpus: Option<usize>,
+
+    /// Open file descriptor limit per benchmark (implies --isolated)
+    #[arg(long, value_name = "N")]
+    open_files: Option<u64>,
+
+    /// Also enforce memory and CPU limits with a cgroup v2 group when writable
+    #[arg(long)]
+    cgroup: bool,
+
+    /// Pin the benchmark thread to the first of these CPUs and worker threads
+    /// to the rest, e.g. 2-5 or 0,2,4
+    #[arg(long, value_name = "CPUS")]
+    pin: Option<String>,
+
+    /// Benchmark thread priority: `nice:<N>` or `fifo:<N>` (needs CAP_SYS_NICE to raise)
+    #[arg(long, value_name = "PRIO")]
+    priority: Option<String>,
+
+    /// Repeat the selected benchmarks for this long (e.g. 30m, 8h) and fail
+    /// if run duration or RSS drifts
+    #[arg(long, value_name = "DURATION")]
+    soak: Option<String>,
+
+    /// JSONL file to append soak samples to
+    /// (default: `target/stress/<suite>/soak.jsonl`)
+    #[arg(long, value_name = "PATH")]
+    soak_output: Option<PathBuf>,
+
+    /// Largest tolerated drift in soak mode (default: 0.1 = 10%)
+    #[arg(long, value_name = "FLOAT")]
+    max_drift: Option<f64>,
+
+    /// Print heartbeat lines while a benchmark runs: auto (only on a
+    /// terminal), always or never
+    #[arg(long, value_name = "MODE")]
+    progress: Option<String>,
+
+    /// Seconds between heartbeat lines (default: 10)
+    #[arg(long, value_name = "SECS")]
+    progress_interval: Option<u64>,
+
     // ========================================================================
     // Output Control
     // ========================================================================
@@ -123,6 +258,55 @@ struct StressArgs {
     #[arg(long)]
     output_dir: Option<PathBuf>,
 
+    /// Results file name template, e.g. '{date}-{branch}-{sha}'
+    #[arg(long, value_name = "TEMPLATE")]
+    file_name: Option<String>,
+
+    /// Gzip results files (needs the gzip feature)
+    #[arg(long)]
+    compress: bool,
+
+    /// Record the host name in results
+    #[arg(long)]
+    record_hostname: bool,
+
+    /// Runs to keep per suite: a count, days such as 30d, or all
+    #[arg(long, value_name = "N|Nd|all")]
+    keep: Option<String>,
+
+    /// latest.json as a copy or a symlink to the newest run
+    #[arg(long, value_name = "MODE")]
+    latest: Option<String>,
+
+    /// Extra reporter: github, junit, csv, tsv, markdown, html, prometheus
+    /// or jsonl (repeatable; github is automatic in GitHub Actions)
+    #[arg(long = "reporter", value_name = "NAME")]
+    reporters: Vec<String>,
+
+    /// Color console output: auto (terminal without NO_COLOR), always or never
+    #[arg(long, value_name = "WHEN")]
+    color: Option<String>,
+
+    /// Console line width (default: the terminal's)
+    #[arg(long, value_name = "COLS")]
+    width: Option<usize>,
+
+    /// Throughput units: si (MB/s) or iec (MiB/s)
+    #[arg(long, value_name = "UNITS")]
+    byte_units: Option<String>,
+
+    /// Show all durations in one unit: auto, ns, us, ms or s
+    #[arg(long, value_name = "UNIT")]
+    time_unit: Option<String>,
+
+    /// Extra console columns, e.g. min,max,stddev,p99,runs
+    #[arg(long, value_name = "LIST")]
+    columns: Option<String>,
+
+    /// Console result order: none, name or duration (slowest first)
+    #[arg(long, value_name = "ORDER")]
+    sort: Option<String>,
+
     // ========================================================================
     // Regression Detection
     // ========================================================================
@@ -165,6 +349,54 @@ struct StressArgs {
     no_fail_fast: bool,
 }
 
+#[derive(Debug, Subcommand)]
+enum StressCommand {
+    /// Export results as CSV or TSV
+    Export(ExportArgs),
+    /// Compare results with a baseline as Markdown, e.g. for a PR comment
+    Compare(CompareArgs),
+}
+
+#[derive(Debug, Parser)]
+struct CompareArgs {
+    /// Baseline result JSON file
+    baseline: PathBuf,
+
+    /// Current result JSON file
+    current: PathBuf,
+
+    /// Regression threshold (default: 0.05 = 5%)
+    #[arg(long, default_value_t = 0.05)]
+    threshold: f64,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short = 'o')]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+struct ExportArgs {
+    /// Result JSON files to export (default: latest.json of every suite
+    /// in --output-dir)
+    files: Vec<PathBuf>,
+
+    /// Output format: csv or tsv
+    #[arg(long, default_value = "csv")]
+    format: CsvFormat,
+
+    /// One row per measured run instead of one per benchmark
+    #[arg(long)]
+    per_run: bool,
+
+    /// Directory holding results when no files are given
+    #[arg(long, default_value = "target/stress")]
+    output_dir: PathBuf,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short = 'o')]
+    output: Option<PathBuf>,
+}
+
 // ============================================================================
 // Discovered Stress File
 // ============================================================================
@@ -308,8 +540,74 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.cmd {
-        Commands::Stress(args) => run_stress(args),
+        Commands::Stress(mut args) => match args.command.take() {
+            Some(StressCommand::Export(export)) => run_export(export),
+            Some(StressCommand::Compare(compare)) => run_compare(compare),
+            None => run_stress(args),
+        },
+    }
+}
+
+/// Flatten saved results into CSV or TSV.
+fn run_export(args: ExportArgs) -> Result<()> {
+    let files = if args.files.is_empty() {
+        latest_results(&args.output_dir)?
+    } else {
+        args.files
+    };
+    if files.is_empty() {
+        bail!(
+            "no results found in {} (run `cargo stress` first or pass result files)",
+            args.output_dir.display()
+        );
+    }
+
+    let suites = files
+        .iter()
+        .map(|path| {
+            SuiteResult::load(path).with_context(|| format!("Failed to load {}", path.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let output = results_to_csv(&suites, args.format, args.per_run);
+
+    match args.output {
+        Some(path) => fs::write(&path, output)
+            .with_context(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{}", output),
+    }
+    Ok(())
+}
+
+/// Render a Markdown comparison of two result files.
+fn run_compare(args: CompareArgs) -> Result<()> {
+    let load = |path: &Path| {
+        SuiteResult::load(path).with_context(|| format!("Failed to load {}", path.display()))
+    };
+    let baseline = load(&args.baseline)?;
+    let current = load(&args.current)?;
+    let output = comparison_markdown(&current, Some(&baseline), args.threshold);
+
+    match args.output {
+        Some(path) => fs::write(&path, output)
+            .with_context(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{}", output),
+    }
+    Ok(())
+}
+
+/// `latest.json` of every suite in `output_dir`, sorted by suite.
+fn latest_results(output_dir: &Path) -> Result<Vec<PathBuf>> {
+    if !output_dir.is_dir() {
+        return Ok(Vec::new());
     }
+    let mut files: Vec<_> = fs::read_dir(output_dir)
+        .with_context(|| format!("Failed to read {}", output_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join("latest.json"))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    Ok(files)
 }
 
 fn run_stress(args: StressArgs) -> Result<()> {
@@ -330,6 +628,9 @@ fn run_stress(args: StressArgs) -> Result<()> {
     let stress_files = discover_stress_files(&benches_dir, &args)?;
 
     if stress_files.is_empty() {
+        if args.format != ListFormat::Pretty {
+            println!("{}", format_benchmark_list(&[], args.format));
+        }
         if verbosity.is_normal() {
             println!(
                 "⚠️  No stress test files found in {}",
@@ -366,6 +667,17 @@ fn run_stress(args: StressArgs) -> Result<()> {
         )?;
     }
 
+    // --list --format terse|json: collect from every binary into one listing
+    if args.list && args.format != ListFormat::Pretty {
+        let benchmarks = list_stress_binaries(&stress_files, &args, &temp_target_dir)?;
+        let output = format_benchmark_list(&benchmarks, args.format);
+        if !output.is_empty() {
+            println!("{}", output);
+        }
+        cleanup_temp_workspace(&temp_manifest, verbosity);
+        return Ok(());
+    }
+
     // Step 4: Run stress binaries
     let results = run_stress_binaries(&stress_files, &args, &temp_target_dir, verbosity)?;
 
@@ -390,6 +702,13 @@ fn run_stress(args: StressArgs) -> Result<()> {
     }
 
     // Cleanup temp workspace on success (leave on failure for debugging)
+    cleanup_temp_workspace(&temp_manifest, verbosity);
+
+    Ok(())
+}
+
+/// Remove the temporary workspace created for building stress binaries.
+fn cleanup_temp_workspace(temp_manifest: &Path, verbosity: Verbosity) {
     let temp_root = temp_manifest.parent().unwrap();
     if let Err(e) = fs::remove_dir_all(temp_root) {
         if verbosity.is_verbose() {
@@ -400,8 +719,6 @@ fn run_stress(args: StressArgs) -> Result<()> {
             );
         }
     }
-
-    Ok(())
 }
 
 // ============================================================================
@@ -417,7 +734,8 @@ enum Verbosity {
 
 impl Verbosity {
     fn from_args(args: &StressArgs) -> Self {
-        if args.quiet {
+        // Machine-readable listings own stdout
+        if args.quiet || (args.list && args.format != ListFormat::Pretty) {
             Verbosity::Quiet
         } else if args.verbose {
             Verbosity::Verbose
@@ -668,6 +986,58 @@ fn run_stress_binaries(
     Ok(results)
 }
 
+/// Ask every stress binary for its benchmarks and merge the listings.
+fn list_stress_binaries(
+    files: &[StressFile],
+    args: &StressArgs,
+    target_dir_parent: &Path,
+) -> Result<Vec<BenchmarkInfo>> {
+    let target_dir = target_dir_parent.join(if args.dev { "debug" } else { "release" });
+
+    let mut benchmarks = Vec::new();
+
+    for file in files {
+        let binary_path = target_dir.join(&file.stem);
+
+        #[cfg(windows)]
+        let binary_path = binary_path.with_extension("exe");
+
+        if !binary_path.exists() {
+            eprintln!(
+                "⚠️  Binary not found: {} (expected at {})",
+                file.stem,
+                binary_path.display()
+            );
+            continue;
+        }
+
+        let output = Command::new(&binary_path)
+            .args(["--list", "--format", "json"])
+            .output()
+            .with_context(|| format!("Failed to execute {}", binary_path.display()))?;
+
+        if !output.status.success() {
+            bail!(
+                "{} --list failed: {}",
+                file.stem,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        let list: BenchmarkList = serde_json::from_slice(&output.stdout)
+            .with_context(|| format!("Invalid --list output from {}", file.stem))?;
+        // Binaries are built from copies in the temp workspace, so point
+        // `file` back at the original bench source.
+        benchmarks.extend(list.benchmarks.into_iter().map(|mut b| {
+            b.binary = Some(file.stem.clone());
+            b.file = file.path.display().to_string();
+            b
+        }));
+    }
+
+    Ok(benchmarks)
+}
+
 /// Run a single stress binary and capture output.
 fn run_single_binary(
     file: &StressFile,
@@ -752,6 +1122,20 @@ fn build_passthrough_args(cmd: &mut Command, args: &StressArgs) {
     if let Some(ref workload) = args.workload {
         cmd.arg("--workload").arg(workload);
     }
+    if args.exact {
+        cmd.arg("--exact");
+    }
+    if args.regex {
+        cmd.arg("--regex");
+    }
+
+    // Tag/group selection and exclusions
+    if let Some(ref select) = args.select {
+        cmd.arg("--select").arg(select);
+    }
+    for pattern in &args.exclude {
+        cmd.arg("--exclude").arg(pattern);
+    }
 
     // Runs
     if args.runs != 1 {
@@ -763,6 +1147,88 @@ fn build_passthrough_args(cmd: &mut Command, args: &StressArgs) {
         cmd.arg("--warmup").arg(args.warmup.to_string());
     }
 
+    // Per-benchmark overrides and caps
+    if let Some(timeout) = args.timeout {
+        cmd.arg("--timeout").arg(timeout.to_string());
+    }
+    if args.force {
+        cmd.arg("--force");
+    }
+    if let Some(n) = args.max_runs {
+        cmd.arg("--max-runs").arg(n.to_string());
+    }
+    if let Some(n) = args.max_warmup {
+        cmd.arg("--max-warmup").arg(n.to_string());
+    }
+    if let Some(secs) = args.max_timeout {
+        cmd.arg("--max-timeout").arg(secs.to_string());
+    }
+
+    // Adaptive sampling
+    if args.adaptive {
+        cmd.arg("--adaptive");
+    }
+    if let Some(n) = args.min_runs {
+        cmd.arg("--min-runs").arg(n.to_string());
+    }
+    if let Some(ci) = args.target_ci {
+        cmd.arg("--target-ci").arg(ci.to_string());
+    }
+    if let Some(secs) = args.time_budget {
+        cmd.arg("--time-budget").arg(secs.to_string());
+    }
+
+    // Ordering
+    if let Some(order) = args.order {
+        cmd.arg("--order").arg(match order {
+            BenchOrder::Declared => "declared",
+            BenchOrder::Name => "name",
+            BenchOrder::Random => "random",
+        });
+    }
+    if let Some(seed) = args.seed {
+        cmd.arg("--seed").arg(seed.to_string());
+    }
+    if args.interleave {
+        cmd.arg("--interleave");
+    }
+    if args.isolated {
+        cmd.arg("--isolated");
+    }
+    if let Some(ref size) = args.memory_limit {
+        cmd.arg("--memory-limit").arg(size);
+    }
+    if let Some(n) = args.cpus {
+        cmd.arg("--cpus").arg(n.to_string());
+    }
+    if let Some(n) = args.open_files {
+        cmd.arg("--open-files").arg(n.to_string());
+    }
+    if args.cgroup {
+        cmd.arg("--cgroup");
+    }
+    if let Some(ref cpus) = args.pin {
+        cmd.arg("--pin").arg(cpus);
+    }
+    if let Some(ref priority) = args.priority {
+        cmd.arg("--priority").arg(priority);
+    }
+    if let Some(ref duration) = args.soak {
+        cmd.arg("--soak").arg(duration);
+    }
+    if let Some(ref path) = args.soak_output {
+        cmd.arg("--soak-output").arg(path);
+    }
+    if let Some(max_drift) = args.max_drift {
+        cmd.arg("--max-drift").arg(max_drift.to_string());
+    }
+    if let Some(ref mode) = args.progress {
+        cmd.arg("--progress").arg(mode);
+    }
+    if let Some(secs) = args.progress_interval {
+        cmd.arg("--progress-interval").arg(secs.to_string());
+    }
+
     // Verbosity
     if args.verbose {
         cmd.arg("--verbose");
@@ -785,6 +1251,45 @@ fn build_passthrough_args(cmd: &mut Command, args: &StressArgs) {
     if let Some(ref dir) = args.output_dir {
         cmd.arg("--output-dir").arg(dir);
     }
+    if let Some(ref template) = args.file_name {
+        cmd.arg("--file-name").arg(template);
+    }
+    if args.compress {
+        cmd.arg("--compress");
+    }
+    if args.record_hostname {
+        cmd.arg("--record-hostname");
+    }
+    if let Some(ref keep) = args.keep {
+        cmd.arg("--keep").arg(keep);
+    }
+    if let Some(ref latest) = args.latest {
+        cmd.arg("--latest").arg(latest);
+    }
+
+    for reporter in &args.reporters {
+        cmd.arg("--reporter").arg(reporter);
+    }
+
+    // Console layout
+    if let Some(ref when) = args.color {
+        cmd.arg("--color").arg(when);
+    }
+    if let Some(width) = args.width {
+        cmd.arg("--width").arg(width.to_string());
+    }
+    if let Some(ref units) = args.byte_units {
+        cmd.arg("--byte-units").arg(units);
+    }
+    if let Some(ref unit) = args.time_unit {
+        cmd.arg("--time-unit").arg(unit);
+    }
+    if let Some(ref columns) = args.columns {
+        cmd.arg("--columns").arg(columns);
+    }
+    if let Some(ref sort) = args.sort {
+        cmd.arg("--sort").arg(sort);
+    }
 
     // Baseline comparison
     if let Some(ref baseline) = args.baseline {
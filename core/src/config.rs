@@ -1,6 +1,14 @@
 //! Configuration for the benchmark runner.
 
+use crate::console::{parse_columns, ConsoleOptions};
+use crate::filter::{FilterMode, NameFilter};
+use crate::layout::{check_file_name, OutputLayout};
+use crate::limits::{parse_size, ResourceLimits};
+use crate::sched::{parse_cpu_list, Priority};
+use crate::soak::{parse_duration, SoakConfig};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Configuration for the benchmark runner.
 #[derive(Debug, Clone)]
@@ -13,12 +21,63 @@ pub struct BenchRunnerConfig {
     pub output_dir: PathBuf,
     /// Print results to stdout.
     pub verbose: bool,
-    /// Filter benchmarks by name substring.
+    /// Filter benchmarks by name (comma-separated patterns, see [`FilterMode`]).
     pub filter: Option<String>,
+    /// How `filter` patterns are interpreted.
+    pub filter_mode: FilterMode,
     /// Git SHA to include in results (for regression tracking).
     pub git_sha: Option<String>,
+    /// Git branch to include in results and result file names.
+    pub git_branch: Option<String>,
     /// Fail if any benchmark exceeds this duration.
     pub timeout: Option<std::time::Duration>,
+    /// Apply `runs`, `warmup_runs` and `timeout` to every benchmark,
+    /// ignoring per-benchmark overrides.
+    pub force: bool,
+    /// Upper bound on measurement runs for any benchmark.
+    pub max_runs: Option<usize>,
+    /// Upper bound on warmup runs for any benchmark.
+    pub max_warmup: Option<usize>,
+    /// Upper bound on the timeout of any benchmark.
+    pub max_timeout: Option<std::time::Duration>,
+    /// Keep sampling until the median is stable instead of using a fixed run count.
+    pub adaptive: Option<AdaptiveConfig>,
+    /// Order in which [`BenchRunner::run_all`](crate::BenchRunner::run_all) runs benchmarks.
+    pub order: BenchOrder,
+    /// Suite seed for random ordering and each benchmark's `ctx.rng()`
+    /// (a fresh seed is picked and recorded if unset).
+    pub seed: Option<u64>,
+    /// Round-robin measurement runs across benchmarks in `run_all`
+    /// instead of running each benchmark to completion.
+    pub interleave: bool,
+    /// Resource limits for benchmarks that do not declare their own.
+    pub limits: ResourceLimits,
+    /// Also enforce memory and CPU limits through a cgroup v2 child group.
+    pub cgroup: bool,
+    /// CPUs to pin to: the benchmark thread takes the first, worker threads
+    /// the rest (no pinning if empty).
+    pub pin: Vec<usize>,
+    /// Scheduling priority for the benchmark thread.
+    pub priority: Option<Priority>,
+    /// Run `run_all` benchmarks repeatedly for a duration and check for drift.
+    pub soak: Option<SoakConfig>,
+    /// When to print heartbeat lines while a benchmark runs.
+    pub progress: ProgressMode,
+    /// Time between heartbeat lines.
+    pub progress_interval: Duration,
+    /// Reporters installed in addition to the console and JSON output.
+    pub reporters: Vec<ReporterKind>,
+    /// Baseline results file that regressions are reported against.
+    pub baseline: Option<PathBuf>,
+    /// Regression threshold (0.05 = 5% slower than the baseline).
+    pub threshold: f64,
+    /// Color, width, units, columns and sorting of the console output.
+    pub console: ConsoleOptions,
+    /// File names, compression and retention of the JSON results.
+    pub layout: OutputLayout,
+    /// Record the host name in the suite environment. Off by default since
+    /// results files are often committed as baselines.
+    pub record_hostname: bool,
 }
 
 impl Default for BenchRunnerConfig {
@@ -29,8 +88,31 @@ impl Default for BenchRunnerConfig {
             output_dir: PathBuf::from("target/stress"),
             verbose: true,
             filter: None,
+            filter_mode: FilterMode::Glob,
             git_sha: None,
+            git_branch: None,
             timeout: None,
+            force: false,
+            max_runs: None,
+            max_warmup: None,
+            max_timeout: None,
+            adaptive: None,
+            order: BenchOrder::Declared,
+            seed: None,
+            interleave: false,
+            limits: ResourceLimits::default(),
+            cgroup: false,
+            pin: Vec::new(),
+            priority: None,
+            soak: None,
+            progress: ProgressMode::Auto,
+            progress_interval: Duration::from_secs(10),
+            reporters: Vec::new(),
+            baseline: None,
+            threshold: 0.05,
+            console: ConsoleOptions::default(),
+            layout: OutputLayout::default(),
+            record_hostname: false,
         }
     }
 }
@@ -49,8 +131,42 @@ impl BenchRunnerConfig {
     /// - `BENCH_VERBOSE`: verbose output (default: true)
     /// - `BENCH_OUTPUT_DIR`: output directory
     /// - `BENCH_FILTER`: filter benchmarks by name
+    /// - `BENCH_FILTER_MODE`: `glob` (default), `exact` or `regex`
     /// - `BENCH_GIT_SHA`: git commit hash
+    /// - `BENCH_GIT_BRANCH`: git branch
     /// - `BENCH_TIMEOUT_SECS`: timeout per benchmark in seconds
+    /// - `BENCH_FORCE`: ignore per-benchmark overrides (default: false)
+    /// - `BENCH_MAX_RUNS`: cap on measurement runs for any benchmark
+    /// - `BENCH_MAX_WARMUP`: cap on warmup runs for any benchmark
+    /// - `BENCH_MAX_TIMEOUT_SECS`: cap on the timeout of any benchmark in seconds
+    /// - `BENCH_TARGET_CI`: enable adaptive runs with this relative CI target
+    /// - `BENCH_TIME_BUDGET_SECS`: enable adaptive runs with this time budget
+    /// - `BENCH_ORDER`: `declared` (default), `name` or `random`
+    /// - `BENCH_SEED`: suite seed for random ordering and `ctx.rng()`
+    /// - `BENCH_INTERLEAVE`: round-robin runs across benchmarks (default: false)
+    /// - `BENCH_MEMORY_LIMIT`: address space limit, e.g. `512M`
+    /// - `BENCH_CPUS`: number of CPU cores per benchmark
+    /// - `BENCH_OPEN_FILES`: open file descriptor limit
+    /// - `BENCH_CGROUP`: enforce limits through cgroup v2 as well (default: false)
+    /// - `BENCH_PIN`: CPU list to pin to, e.g. `2-5`
+    /// - `BENCH_PRIORITY`: `nice:<N>` or `fifo:<N>`
+    /// - `BENCH_SOAK`: soak duration, e.g. `8h` or `30m`
+    /// - `BENCH_SOAK_OUTPUT`: JSONL file for soak samples
+    /// - `BENCH_MAX_DRIFT`: drift limit in soak mode (default: 0.1)
+    /// - `BENCH_PROGRESS`: `auto` (default), `always` or `never`
+    /// - `BENCH_PROGRESS_INTERVAL_SECS`: seconds between heartbeat lines (default: 10)
+    /// - `BENCH_REPORTER`: comma-separated extra reporters, e.g. `github,csv`
+    /// - `BENCH_COLOR`: `auto` (default), `always` or `never`; `NO_COLOR` disables `auto`
+    /// - `BENCH_WIDTH`: console line width (default: the terminal's)
+    /// - `BENCH_BYTE_UNITS`: `si` (default) or `iec`
+    /// - `BENCH_TIME_UNIT`: `auto` (default), `ns`, `us`, `ms` or `s`
+    /// - `BENCH_COLUMNS`: extra console columns, e.g. `min,max,p99`
+    /// - `BENCH_SORT`: console order: `none` (default), `name` or `duration`
+    /// - `BENCH_FILE_NAME`: results file name template, e.g. `{date}-{branch}-{sha}`
+    /// - `BENCH_COMPRESS`: gzip results files (default: false, needs the `gzip` feature)
+    /// - `BENCH_KEEP`: runs to keep per suite: `all` (default), a count or days (`30d`)
+    /// - `BENCH_LATEST`: `copy` (default) or `symlink` for `latest.json`
+    /// - `BENCH_RECORD_HOSTNAME`: record the host name in results (default: false)
     pub fn from_env() -> Self {
         let mut cfg = Self::default();
 
@@ -73,19 +189,212 @@ impl BenchRunnerConfig {
         if let Ok(v) = std::env::var("BENCH_FILTER") {
             cfg.filter = Some(v);
         }
+        if let Ok(v) = std::env::var("BENCH_FILTER_MODE") {
+            match v.parse() {
+                Ok(mode) => cfg.filter_mode = mode,
+                Err(e) => eprintln!("Warning: ignoring BENCH_FILTER_MODE: {}", e),
+            }
+        }
+        if let Some(spec) = &cfg.filter {
+            if let Err(e) = NameFilter::new(spec, cfg.filter_mode) {
+                eprintln!("Warning: ignoring BENCH_FILTER: {}", e);
+                cfg.filter = None;
+            }
+        }
         if let Ok(v) = std::env::var("BENCH_GIT_SHA") {
             cfg.git_sha = Some(v);
         }
+        if let Ok(v) = std::env::var("BENCH_GIT_BRANCH") {
+            cfg.git_branch = Some(v);
+        }
         if let Ok(v) = std::env::var("BENCH_TIMEOUT_SECS") {
             if let Ok(secs) = v.parse::<u64>() {
                 cfg.timeout = Some(std::time::Duration::from_secs(secs));
             }
         }
+        if let Ok(v) = std::env::var("BENCH_FORCE") {
+            cfg.force = v != "0" && !v.eq_ignore_ascii_case("false");
+        }
+        if let Ok(v) = std::env::var("BENCH_MAX_RUNS") {
+            if let Ok(n) = v.parse() {
+                cfg.max_runs = Some(n);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_MAX_WARMUP") {
+            if let Ok(n) = v.parse() {
+                cfg.max_warmup = Some(n);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_MAX_TIMEOUT_SECS") {
+            if let Ok(secs) = v.parse::<u64>() {
+                cfg.max_timeout = Some(std::time::Duration::from_secs(secs));
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_TARGET_CI") {
+            if let Ok(ci) = v.parse() {
+                cfg.adaptive = Some(cfg.adaptive.unwrap_or_default().target_ci(ci));
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_TIME_BUDGET_SECS") {
+            if let Ok(secs) = v.parse::<u64>() {
+                cfg.adaptive = Some(
+                    cfg.adaptive
+                        .unwrap_or_default()
+                        .time_budget(Duration::from_secs(secs)),
+                );
+            }
+        }
+
+        if let Ok(v) = std::env::var("BENCH_ORDER") {
+            match v.parse() {
+                Ok(order) => cfg.order = order,
+                Err(e) => eprintln!("Warning: ignoring BENCH_ORDER: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_SEED") {
+            if let Ok(seed) = v.parse() {
+                cfg.seed = Some(seed);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_INTERLEAVE") {
+            cfg.interleave = v != "0" && !v.eq_ignore_ascii_case("false");
+        }
+        if let Ok(v) = std::env::var("BENCH_MEMORY_LIMIT") {
+            match parse_size(&v) {
+                Ok(bytes) => cfg.limits.memory = Some(bytes),
+                Err(e) => eprintln!("Warning: ignoring BENCH_MEMORY_LIMIT: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_CPUS") {
+            if let Ok(n) = v.parse() {
+                cfg.limits.cpus = Some(n);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_OPEN_FILES") {
+            if let Ok(n) = v.parse() {
+                cfg.limits.open_files = Some(n);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_CGROUP") {
+            cfg.cgroup = v != "0" && !v.eq_ignore_ascii_case("false");
+        }
+        if let Ok(v) = std::env::var("BENCH_PIN") {
+            match parse_cpu_list(&v) {
+                Ok(cpus) => cfg.pin = cpus,
+                Err(e) => eprintln!("Warning: ignoring BENCH_PIN: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_PRIORITY") {
+            match v.parse() {
+                Ok(priority) => cfg.priority = Some(priority),
+                Err(e) => eprintln!("Warning: ignoring BENCH_PRIORITY: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_SOAK") {
+            match parse_duration(&v) {
+                Ok(duration) => cfg.soak = Some(SoakConfig::new(duration)),
+                Err(e) => eprintln!("Warning: ignoring BENCH_SOAK: {}", e),
+            }
+        }
+        if let Some(soak) = cfg.soak.as_mut() {
+            if let Ok(v) = std::env::var("BENCH_SOAK_OUTPUT") {
+                soak.output = Some(PathBuf::from(v));
+            }
+            if let Ok(v) = std::env::var("BENCH_MAX_DRIFT") {
+                if let Ok(max_drift) = v.parse() {
+                    soak.max_drift = max_drift;
+                }
+            }
+        }
+
+        if let Ok(v) = std::env::var("BENCH_PROGRESS") {
+            match v.parse() {
+                Ok(mode) => cfg.progress = mode,
+                Err(e) => eprintln!("Warning: ignoring BENCH_PROGRESS: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_PROGRESS_INTERVAL_SECS") {
+            if let Ok(secs) = v.parse::<u64>() {
+                cfg.progress_interval = Duration::from_secs(secs.max(1));
+            }
+        }
+
+        if let Ok(v) = std::env::var("BENCH_REPORTER") {
+            for name in v.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                match name.parse() {
+                    Ok(kind) => cfg = cfg.reporter(kind),
+                    Err(e) => eprintln!("Warning: ignoring BENCH_REPORTER: {}", e),
+                }
+            }
+        }
+
+        if let Ok(v) = std::env::var("BENCH_COLOR") {
+            match v.parse() {
+                Ok(mode) => cfg.console.color = mode,
+                Err(e) => eprintln!("Warning: ignoring BENCH_COLOR: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_WIDTH") {
+            if let Ok(width) = v.parse() {
+                cfg.console.width = Some(width);
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_BYTE_UNITS") {
+            match v.parse() {
+                Ok(units) => cfg.console.byte_units = units,
+                Err(e) => eprintln!("Warning: ignoring BENCH_BYTE_UNITS: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_TIME_UNIT") {
+            match v.parse() {
+                Ok(unit) => cfg.console.time_unit = unit,
+                Err(e) => eprintln!("Warning: ignoring BENCH_TIME_UNIT: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_COLUMNS") {
+            match parse_columns(&v) {
+                Ok(columns) => cfg.console.columns = columns,
+                Err(e) => eprintln!("Warning: ignoring BENCH_COLUMNS: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_SORT") {
+            match v.parse() {
+                Ok(sort) => cfg.console.sort = sort,
+                Err(e) => eprintln!("Warning: ignoring BENCH_SORT: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_FILE_NAME") {
+            match check_file_name(&v) {
+                Ok(()) => cfg.layout.file_name = v,
+                Err(e) => eprintln!("Warning: ignoring BENCH_FILE_NAME: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_COMPRESS") {
+            cfg.layout.compress = v != "0" && !v.eq_ignore_ascii_case("false");
+        }
+        if let Ok(v) = std::env::var("BENCH_RECORD_HOSTNAME") {
+            cfg.record_hostname = v != "0" && !v.eq_ignore_ascii_case("false");
+        }
+        if let Ok(v) = std::env::var("BENCH_KEEP") {
+            match v.parse() {
+                Ok(retention) => cfg.layout.retention = retention,
+                Err(e) => eprintln!("Warning: ignoring BENCH_KEEP: {}", e),
+            }
+        }
+        if let Ok(v) = std::env::var("BENCH_LATEST") {
+            match v.parse() {
+                Ok(latest) => cfg.layout.latest = latest,
+                Err(e) => eprintln!("Warning: ignoring BENCH_LATEST: {}", e),
+            }
+        }
 
         // Try to detect git SHA if not set
         if cfg.git_sha.is_none() {
             cfg.git_sha = detect_git_sha();
         }
+        if cfg.git_branch.is_none() {
+            cfg.git_branch = detect_git_branch();
+        }
 
         cfg
     }
@@ -120,6 +429,12 @@ impl BenchRunnerConfig {
         self
     }
 
+    /// Set how filter patterns are interpreted.
+    pub fn filter_mode(mut self, mode: FilterMode) -> Self {
+        self.filter_mode = mode;
+        self
+    }
+
     /// Clear filter pattern.
     pub fn no_filter(mut self) -> Self {
         self.filter = None;
@@ -132,11 +447,432 @@ impl BenchRunnerConfig {
         self
     }
 
+    /// Set git branch.
+    pub fn git_branch(mut self, branch: impl Into<String>) -> Self {
+        self.git_branch = Some(branch.into());
+        self
+    }
+
     /// Set timeout per benchmark.
     pub fn timeout(mut self, duration: std::time::Duration) -> Self {
         self.timeout = Some(duration);
         self
     }
+
+    /// Apply the global runs/warmup/timeout to every benchmark, ignoring
+    /// per-benchmark overrides.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Cap measurement runs for every benchmark.
+    pub fn max_runs(mut self, n: usize) -> Self {
+        self.max_runs = Some(n);
+        self
+    }
+
+    /// Cap warmup runs for every benchmark.
+    pub fn max_warmup(mut self, n: usize) -> Self {
+        self.max_warmup = Some(n);
+        self
+    }
+
+    /// Cap the timeout for every benchmark.
+    pub fn max_timeout(mut self, duration: std::time::Duration) -> Self {
+        self.max_timeout = Some(duration);
+        self
+    }
+
+    /// Enable adaptive run counts.
+    pub fn adaptive(mut self, adaptive: AdaptiveConfig) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    /// Set the order in which `run_all` runs benchmarks.
+    pub fn order(mut self, order: BenchOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set the suite seed for random ordering and `ctx.rng()`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Round-robin measurement runs across benchmarks in `run_all`.
+    pub fn interleave(mut self, interleave: bool) -> Self {
+        self.interleave = interleave;
+        self
+    }
+
+    /// Set default resource limits (applied in `--isolated` children).
+    pub fn limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Enforce memory and CPU limits through cgroup v2 when available.
+    pub fn cgroup(mut self, cgroup: bool) -> Self {
+        self.cgroup = cgroup;
+        self
+    }
+
+    /// Pin the benchmark thread to the first of `cpus` and worker threads to
+    /// the rest.
+    pub fn pin(mut self, cpus: impl Into<Vec<usize>>) -> Self {
+        self.pin = cpus.into();
+        self
+    }
+
+    /// Set the scheduling priority of the benchmark thread.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Soak `run_all` benchmarks instead of making a fixed number of runs.
+    pub fn soak(mut self, soak: SoakConfig) -> Self {
+        self.soak = Some(soak);
+        self
+    }
+
+    /// Set when heartbeat lines are printed.
+    pub fn progress(mut self, mode: ProgressMode) -> Self {
+        self.progress = mode;
+        self
+    }
+
+    /// Set the time between heartbeat lines.
+    pub fn progress_interval(mut self, interval: Duration) -> Self {
+        self.progress_interval = interval;
+        self
+    }
+
+    /// Install an extra reporter (once, however often it is added).
+    pub fn reporter(mut self, kind: ReporterKind) -> Self {
+        if !self.reporters.contains(&kind) {
+            self.reporters.push(kind);
+        }
+        self
+    }
+
+    /// Set the baseline results file that reporters compare against.
+    pub fn baseline(mut self, path: impl Into<PathBuf>) -> Self {
+        self.baseline = Some(path.into());
+        self
+    }
+
+    /// Set the regression threshold (0.05 = 5%).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the console output options.
+    pub fn console(mut self, options: ConsoleOptions) -> Self {
+        self.console = options;
+        self
+    }
+
+    /// Set the file names, compression and retention of the JSON results.
+    pub fn layout(mut self, layout: OutputLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Record the host name in the suite environment.
+    pub fn record_hostname(mut self, record: bool) -> Self {
+        self.record_hostname = record;
+        self
+    }
+
+    /// Resolve the settings a single benchmark actually runs with.
+    ///
+    /// Per-benchmark overrides win over the global values unless `force` is
+    /// set; resource limits are always merged per field. The `max_*` caps are
+    /// applied last. At least one run is always made.
+    /// A benchmark with an explicit run count is never run adaptively.
+    pub fn resolve(&self, overrides: &BenchOverrides) -> BenchSettings {
+        let (runs, warmup_runs, timeout) = if self.force {
+            (self.runs, self.warmup_runs, self.timeout)
+        } else {
+            (
+                overrides.runs.unwrap_or(self.runs),
+                overrides.warmup.unwrap_or(self.warmup_runs),
+                overrides.timeout.or(self.timeout),
+            )
+        };
+        let limits = overrides.limits.or(self.limits);
+
+        let runs = self.max_runs.map_or(runs, |max| runs.min(max)).max(1);
+        let warmup_runs = self
+            .max_warmup
+            .map_or(warmup_runs, |max| warmup_runs.min(max));
+        let timeout = match (timeout, self.max_timeout) {
+            (Some(t), Some(max)) => Some(t.min(max)),
+            (t, max) => t.or(max),
+        };
+
+        let adaptive = if overrides.runs.is_some() && !self.force {
+            None
+        } else {
+            self.adaptive.map(|mut adaptive| {
+                if let Some(max) = self.max_runs {
+                    adaptive.max_runs = adaptive.max_runs.min(max);
+                }
+                adaptive.max_runs = adaptive.max_runs.max(1);
+                adaptive.min_runs = adaptive.min_runs.clamp(1, adaptive.max_runs);
+                adaptive
+            })
+        };
+
+        BenchSettings {
+            runs,
+            warmup_runs,
+            timeout,
+            adaptive,
+            limits,
+        }
+    }
+}
+
+/// Order in which a set of benchmarks is run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BenchOrder {
+    /// Registration order
+    #[default]
+    Declared,
+    /// Sorted by name
+    Name,
+    /// Shuffled with a seeded RNG
+    Random,
+}
+
+impl std::str::FromStr for BenchOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "declared" => Ok(BenchOrder::Declared),
+            "name" => Ok(BenchOrder::Name),
+            "random" => Ok(BenchOrder::Random),
+            other => Err(format!(
+                "unknown order '{}' (expected declared, name or random)",
+                other
+            )),
+        }
+    }
+}
+
+/// When to print progress heartbeats for running benchmarks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressMode {
+    /// Only when stderr is a terminal and output is verbose
+    #[default]
+    Auto,
+    /// Always, e.g. to keep CI jobs from timing out on silence
+    Always,
+    /// Never
+    Never,
+}
+
+impl ProgressMode {
+    /// Whether heartbeats should be printed.
+    pub(crate) fn enabled(self, verbose: bool) -> bool {
+        use std::io::IsTerminal;
+        match self {
+            ProgressMode::Auto => verbose && std::io::stderr().is_terminal(),
+            ProgressMode::Always => true,
+            ProgressMode::Never => false,
+        }
+    }
+}
+
+impl std::str::FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(ProgressMode::Auto),
+            "always" => Ok(ProgressMode::Always),
+            "never" => Ok(ProgressMode::Never),
+            other => Err(format!(
+                "unknown progress mode '{}' (expected auto, always or never)",
+                other
+            )),
+        }
+    }
+}
+
+/// Extra reporter selected with `--reporter` or `BENCH_REPORTER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReporterKind {
+    /// GitHub Actions annotations and job summary; also enabled
+    /// automatically when `GITHUB_ACTIONS` is set
+    Github,
+    /// JUnit XML for CI test dashboards
+    Junit,
+    /// One CSV row per benchmark
+    Csv,
+    /// One TSV row per benchmark
+    Tsv,
+    /// Markdown comparison with the baseline, for PR comments
+    Markdown,
+    /// Self-contained HTML report with charts
+    Html,
+    /// OpenMetrics text file for the Prometheus textfile collector
+    Prometheus,
+    /// JSON Lines event stream, flushed as benchmarks run
+    Jsonl,
+}
+
+impl std::str::FromStr for ReporterKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "github" => Ok(ReporterKind::Github),
+            "junit" => Ok(ReporterKind::Junit),
+            "csv" => Ok(ReporterKind::Csv),
+            "tsv" => Ok(ReporterKind::Tsv),
+            "markdown" => Ok(ReporterKind::Markdown),
+            "html" => Ok(ReporterKind::Html),
+            "prometheus" => Ok(ReporterKind::Prometheus),
+            "jsonl" => Ok(ReporterKind::Jsonl),
+            other => Err(format!(
+                "unknown reporter '{}' (expected github, junit, csv, tsv, markdown, html, prometheus or jsonl)",
+                other
+            )),
+        }
+    }
+}
+
+/// Settings for adaptive run counts.
+///
+/// After `min_runs` measurements the runner keeps sampling until the 95%
+/// confidence interval of the median is within `target_ci` of the median
+/// (relative half-width), `max_runs` is reached, or the benchmark has spent
+/// `time_budget` in measured runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveConfig {
+    /// Minimum measurement runs before the interval is checked.
+    pub min_runs: usize,
+    /// Maximum measurement runs.
+    pub max_runs: usize,
+    /// Target relative half-width of the median's confidence interval (0.02 = ±2%).
+    pub target_ci: f64,
+    /// Stop sampling once measured runs have taken this long in total.
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for AdaptiveConfig {
+    fn default() -> Self {
+        Self {
+            min_runs: 10,
+            max_runs: 100,
+            target_ci: 0.02,
+            time_budget: None,
+        }
+    }
+}
+
+impl AdaptiveConfig {
+    /// Create adaptive settings with defaults (10..=100 runs, ±2%).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum number of runs.
+    pub fn min_runs(mut self, n: usize) -> Self {
+        self.min_runs = n;
+        self
+    }
+
+    /// Set the maximum number of runs.
+    pub fn max_runs(mut self, n: usize) -> Self {
+        self.max_runs = n;
+        self
+    }
+
+    /// Set the target relative confidence interval.
+    pub fn target_ci(mut self, ci: f64) -> Self {
+        self.target_ci = ci;
+        self
+    }
+
+    /// Set the measured-time budget per benchmark.
+    pub fn time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = Some(budget);
+        self
+    }
+}
+
+/// Optional per-benchmark settings that take precedence over the runner config.
+///
+/// Set from `#[stress_test(runs = 3, warmup = 1, timeout = 600)]` or passed to
+/// [`BenchRunner::run_with`](crate::BenchRunner::run_with).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchOverrides {
+    /// Measurement runs for this benchmark.
+    pub runs: Option<usize>,
+    /// Warmup runs for this benchmark.
+    pub warmup: Option<usize>,
+    /// Timeout for a single measured run of this benchmark.
+    pub timeout: Option<Duration>,
+    /// Resource limits for this benchmark (unset limits fall back to the config).
+    pub limits: ResourceLimits,
+}
+
+impl BenchOverrides {
+    /// Create empty overrides (use the runner config for everything).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the number of measurement runs.
+    pub fn runs(mut self, n: usize) -> Self {
+        self.runs = Some(n);
+        self
+    }
+
+    /// Override the number of warmup runs.
+    pub fn warmup(mut self, n: usize) -> Self {
+        self.warmup = Some(n);
+        self
+    }
+
+    /// Override the per-run timeout.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+        self
+    }
+
+    /// Set resource limits for this benchmark.
+    pub fn limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// Effective settings for one benchmark after applying overrides and caps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchSettings {
+    /// Measurement runs (ignored when `adaptive` is set).
+    pub runs: usize,
+    /// Warmup runs.
+    pub warmup_runs: usize,
+    /// Timeout for a single measured run.
+    pub timeout: Option<Duration>,
+    /// Adaptive sampling, if enabled for this benchmark.
+    pub adaptive: Option<AdaptiveConfig>,
+    /// Resource limits for the benchmark's isolated child process.
+    pub limits: ResourceLimits,
 }
 
 fn detect_git_sha() -> Option<String> {
@@ -155,6 +891,18 @@ fn detect_git_sha() -> Option<String> {
         })
 }
 
+/// Current branch, or `None` on a detached HEAD.
+fn detect_git_branch() -> Option<String> {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|branch| branch != "HEAD")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +928,114 @@ mod tests {
         assert!(!cfg.verbose);
         assert_eq!(cfg.filter, Some("my_bench".to_string()));
     }
+
+    #[test]
+    fn should_prefer_overrides_when_not_forced() {
+        let cfg = BenchRunnerConfig::new().runs(10).warmup(2);
+        let settings = cfg.resolve(
+            &BenchOverrides::new()
+                .runs(1)
+                .timeout(Duration::from_secs(5)),
+        );
+
+        assert_eq!(settings.runs, 1);
+        assert_eq!(settings.warmup_runs, 2);
+        assert_eq!(settings.timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn should_ignore_overrides_when_forced() {
+        let cfg = BenchRunnerConfig::new().runs(10).warmup(2).force(true);
+        let settings = cfg.resolve(&BenchOverrides::new().runs(1).warmup(0));
+
+        assert_eq!(settings.runs, 10);
+        assert_eq!(settings.warmup_runs, 2);
+        assert_eq!(settings.timeout, None);
+    }
+
+    #[test]
+    fn should_merge_limits_per_field_even_when_forced() {
+        let cfg = BenchRunnerConfig::new().limits(ResourceLimits::new().memory(1 << 20).cpus(4));
+        let overrides = BenchOverrides::new().limits(ResourceLimits::new().cpus(2));
+
+        let settings = cfg.resolve(&overrides);
+        assert_eq!(
+            settings.limits,
+            ResourceLimits::new().memory(1 << 20).cpus(2)
+        );
+
+        let forced = cfg.clone().force(true).resolve(&overrides);
+        assert_eq!(forced.limits, settings.limits);
+    }
+
+    #[test]
+    fn should_apply_caps_after_overrides() {
+        let cfg = BenchRunnerConfig::new()
+            .max_runs(3)
+            .max_warmup(1)
+            .max_timeout(Duration::from_secs(60));
+        let settings = cfg.resolve(
+            &BenchOverrides::new()
+                .runs(20)
+                .warmup(5)
+                .timeout(Duration::from_secs(1200)),
+        );
+
+        assert_eq!(settings.runs, 3);
+        assert_eq!(settings.warmup_runs, 1);
+        assert_eq!(settings.timeout, Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn should_cap_adaptive_max_runs() {
+        let cfg = BenchRunnerConfig::new()
+            .max_runs(20)
+            .adaptive(AdaptiveConfig::new().min_runs(30).max_runs(50));
+        let adaptive = cfg.resolve(&BenchOverrides::new()).adaptive.unwrap();
+
+        assert_eq!(adaptive.max_runs, 20);
+        assert_eq!(adaptive.min_runs, 20);
+    }
+
+    #[test]
+    fn should_not_run_adaptively_when_runs_overridden() {
+        let cfg = BenchRunnerConfig::new().adaptive(AdaptiveConfig::new());
+        assert!(cfg
+            .resolve(&BenchOverrides::new().runs(3))
+            .adaptive
+            .is_none());
+    }
+
+    #[test]
+    fn should_always_make_at_least_one_run() {
+        let cfg = BenchRunnerConfig::new().runs(0);
+        assert_eq!(cfg.resolve(&BenchOverrides::new()).runs, 1);
+    }
+
+    #[test]
+    fn should_parse_bench_order() {
+        assert_eq!("Random".parse::<BenchOrder>(), Ok(BenchOrder::Random));
+        assert_eq!("name".parse::<BenchOrder>(), Ok(BenchOrder::Name));
+        assert!("reverse".parse::<BenchOrder>().is_err());
+    }
+
+    #[test]
+    fn should_parse_progress_mode() {
+        assert_eq!("Always".parse::<ProgressMode>(), Ok(ProgressMode::Always));
+        assert_eq!("never".parse::<ProgressMode>(), Ok(ProgressMode::Never));
+        assert!("sometimes".parse::<ProgressMode>().is_err());
+        assert!(!ProgressMode::Never.enabled(true));
+        assert!(ProgressMode::Always.enabled(false));
+    }
+
+    #[test]
+    fn should_add_each_reporter_once() {
+        assert_eq!("GitHub".parse::<ReporterKind>(), Ok(ReporterKind::Github));
+        assert_eq!("junit".parse::<ReporterKind>(), Ok(ReporterKind::Junit));
+        assert!("teamcity".parse::<ReporterKind>().is_err());
+        let config = BenchRunnerConfig::new()
+            .reporter(ReporterKind::Github)
+            .reporter(ReporterKind::Github);
+        assert_eq!(config.reporters, [ReporterKind::Github]);
+    }
 }
@@ -0,0 +1,444 @@
+//! Console output options: color, layout width, units, extra columns and
+//! sorting for [`ConsoleReporter`](crate::ConsoleReporter).
+
+use crate::report::format_duration;
+use crate::result::BenchResult;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How [`ConsoleReporter`](crate::ConsoleReporter) lays out its output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsoleOptions {
+    /// When to color regressions, improvements and failures.
+    pub color: ColorMode,
+    /// Line width to fit results into (the terminal's width if unset).
+    pub width: Option<usize>,
+    /// Units for byte throughput.
+    pub byte_units: ByteUnits,
+    /// Unit for durations.
+    pub time_unit: TimeUnit,
+    /// Columns shown after the median, in order.
+    pub columns: Vec<ConsoleColumn>,
+    /// Order of the result lines.
+    pub sort: ConsoleSort,
+}
+
+impl ConsoleOptions {
+    /// Default options: automatic color, width and time unit, no extra
+    /// columns, results in the order they finish.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when output is colored.
+    pub fn color(mut self, color: ColorMode) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Fit lines into `width` columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the units for byte throughput.
+    pub fn byte_units(mut self, units: ByteUnits) -> Self {
+        self.byte_units = units;
+        self
+    }
+
+    /// Show all durations in one unit.
+    pub fn time_unit(mut self, unit: TimeUnit) -> Self {
+        self.time_unit = unit;
+        self
+    }
+
+    /// Show these columns after the median.
+    pub fn columns(mut self, columns: Vec<ConsoleColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the order of the result lines.
+    pub fn sort(mut self, sort: ConsoleSort) -> Self {
+        self.sort = sort;
+        self
+    }
+}
+
+/// When to use ANSI colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// When stdout is a terminal, `NO_COLOR` is unset and `TERM` is not `dumb`
+    #[default]
+    Auto,
+    /// Always, e.g. for CI logs that render ANSI colors
+    Always,
+    /// Never
+    Never,
+}
+
+impl ColorMode {
+    /// Whether output should be colored.
+    pub(crate) fn enabled(self) -> bool {
+        use std::io::IsTerminal;
+        match self {
+            ColorMode::Auto => {
+                let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+                !no_color
+                    && std::env::var("TERM").map_or(true, |term| term != "dumb")
+                    && std::io::stdout().is_terminal()
+            }
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
+
+impl std::str::FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(ColorMode::Auto),
+            "always" => Ok(ColorMode::Always),
+            "never" => Ok(ColorMode::Never),
+            other => Err(format!(
+                "unknown color mode '{}' (expected auto, always or never)",
+                other
+            )),
+        }
+    }
+}
+
+/// Units for byte throughput.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ByteUnits {
+    /// Powers of 1000: KB/s, MB/s, GB/s
+    #[default]
+    Si,
+    /// Powers of 1024: KiB/s, MiB/s, GiB/s
+    Iec,
+}
+
+impl ByteUnits {
+    /// Format bytes per second, e.g. `12.50 MB/s`.
+    pub(crate) fn format_rate(self, bps: f64) -> String {
+        let (base, units) = match self {
+            ByteUnits::Si => (1000.0, ["B/s", "KB/s", "MB/s", "GB/s"]),
+            ByteUnits::Iec => (1024.0, ["B/s", "KiB/s", "MiB/s", "GiB/s"]),
+        };
+        let mut value = bps;
+        let mut unit = 0;
+        while value >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        format!("{:.2} {}", value, units[unit])
+    }
+}
+
+impl std::str::FromStr for ByteUnits {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "si" => Ok(ByteUnits::Si),
+            "iec" => Ok(ByteUnits::Iec),
+            other => Err(format!(
+                "unknown byte units '{}' (expected si or iec)",
+                other
+            )),
+        }
+    }
+}
+
+/// Unit for durations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    /// The largest unit that keeps the value at or above 1
+    #[default]
+    Auto,
+    Ns,
+    Us,
+    Ms,
+    S,
+}
+
+impl TimeUnit {
+    /// Format a duration in this unit with two decimals.
+    pub(crate) fn format(self, d: Duration) -> String {
+        let secs = d.as_secs_f64();
+        match self {
+            TimeUnit::Auto => format_duration(d),
+            TimeUnit::Ns => format!("{:.2}ns", secs * 1_000_000_000.0),
+            TimeUnit::Us => format!("{:.2}us", secs * 1_000_000.0),
+            TimeUnit::Ms => format!("{:.2}ms", secs * 1_000.0),
+            TimeUnit::S => format!("{:.2}s", secs),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(TimeUnit::Auto),
+            "ns" => Ok(TimeUnit::Ns),
+            "us" | "µs" => Ok(TimeUnit::Us),
+            "ms" => Ok(TimeUnit::Ms),
+            "s" => Ok(TimeUnit::S),
+            other => Err(format!(
+                "unknown time unit '{}' (expected auto, ns, us, ms or s)",
+                other
+            )),
+        }
+    }
+}
+
+/// Optional column after the median.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleColumn {
+    Mean,
+    Min,
+    Max,
+    /// Standard deviation of the measured runs
+    StdDev,
+    P50,
+    P90,
+    P95,
+    P99,
+    /// Number of measured runs
+    Runs,
+}
+
+impl ConsoleColumn {
+    /// Column heading.
+    pub(crate) fn title(self) -> &'static str {
+        match self {
+            ConsoleColumn::Mean => "Mean",
+            ConsoleColumn::Min => "Min",
+            ConsoleColumn::Max => "Max",
+            ConsoleColumn::StdDev => "Std dev",
+            ConsoleColumn::P50 => "p50",
+            ConsoleColumn::P90 => "p90",
+            ConsoleColumn::P95 => "p95",
+            ConsoleColumn::P99 => "p99",
+            ConsoleColumn::Runs => "Runs",
+        }
+    }
+
+    /// The column's value for a result.
+    pub(crate) fn value(self, result: &BenchResult, unit: TimeUnit) -> String {
+        match self {
+            ConsoleColumn::Mean => unit.format(result.mean()),
+            ConsoleColumn::Min => unit.format(result.min_duration()),
+            ConsoleColumn::Max => unit.format(result.max_duration()),
+            ConsoleColumn::StdDev => result.std_dev().map_or("-".to_string(), |d| unit.format(d)),
+            ConsoleColumn::P50 => unit.format(result.percentile(50.0)),
+            ConsoleColumn::P90 => unit.format(result.percentile(90.0)),
+            ConsoleColumn::P95 => unit.format(result.percentile(95.0)),
+            ConsoleColumn::P99 => unit.format(result.percentile(99.0)),
+            ConsoleColumn::Runs => result.runs.to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for ConsoleColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mean" => Ok(ConsoleColumn::Mean),
+            "min" => Ok(ConsoleColumn::Min),
+            "max" => Ok(ConsoleColumn::Max),
+            "stddev" => Ok(ConsoleColumn::StdDev),
+            "p50" => Ok(ConsoleColumn::P50),
+            "p90" => Ok(ConsoleColumn::P90),
+            "p95" => Ok(ConsoleColumn::P95),
+            "p99" => Ok(ConsoleColumn::P99),
+            "runs" => Ok(ConsoleColumn::Runs),
+            other => Err(format!(
+                "unknown column '{}' (expected mean, min, max, stddev, p50, p90, p95, p99 or runs)",
+                other
+            )),
+        }
+    }
+}
+
+/// Parse a comma-separated column list, e.g. `min,max,p99`.
+pub(crate) fn parse_columns(s: &str) -> Result<Vec<ConsoleColumn>, String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Order of the console result lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleSort {
+    /// As benchmarks finish
+    #[default]
+    None,
+    /// By name, once the suite has finished
+    Name,
+    /// Slowest first, once the suite has finished
+    Duration,
+}
+
+impl std::str::FromStr for ConsoleSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(ConsoleSort::None),
+            "name" => Ok(ConsoleSort::Name),
+            "duration" => Ok(ConsoleSort::Duration),
+            other => Err(format!(
+                "unknown sort order '{}' (expected none, name or duration)",
+                other
+            )),
+        }
+    }
+}
+
+/// ANSI style for a piece of console output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    /// Regressions, failures and timeouts
+    Red,
+    /// Improvements
+    Green,
+    /// Warnings such as a missed confidence target
+    Yellow,
+    /// Skipped benchmarks
+    Dim,
+}
+
+impl Style {
+    /// Wrap `text` in this style's escape codes if `enabled`.
+    pub(crate) fn paint(self, text: &str, enabled: bool) -> String {
+        if !enabled {
+            return text.to_string();
+        }
+        let code = match self {
+            Style::Red => "31",
+            Style::Green => "32",
+            Style::Yellow => "33",
+            Style::Dim => "2",
+        };
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+}
+
+/// Width of the terminal stdout is attached to, or `COLUMNS`.
+pub(crate) fn terminal_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.parse().ok())
+        .filter(|&c: &usize| c > 0)
+    {
+        return Some(columns);
+    }
+    stdout_width()
+}
+
+#[cfg(target_os = "linux")]
+fn stdout_width() -> Option<usize> {
+    use std::io::IsTerminal;
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    // SAFETY: TIOCGWINSZ only writes a winsize into the zeroed struct
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    (ok && size.ws_col > 0).then_some(size.ws_col as usize)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn stdout_width() -> Option<usize> {
+    None
+}
+
+/// Shorten `name` to `width` characters by replacing its middle with `…`,
+/// so both the group and the benchmark stay recognisable.
+pub(crate) fn truncate_middle(name: &str, width: usize) -> String {
+    let len = name.chars().count();
+    if len <= width || width < 3 {
+        return name.to_string();
+    }
+    let tail = (width - 1) / 2;
+    let head = width - 1 - tail;
+    let start: String = name.chars().take(head).collect();
+    let end: String = name.chars().skip(len - tail).collect();
+    format!("{}…{}", start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_rates_in_si_or_iec_units() {
+        assert_eq!(ByteUnits::Si.format_rate(1_500_000.0), "1.50 MB/s");
+        assert_eq!(ByteUnits::Iec.format_rate(1_572_864.0), "1.50 MiB/s");
+        assert_eq!(ByteUnits::Iec.format_rate(512.0), "512.00 B/s");
+        assert_eq!(ByteUnits::Si.format_rate(3e12), "3000.00 GB/s");
+    }
+
+    #[test]
+    fn should_format_durations_in_fixed_unit() {
+        let d = Duration::from_micros(1500);
+        assert_eq!(TimeUnit::Auto.format(d), "1.50ms");
+        assert_eq!(TimeUnit::Us.format(d), "1500.00us");
+        assert_eq!(TimeUnit::S.format(d), "0.00s");
+        assert_eq!("µs".parse::<TimeUnit>(), Ok(TimeUnit::Us));
+        assert!("min".parse::<TimeUnit>().is_err());
+    }
+
+    #[test]
+    fn should_parse_column_lists() {
+        assert_eq!(
+            parse_columns("min, max,stddev,P99,runs"),
+            Ok(vec![
+                ConsoleColumn::Min,
+                ConsoleColumn::Max,
+                ConsoleColumn::StdDev,
+                ConsoleColumn::P99,
+                ConsoleColumn::Runs
+            ])
+        );
+        assert!(parse_columns("min,median").is_err());
+        assert_eq!("duration".parse::<ConsoleSort>(), Ok(ConsoleSort::Duration));
+        assert_eq!("Never".parse::<ColorMode>(), Ok(ColorMode::Never));
+    }
+
+    #[test]
+    fn should_truncate_long_names_in_the_middle() {
+        assert_eq!(truncate_middle("storage/scan", 20), "storage/scan");
+        assert_eq!(
+            truncate_middle("storage/compaction_under_load", 15),
+            "storage…er_load"
+        );
+        assert_eq!(
+            truncate_middle("storage/compaction_under_load", 15)
+                .chars()
+                .count(),
+            15
+        );
+    }
+
+    #[test]
+    fn should_only_paint_when_enabled() {
+        assert_eq!(Style::Red.paint("slow", false), "slow");
+        assert_eq!(Style::Green.paint("fast", true), "\x1b[32mfast\x1b[0m");
+    }
+}
@@ -1,5 +1,13 @@
 //! Benchmark context for timing control.
 
+use crate::faults::{self, FaultPlan, ScratchDir};
+use crate::isolate;
+use crate::progress::{Progress, ProgressHandle};
+use crate::result::InjectedFault;
+use crate::rng::StressRng;
+use crate::sched;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// Context passed to benchmark closures for timing control.
@@ -10,18 +18,151 @@ pub struct StressContext {
     pub(crate) bytes: Option<u64>,
     pub(crate) elements: Option<u64>,
     pub(crate) tags: Vec<(String, String)>,
+    pub(crate) metrics: BTreeMap<String, f64>,
+    pub(crate) faults: Vec<InjectedFault>,
+    pub(crate) run: usize,
+    /// Warmup runs never schedule kills
+    pub(crate) warmup: bool,
+    /// CPUs from `--pin`; workers use all but the first
+    pub(crate) pin: Vec<usize>,
+    /// Set when progress heartbeats are enabled
+    pub(crate) progress: Option<Arc<Progress>>,
+    seed: u64,
+    rng: StressRng,
+    plan: FaultPlan,
 }
 
 impl StressContext {
+    #[cfg(test)]
     pub(crate) fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    pub(crate) fn with_seed(seed: u64) -> Self {
         Self {
             duration: None,
             bytes: None,
             elements: None,
             tags: Vec::new(),
+            metrics: BTreeMap::new(),
+            faults: Vec::new(),
+            run: 0,
+            warmup: false,
+            pin: Vec::new(),
+            progress: None,
+            seed,
+            rng: StressRng::new(seed),
+            plan: FaultPlan::default(),
         }
     }
 
+    /// Deterministic RNG for generating workload data.
+    ///
+    /// Seeded from the suite seed (`--seed` / `BENCH_SEED`) and the benchmark
+    /// name, and reset for every run, so each run sees the same sequence.
+    ///
+    /// ```rust,no_run
+    /// # use cntryl_stress::StressContext;
+    /// # fn example(ctx: &mut StressContext) {
+    /// let keys: Vec<u64> = (0..1000).map(|_| ctx.rng().next_u64()).collect();
+    /// ctx.measure(|| keys.iter().sum::<u64>());
+    /// # }
+    /// ```
+    pub fn rng(&mut self) -> &mut StressRng {
+        &mut self.rng
+    }
+
+    /// Seed of this benchmark's RNG (also recorded in the result).
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Faults to inject during the measured region of this run.
+    ///
+    /// Faults are armed only while a `measure` method runs, and every fault
+    /// that fires is recorded in the result. See [`faults`].
+    ///
+    /// ```rust,no_run
+    /// # use cntryl_stress::StressContext;
+    /// # use std::time::Duration;
+    /// # fn example(ctx: &mut StressContext) {
+    /// ctx.faults().error("sst.write", "injected EIO").after(10).times(1);
+    /// ctx.measure(|| { /* calls cntryl_stress::faults::failpoint("sst.write") */ });
+    /// # }
+    /// ```
+    pub fn faults(&mut self) -> &mut FaultPlan {
+        &mut self.plan
+    }
+
+    /// Create a scratch directory whose files fail with a disk full error after
+    /// `limit` bytes, to simulate a full disk.
+    pub fn scratch_dir(&self, limit: u64) -> std::io::Result<ScratchDir> {
+        ScratchDir::new(limit)
+    }
+
+    /// How many times this benchmark's process has been killed and restarted
+    /// by a [`kill_after`](FaultPlan::kill_after) fault.
+    ///
+    /// Always 0 outside `--isolated` mode.
+    pub fn restart_count(&self) -> u32 {
+        isolate::restart_count()
+    }
+
+    /// CPU that worker thread `index` should be pinned to with
+    /// [`pin_current_thread`](crate::pin_current_thread).
+    ///
+    /// The benchmark thread runs on the first `--pin` CPU; workers get the
+    /// others round-robin. `None` if no pin list is configured.
+    ///
+    /// ```rust,no_run
+    /// # use cntryl_stress::StressContext;
+    /// # fn example(ctx: &mut StressContext) {
+    /// let cpus: Vec<_> = (0..4).map(|i| ctx.worker_cpu(i)).collect();
+    /// ctx.measure(|| {
+    ///     std::thread::scope(|s| {
+    ///         for cpu in cpus {
+    ///             s.spawn(move || {
+    ///                 if let Some(cpu) = cpu {
+    ///                     let _ = cntryl_stress::pin_current_thread(cpu);
+    ///                 }
+    ///                 // ... worker loop
+    ///             });
+    ///         }
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    pub fn worker_cpu(&self, index: usize) -> Option<usize> {
+        sched::worker_cpu(&self.pin, index)
+    }
+
+    /// Report progress within this run for the heartbeat line, e.g. rows
+    /// loaded during setup. Does nothing unless progress output is enabled.
+    pub fn progress(&self, done: u64, total: u64) {
+        self.progress_handle().update(done, total);
+    }
+
+    /// Handle for reporting progress from inside `measure` or from worker
+    /// threads, where `ctx` itself is not available.
+    ///
+    /// ```rust,no_run
+    /// # use cntryl_stress::StressContext;
+    /// # fn example(ctx: &mut StressContext) {
+    /// let progress = ctx.progress_handle();
+    /// ctx.measure(|| {
+    ///     for i in 0..1_000_000u64 {
+    ///         // ... one unit of work
+    ///         if i % 10_000 == 0 {
+    ///             progress.update(i, 1_000_000);
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn progress_handle(&self) -> ProgressHandle {
+        ProgressHandle::new(self.progress.clone())
+    }
+
     /// Record throughput in bytes processed.
     ///
     /// This enables bytes/sec reporting in results.
@@ -43,6 +184,14 @@ impl StressContext {
         self.tags.push((key.into(), value.into()));
     }
 
+    /// Report a custom metric for this run, e.g. queue depth or cache size.
+    ///
+    /// The last run's value is kept in the result; in soak mode every run's
+    /// metrics are written to the samples file.
+    pub fn metric(&mut self, name: impl Into<String>, value: f64) {
+        self.metrics.insert(name.into(), value);
+    }
+
     /// Time a single-shot operation. Call exactly once per benchmark.
     ///
     /// Everything before this is setup (not timed).
@@ -69,10 +218,7 @@ impl StressContext {
     where
         F: FnOnce() -> R,
     {
-        let start = Instant::now();
-        let result = f();
-        self.duration = Some(start.elapsed());
-        result
+        self.timed(f)
     }
 
     /// Time an operation on a borrowed reference (avoids moves).
@@ -82,10 +228,7 @@ impl StressContext {
     where
         F: FnOnce(&T) -> R,
     {
-        let start = Instant::now();
-        let result = f(target);
-        self.duration = Some(start.elapsed());
-        result
+        self.timed(|| f(target))
     }
 
     /// Time an operation on a mutable reference.
@@ -93,9 +236,33 @@ impl StressContext {
     where
         F: FnOnce(&mut T) -> R,
     {
+        self.timed(|| f(target))
+    }
+
+    /// Time `f` with the fault plan armed.
+    fn timed<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        if self.plan.is_empty() {
+            let start = Instant::now();
+            let result = f();
+            self.duration = Some(start.elapsed());
+            return result;
+        }
+
+        let kill = self
+            .plan
+            .kill_after_duration()
+            .filter(|_| !self.warmup && self.restart_count() == 0);
+        if let Some(after) = kill {
+            isolate::schedule_kill(after, self.run);
+        }
+        let guard = faults::arm(&self.plan, self.seed, self.run);
         let start = Instant::now();
-        let result = f(target);
+        let result = f();
         self.duration = Some(start.elapsed());
+        self.faults.extend(guard.finish());
+        if kill.is_some() {
+            isolate::cancel_kill();
+        }
         result
     }
 
@@ -135,6 +302,14 @@ mod tests {
         assert_eq!(ctx.elements, Some(100));
     }
 
+    #[test]
+    fn should_restart_rng_sequence_for_same_seed() {
+        let mut first = StressContext::with_seed(7);
+        let mut second = StressContext::with_seed(7);
+        assert_eq!(first.rng().next_u64(), second.rng().next_u64());
+        assert_eq!(first.seed(), 7);
+    }
+
     #[test]
     fn should_collect_tags_when_added() {
         let mut ctx = StressContext::new();
@@ -142,4 +317,12 @@ mod tests {
         ctx.tag("version", "1.0");
         assert_eq!(ctx.tags.len(), 2);
     }
+
+    #[test]
+    #[should_panic(expected = "--isolated")]
+    fn should_refuse_kill_faults_when_not_isolated() {
+        let mut ctx = StressContext::new();
+        ctx.faults().kill_after(Duration::from_millis(1));
+        ctx.measure(|| {});
+    }
 }
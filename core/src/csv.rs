@@ -0,0 +1,363 @@
+//! CSV and TSV export of results for spreadsheets and pandas.
+//!
+//! Results are flattened to one row per benchmark, or one row per measured
+//! run. Columns always appear in the order of [`BENCH_COLUMNS`] and
+//! [`RUN_COLUMNS`]; new columns are only ever appended, so scripts can rely
+//! on positions across versions. Durations are in nanoseconds, throughput in
+//! units per second, tags are `key=value` pairs joined by `;` and sorted by
+//! key, and missing values are empty.
+
+use crate::environment::Environment;
+use crate::report::{short_name, suite_path_name, Reporter};
+use crate::result::{BenchResult, SuiteResult};
+use std::path::PathBuf;
+
+/// Columns of a per-benchmark export, in order.
+pub const BENCH_COLUMNS: &[&str] = &[
+    "suite",
+    "benchmark",
+    "group",
+    "git_sha",
+    "started_at",
+    "runs",
+    "warmup_runs",
+    "median_ns",
+    "mean_ns",
+    "min_ns",
+    "max_ns",
+    "std_dev_ns",
+    "p50_ns",
+    "p90_ns",
+    "p95_ns",
+    "p99_ns",
+    "bytes",
+    "elements",
+    "bytes_per_sec",
+    "elements_per_sec",
+    "timed_out",
+    "seed",
+    "tags",
+    "declared_tags",
+    "os",
+    "arch",
+    "cpus",
+    "cpu_model",
+    "kernel",
+    "hostname",
+];
+
+/// Columns of a per-run export, in order.
+pub const RUN_COLUMNS: &[&str] = &[
+    "suite",
+    "benchmark",
+    "group",
+    "git_sha",
+    "started_at",
+    "run",
+    "duration_ns",
+    "bytes",
+    "elements",
+    "tags",
+    "declared_tags",
+    "os",
+    "arch",
+    "cpus",
+    "cpu_model",
+    "kernel",
+    "hostname",
+];
+
+/// Field separator of an export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CsvFormat {
+    /// Comma-separated, quoted as in RFC 4180
+    #[default]
+    Csv,
+    /// Tab-separated; tabs and line breaks in values become spaces
+    Tsv,
+}
+
+impl CsvFormat {
+    /// File extension for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            CsvFormat::Csv => "csv",
+            CsvFormat::Tsv => "tsv",
+        }
+    }
+
+    fn field(self, value: &str) -> String {
+        match self {
+            CsvFormat::Csv if value.contains([',', '"', '\n', '\r']) => {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            }
+            CsvFormat::Csv => value.to_string(),
+            CsvFormat::Tsv => value.replace(['\t', '\n', '\r'], " "),
+        }
+    }
+
+    fn row(self, fields: &[String]) -> String {
+        let separator = match self {
+            CsvFormat::Csv => ",",
+            CsvFormat::Tsv => "\t",
+        };
+        let fields: Vec<_> = fields.iter().map(|f| self.field(f)).collect();
+        fields.join(separator) + "\n"
+    }
+}
+
+impl std::str::FromStr for CsvFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(CsvFormat::Csv),
+            "tsv" => Ok(CsvFormat::Tsv),
+            other => Err(format!(
+                "unknown export format '{}' (expected csv or tsv)",
+                other
+            )),
+        }
+    }
+}
+
+/// Flatten suites into a CSV or TSV document with a header row.
+///
+/// With `per_run`, each measured run of each benchmark is a row.
+pub fn results_to_csv(suites: &[SuiteResult], format: CsvFormat, per_run: bool) -> String {
+    let columns = if per_run { RUN_COLUMNS } else { BENCH_COLUMNS };
+    let header: Vec<_> = columns.iter().map(|c| c.to_string()).collect();
+    let mut out = format.row(&header);
+
+    for suite in suites {
+        for result in &suite.results {
+            if per_run {
+                for (run, duration) in result.all_runs.iter().enumerate() {
+                    let mut fields = leading_fields(suite, result);
+                    fields.extend([
+                        run.to_string(),
+                        duration.as_nanos().to_string(),
+                        optional(result.bytes),
+                        optional(result.elements),
+                    ]);
+                    fields.extend(trailing_fields(suite, result));
+                    out.push_str(&format.row(&fields));
+                }
+            } else {
+                out.push_str(&format.row(&bench_fields(suite, result)));
+            }
+        }
+    }
+    out
+}
+
+/// `suite` to `started_at`, shared by both layouts.
+fn leading_fields(suite: &SuiteResult, result: &BenchResult) -> Vec<String> {
+    vec![
+        suite.suite.clone(),
+        short_name(&result.name).to_string(),
+        result.group.clone().unwrap_or_default(),
+        suite.git_sha.clone().unwrap_or_default(),
+        suite.started_at.clone(),
+    ]
+}
+
+/// `tags` to `hostname`, shared by both layouts.
+fn trailing_fields(suite: &SuiteResult, result: &BenchResult) -> Vec<String> {
+    let mut tags: Vec<_> = result
+        .tags
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    tags.sort();
+
+    let env = suite.environment.clone().unwrap_or_default();
+    let Environment {
+        os,
+        arch,
+        cpus,
+        cpu_model,
+        kernel,
+        hostname,
+        ..
+    } = env;
+    let cpus = if cpus == 0 {
+        String::new()
+    } else {
+        cpus.to_string()
+    };
+    vec![
+        tags.join(";"),
+        result.declared_tags.join(";"),
+        os,
+        arch,
+        cpus,
+        cpu_model.unwrap_or_default(),
+        kernel.unwrap_or_default(),
+        hostname.unwrap_or_default(),
+    ]
+}
+
+fn bench_fields(suite: &SuiteResult, result: &BenchResult) -> Vec<String> {
+    let nanos = |d: std::time::Duration| d.as_nanos().to_string();
+    let mut fields = leading_fields(suite, result);
+    fields.extend([
+        result.runs.to_string(),
+        result.warmup_runs.to_string(),
+        nanos(result.duration),
+        nanos(result.mean()),
+        nanos(result.min_duration()),
+        nanos(result.max_duration()),
+        result.std_dev().map(nanos).unwrap_or_default(),
+        nanos(result.percentile(50.0)),
+        nanos(result.percentile(90.0)),
+        nanos(result.percentile(95.0)),
+        nanos(result.percentile(99.0)),
+        optional(result.bytes),
+        optional(result.elements),
+        result
+            .bytes_per_sec()
+            .map(|v| format!("{:.0}", v))
+            .unwrap_or_default(),
+        result
+            .elements_per_sec()
+            .map(|v| format!("{:.0}", v))
+            .unwrap_or_default(),
+        result.timed_out.to_string(),
+        optional(result.seed),
+    ]);
+    fields.extend(trailing_fields(suite, result));
+    fields
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Reporter that writes `{output_dir}/{suite}/results.csv` (or `.tsv`,
+/// or `runs.csv` per run).
+pub struct CsvReporter {
+    output_dir: PathBuf,
+    format: CsvFormat,
+    per_run: bool,
+}
+
+impl CsvReporter {
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            format: CsvFormat::Csv,
+            per_run: false,
+        }
+    }
+
+    /// Write CSV (default) or TSV.
+    pub fn format(mut self, format: CsvFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Write one row per measured run instead of per benchmark.
+    pub fn per_run(mut self, per_run: bool) -> Self {
+        self.per_run = per_run;
+        self
+    }
+
+    fn write(&self, suite: &SuiteResult) -> std::io::Result<PathBuf> {
+        let suite_dir = self.output_dir.join(suite_path_name(&suite.suite));
+        std::fs::create_dir_all(&suite_dir)?;
+        let stem = if self.per_run { "runs" } else { "results" };
+        let path = suite_dir.join(format!("{}.{}", stem, self.format.extension()));
+        let csv = results_to_csv(std::slice::from_ref(suite), self.format, self.per_run);
+        std::fs::write(&path, csv)?;
+        Ok(path)
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn suite_end(&self, result: &SuiteResult) {
+        match self.write(result) {
+            Ok(path) => eprintln!(
+                "  {} results at: {}",
+                self.format.extension().to_uppercase(),
+                path.display()
+            ),
+            Err(e) => eprintln!("Warning: failed to write CSV results: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suite() -> SuiteResult {
+        let scan = BenchResult::fixture("storage/scan, full", 20)
+            .measured(&[10, 20, 30])
+            .bytes(2_000_000)
+            .tag("disk", "nvme")
+            .tag("cache", "cold");
+        SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![scan],
+            started_at: "1771376841729".to_string(),
+            git_sha: Some("36d2a432".to_string()),
+            environment: Some(Environment {
+                os: "linux".to_string(),
+                arch: "x86_64".to_string(),
+                cpus: 8,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_flatten_one_row_per_benchmark() {
+        let csv = results_to_csv(&[suite()], CsvFormat::Csv, false);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), BENCH_COLUMNS.join(","));
+        assert_eq!(
+            lines.next().unwrap(),
+            "storage,\"scan, full\",,36d2a432,1771376841729,3,0,20000000,20000000,10000000,\
+             30000000,10000000,20000000,28000000,29000000,29800000,2000000,,100000000,,false,,\
+             cache=cold;disk=nvme,,linux,x86_64,8,,,"
+        );
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn should_flatten_one_row_per_run_as_tsv() {
+        let tsv = results_to_csv(&[suite()], CsvFormat::Tsv, true);
+        let lines: Vec<_> = tsv.lines().collect();
+        assert_eq!(lines[0], RUN_COLUMNS.join("\t"));
+        assert_eq!(lines.len(), 4);
+        let fields: Vec<_> = lines[3].split('\t').collect();
+        assert_eq!(fields.len(), RUN_COLUMNS.len());
+        assert_eq!(
+            &fields[..7],
+            [
+                "storage",
+                "scan, full",
+                "",
+                "36d2a432",
+                "1771376841729",
+                "2",
+                "30000000"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_column_order_stable() {
+        // Columns may be appended, never reordered
+        assert_eq!(
+            &BENCH_COLUMNS[..4],
+            ["suite", "benchmark", "group", "git_sha"]
+        );
+        assert_eq!(BENCH_COLUMNS[7], "median_ns");
+        assert_eq!(RUN_COLUMNS[6], "duration_ns");
+        assert_eq!("TSV".parse::<CsvFormat>(), Ok(CsvFormat::Tsv));
+        assert!("xlsx".parse::<CsvFormat>().is_err());
+    }
+}
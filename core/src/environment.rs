@@ -0,0 +1,104 @@
+//! Description of the machine a suite ran on.
+//!
+//! Recorded in [`SuiteResult::environment`](crate::SuiteResult::environment)
+//! so results from different machines can be told apart in exports and
+//! reports. Fields that cannot be determined are left out.
+
+use serde::{Deserialize, Serialize};
+
+/// Machine a suite ran on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Environment {
+    /// Operating system, e.g. `linux`
+    pub os: String,
+    /// CPU architecture, e.g. `x86_64`
+    pub arch: String,
+    /// Logical CPUs available to the process
+    pub cpus: usize,
+    /// CPU model name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_model: Option<String>,
+    /// Kernel release
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<String>,
+    /// Total physical memory in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<u64>,
+    /// Host name, only recorded when enabled with `--record-hostname`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// Version of cntryl-stress that produced the results
+    #[serde(default)]
+    pub version: String,
+}
+
+impl Environment {
+    /// Describe the current machine, without its host name.
+    pub fn capture() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpus: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            cpu_model: cpu_model(),
+            kernel: read_trimmed("/proc/sys/kernel/osrelease"),
+            memory: total_memory(),
+            hostname: None,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+
+    /// Add the host name, which [`capture`](Self::capture) leaves out.
+    pub fn record_hostname(mut self) -> Self {
+        self.hostname =
+            read_trimmed("/proc/sys/kernel/hostname").or_else(|| std::env::var("HOSTNAME").ok());
+        self
+    }
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    let value = std::fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// `model name` from `/proc/cpuinfo` (Linux).
+fn cpu_model() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "model name")
+        .map(|(_, value)| value.trim().to_string())
+}
+
+/// `MemTotal` from `/proc/meminfo` (Linux).
+fn total_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|l| l.starts_with("MemTotal:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_capture_current_platform() {
+        let env = Environment::capture();
+        assert_eq!(env.os, std::env::consts::OS);
+        assert_eq!(env.arch, std::env::consts::ARCH);
+        assert!(env.cpus >= 1);
+        assert_eq!(env.version, env!("CARGO_PKG_VERSION"));
+        #[cfg(target_os = "linux")]
+        assert!(env.memory.is_some_and(|m| m > 0));
+        assert_eq!(env.hostname, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_record_hostname_only_when_asked() {
+        let env = Environment::capture().record_hostname();
+        assert!(env.hostname.is_some());
+    }
+}
@@ -0,0 +1,558 @@
+//! Fault injection for stress scenarios.
+//!
+//! Faults are scheduled on the benchmark's [`StressContext`] and are armed
+//! only while its measured region runs:
+//!
+//! - **Delays and errors** fire at named failpoints that the system under
+//!   test checks by calling [`failpoint`].
+//! - **Disk full** is simulated either at a failpoint or by a
+//!   [`ScratchDir`] whose files stop accepting writes at a byte quota.
+//! - **Kill and restart** terminates the benchmark process mid-measurement
+//!   and starts it again; this needs the `--isolated` mode of the stress
+//!   binary so the harness itself survives.
+//!
+//! Every injected fault is recorded in the benchmark's
+//! [`BenchResult::faults`](crate::BenchResult::faults).
+//!
+//! ```rust,no_run
+//! use cntryl_stress::faults;
+//! use cntryl_stress::StressContext;
+//! use std::time::Duration;
+//!
+//! // In the system under test (behind a feature flag):
+//! fn sync_wal() -> std::io::Result<()> {
+//!     faults::failpoint("wal.fsync")?;
+//!     // ... real fsync
+//!     Ok(())
+//! }
+//!
+//! fn recovery(ctx: &mut StressContext) {
+//!     ctx.faults().delay("wal.fsync", Duration::from_millis(20)).times(3);
+//!     ctx.faults().error("wal.fsync", "injected EIO").after(100).times(1);
+//!     ctx.measure(|| {
+//!         for _ in 0..1000 {
+//!             let _ = sync_wal();
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! [`StressContext`]: crate::StressContext
+
+use crate::result::{FaultKind, InjectedFault};
+use crate::rng::StressRng;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Faults scheduled for the next measured region of a benchmark.
+///
+/// Obtained from [`StressContext::faults`](crate::StressContext::faults).
+#[derive(Debug, Clone, Default)]
+pub struct FaultPlan {
+    faults: Vec<Fault>,
+    kill_after: Option<Duration>,
+}
+
+/// What happens when a fault fires at a failpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaultAction {
+    /// Sleep before returning `Ok(())`
+    Delay(Duration),
+    /// Return an `io::Error` with this message
+    Error(String),
+    /// Return an `io::Error` reporting no space left on device
+    DiskFull,
+}
+
+/// A fault bound to a failpoint, with its trigger conditions.
+#[derive(Debug, Clone)]
+pub struct Fault {
+    failpoint: String,
+    action: FaultAction,
+    skip: u64,
+    times: Option<u64>,
+    probability: f64,
+}
+
+impl Fault {
+    fn new(failpoint: impl Into<String>, action: FaultAction) -> Self {
+        Self {
+            failpoint: failpoint.into(),
+            action,
+            skip: 0,
+            times: None,
+            probability: 1.0,
+        }
+    }
+
+    /// Let the first `hits` checks of the failpoint pass before firing.
+    pub fn after(&mut self, hits: u64) -> &mut Self {
+        self.skip = hits;
+        self
+    }
+
+    /// Fire at most `n` times (unlimited by default).
+    pub fn times(&mut self, n: u64) -> &mut Self {
+        self.times = Some(n);
+        self
+    }
+
+    /// Fire on each eligible check with probability `p`.
+    ///
+    /// Draws come from the benchmark's seed, so they are reproducible.
+    pub fn probability(&mut self, p: f64) -> &mut Self {
+        self.probability = p.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl FaultPlan {
+    /// Delay every check of `failpoint` by `duration`.
+    pub fn delay(&mut self, failpoint: impl Into<String>, duration: Duration) -> &mut Fault {
+        self.push(Fault::new(failpoint, FaultAction::Delay(duration)))
+    }
+
+    /// Make checks of `failpoint` return an error.
+    pub fn error(
+        &mut self,
+        failpoint: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Fault {
+        self.push(Fault::new(failpoint, FaultAction::Error(message.into())))
+    }
+
+    /// Make checks of `failpoint` fail with a "no space left on device" error.
+    pub fn disk_full(&mut self, failpoint: impl Into<String>) -> &mut Fault {
+        self.push(Fault::new(failpoint, FaultAction::DiskFull))
+    }
+
+    /// Kill the benchmark process `after` the measured region starts, then
+    /// restart it.
+    ///
+    /// Only honoured in `--isolated` mode, where each benchmark runs in its
+    /// own child process. The process is killed once; after the restart
+    /// [`StressContext::restart_count`](crate::StressContext::restart_count)
+    /// is non-zero, kill faults are skipped and the benchmark typically
+    /// measures recovery.
+    pub fn kill_after(&mut self, after: Duration) -> &mut Self {
+        self.kill_after = Some(after);
+        self
+    }
+
+    /// Whether no faults are scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.faults.is_empty() && self.kill_after.is_none()
+    }
+
+    pub(crate) fn kill_after_duration(&self) -> Option<Duration> {
+        self.kill_after
+    }
+
+    fn push(&mut self, fault: Fault) -> &mut Fault {
+        self.faults.push(fault);
+        self.faults.last_mut().unwrap()
+    }
+}
+
+// ============================================================================
+// Active plan (process-global, armed during the measured region)
+// ============================================================================
+
+/// Fast path for `failpoint` when nothing is armed.
+static ARMED: AtomicBool = AtomicBool::new(false);
+static ACTIVE: Mutex<Option<ActivePlan>> = Mutex::new(None);
+
+struct ActivePlan {
+    faults: Vec<ArmedFault>,
+    rng: StressRng,
+    start: Instant,
+    run: usize,
+    injected: Vec<InjectedFault>,
+}
+
+struct ArmedFault {
+    fault: Fault,
+    hits: u64,
+    fired: u64,
+}
+
+/// Disarms the active plan when dropped, even if the measured closure panics.
+pub(crate) struct ArmGuard {
+    active: bool,
+}
+
+impl ArmGuard {
+    /// Collect the faults injected while armed and disarm.
+    pub(crate) fn finish(mut self) -> Vec<InjectedFault> {
+        self.active = false;
+        disarm()
+    }
+}
+
+impl Drop for ArmGuard {
+    fn drop(&mut self) {
+        if self.active {
+            disarm();
+        }
+    }
+}
+
+/// Arm `plan` for the measured region of run `run`.
+pub(crate) fn arm(plan: &FaultPlan, seed: u64, run: usize) -> ArmGuard {
+    let active = ActivePlan {
+        faults: plan
+            .faults
+            .iter()
+            .map(|fault| ArmedFault {
+                fault: fault.clone(),
+                hits: 0,
+                fired: 0,
+            })
+            .collect(),
+        rng: StressRng::new(seed),
+        start: Instant::now(),
+        run,
+        injected: Vec::new(),
+    };
+    *lock_active() = Some(active);
+    ARMED.store(true, Ordering::SeqCst);
+    ArmGuard { active: true }
+}
+
+fn disarm() -> Vec<InjectedFault> {
+    ARMED.store(false, Ordering::SeqCst);
+    lock_active()
+        .take()
+        .map(|active| active.injected)
+        .unwrap_or_default()
+}
+
+fn lock_active() -> std::sync::MutexGuard<'static, Option<ActivePlan>> {
+    // A panicking benchmark must not disable fault injection for the rest
+    // of the suite
+    ACTIVE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Record a fault that was injected outside a failpoint (e.g. by a
+/// [`ScratchDir`]). Ignored when no plan is armed.
+fn record(kind: FaultKind, failpoint: Option<&str>, detail: Option<String>) {
+    if !ARMED.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(active) = lock_active().as_mut() {
+        let fault = InjectedFault {
+            kind,
+            failpoint: failpoint.map(str::to_string),
+            at: active.start.elapsed(),
+            run: active.run,
+            detail,
+        };
+        active.injected.push(fault);
+    }
+}
+
+/// Check a named failpoint.
+///
+/// Call this from the system under test at points where a fault should be
+/// injectable. Returns `Ok(())` immediately unless a benchmark is measuring
+/// with a fault scheduled for `name`, in which case it sleeps or returns the
+/// injected error. The check is a single atomic load when nothing is armed.
+pub fn failpoint(name: &str) -> io::Result<()> {
+    if !ARMED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    let action = {
+        let mut guard = lock_active();
+        let active = match guard.as_mut() {
+            Some(active) => active,
+            None => return Ok(()),
+        };
+        let at = active.start.elapsed();
+        let run = active.run;
+
+        let mut fired = None;
+        for armed in active.faults.iter_mut() {
+            if armed.fault.failpoint != name {
+                continue;
+            }
+            armed.hits += 1;
+            if armed.hits <= armed.fault.skip
+                || armed.fault.times.is_some_and(|max| armed.fired >= max)
+            {
+                continue;
+            }
+            if armed.fault.probability < 1.0 && active.rng.next_f64() >= armed.fault.probability {
+                continue;
+            }
+            armed.fired += 1;
+            fired = Some(armed.fault.action.clone());
+            break;
+        }
+
+        let action = match fired {
+            Some(action) => action,
+            None => return Ok(()),
+        };
+        let (kind, detail) = match &action {
+            FaultAction::Delay(d) => (FaultKind::Delay, Some(format!("{:?}", d))),
+            FaultAction::Error(message) => (FaultKind::Error, Some(message.clone())),
+            FaultAction::DiskFull => (FaultKind::DiskFull, None),
+        };
+        active.injected.push(InjectedFault {
+            kind,
+            failpoint: Some(name.to_string()),
+            at,
+            run,
+            detail,
+        });
+        action
+    };
+
+    // Sleep and build errors outside the lock so other threads keep going
+    match action {
+        FaultAction::Delay(d) => {
+            std::thread::sleep(d);
+            Ok(())
+        }
+        FaultAction::Error(message) => Err(io::Error::other(message)),
+        FaultAction::DiskFull => Err(disk_full_error(name)),
+    }
+}
+
+fn disk_full_error(at: &str) -> io::Error {
+    io::Error::other(format!("no space left on device (injected at {})", at))
+}
+
+// ============================================================================
+// Size-limited scratch directory
+// ============================================================================
+
+static SCRATCH_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A temporary directory whose files fail with a disk full error once the bytes
+/// written through them exceed a quota.
+///
+/// Only writes made through [`ScratchDir::create`] count against the quota;
+/// the directory is removed when dropped. Obtained from
+/// [`StressContext::scratch_dir`](crate::StressContext::scratch_dir).
+#[derive(Debug)]
+pub struct ScratchDir {
+    path: PathBuf,
+    limit: u64,
+    used: Arc<AtomicU64>,
+}
+
+impl ScratchDir {
+    /// Create an empty scratch directory under the system temp directory.
+    pub fn new(limit: u64) -> io::Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "cntryl-stress-{}-{}",
+            std::process::id(),
+            SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self {
+            path,
+            limit,
+            used: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Directory path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Quota in bytes.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Bytes written so far.
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Create (or truncate) a file whose writes count against the quota.
+    pub fn create(&self, name: impl AsRef<Path>) -> io::Result<LimitedFile> {
+        let path = self.path.join(name);
+        Ok(LimitedFile {
+            file: File::create(&path)?,
+            path,
+            limit: self.limit,
+            used: Arc::clone(&self.used),
+        })
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A file in a [`ScratchDir`] that reports a disk full error past the quota.
+///
+/// A write that would cross the quota is cut short at the limit, like a real
+/// full disk; the next write fails.
+#[derive(Debug)]
+pub struct LimitedFile {
+    file: File,
+    path: PathBuf,
+    limit: u64,
+    used: Arc<AtomicU64>,
+}
+
+impl LimitedFile {
+    /// Path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The underlying file, e.g. for `sync_all`.
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+}
+
+impl Write for LimitedFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let used = self.used.load(Ordering::Relaxed);
+        let available = self.limit.saturating_sub(used);
+        if available == 0 && !buf.is_empty() {
+            record(
+                FaultKind::DiskFull,
+                None,
+                Some(self.path.display().to_string()),
+            );
+            return Err(disk_full_error(&self.path.display().to_string()));
+        }
+
+        let len = buf.len().min(available.min(usize::MAX as u64) as usize);
+        let written = self.file.write(&buf[..len])?;
+        self.used.fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Failpoint state is process-global; tests that arm plans hold this lock.
+#[cfg(test)]
+pub(crate) fn serial() -> std::sync::MutexGuard<'static, ()> {
+    static SERIAL: Mutex<()> = Mutex::new(());
+    SERIAL.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_pass_failpoints_when_nothing_armed() {
+        let _serial = serial();
+        assert!(failpoint("anything").is_ok());
+    }
+
+    #[test]
+    fn should_fire_error_after_skips_and_limit_times() {
+        let _serial = serial();
+        let mut plan = FaultPlan::default();
+        plan.error("wal.fsync", "EIO").after(2).times(1);
+
+        let guard = arm(&plan, 0, 3);
+        let outcomes: Vec<bool> = (0..5).map(|_| failpoint("wal.fsync").is_ok()).collect();
+        assert!(failpoint("other").is_ok());
+        let injected = guard.finish();
+
+        assert_eq!(outcomes, [true, true, false, true, true]);
+        assert_eq!(injected.len(), 1);
+        assert_eq!(injected[0].kind, FaultKind::Error);
+        assert_eq!(injected[0].failpoint.as_deref(), Some("wal.fsync"));
+        assert_eq!(injected[0].run, 3);
+        assert_eq!(injected[0].detail.as_deref(), Some("EIO"));
+        assert!(failpoint("wal.fsync").is_ok());
+    }
+
+    #[test]
+    fn should_delay_and_report_disk_full() {
+        let _serial = serial();
+        let mut plan = FaultPlan::default();
+        plan.delay("slow", Duration::from_millis(5));
+        plan.disk_full("append");
+
+        let guard = arm(&plan, 0, 0);
+        let start = Instant::now();
+        assert!(failpoint("slow").is_ok());
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        let err = failpoint("append").unwrap_err();
+        assert!(err.to_string().starts_with("no space left on device"));
+        let kinds: Vec<_> = guard.finish().iter().map(|f| f.kind).collect();
+
+        assert_eq!(kinds, [FaultKind::Delay, FaultKind::DiskFull]);
+    }
+
+    #[test]
+    fn should_reproduce_probabilistic_faults_from_seed() {
+        let _serial = serial();
+        let mut plan = FaultPlan::default();
+        plan.error("flaky", "boom").probability(0.5);
+
+        let pattern = |seed| {
+            let guard = arm(&plan, seed, 0);
+            let hits: Vec<bool> = (0..32).map(|_| failpoint("flaky").is_err()).collect();
+            guard.finish();
+            hits
+        };
+        assert_eq!(pattern(11), pattern(11));
+        assert!(pattern(11).contains(&true) && pattern(11).contains(&false));
+    }
+
+    #[test]
+    fn should_disarm_when_guard_dropped() {
+        let _serial = serial();
+        let mut plan = FaultPlan::default();
+        plan.error("x", "boom");
+        drop(arm(&plan, 0, 0));
+        assert!(failpoint("x").is_ok());
+    }
+
+    #[test]
+    fn should_fail_writes_past_scratch_quota() {
+        let _serial = serial();
+        let dir = ScratchDir::new(10).unwrap();
+        let mut file = dir.create("data").unwrap();
+
+        assert_eq!(file.write(&[1; 6]).unwrap(), 6);
+        assert_eq!(file.write(&[2; 6]).unwrap(), 4);
+        let err = file.write(&[3]).unwrap_err();
+        assert!(err.to_string().starts_with("no space left on device"));
+        assert_eq!(dir.used(), 10);
+
+        let path = dir.path().to_path_buf();
+        drop(file);
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn should_record_scratch_disk_full_while_armed() {
+        let _serial = serial();
+        let dir = ScratchDir::new(0).unwrap();
+        let mut file = dir.create("data").unwrap();
+
+        let guard = arm(&FaultPlan::default(), 0, 0);
+        assert!(file.write_all(b"x").is_err());
+        let injected = guard.finish();
+
+        assert_eq!(injected.len(), 1);
+        assert_eq!(injected[0].kind, FaultKind::DiskFull);
+    }
+}
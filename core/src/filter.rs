@@ -0,0 +1,402 @@
+//! Benchmark name matching shared by `BenchRunner` and the harness.
+//!
+//! A filter is one or more comma-separated patterns; a name is selected if any
+//! pattern matches. Three modes are supported:
+//!
+//! - **Glob** (default, case-insensitive): `*` matches within one path segment,
+//!   `**` matches across `::` and `/` separators, `?` matches a single
+//!   character, and `[abc]`, `[a-z]`, `[!abc]` match character classes. Like
+//!   `cargo test`, a pattern without any wildcard matches as a substring.
+//! - **Exact**: the pattern must equal the name (case-sensitive).
+//! - **Regex**: the pattern is a regular expression searched for in the name.
+
+use std::fmt;
+
+/// How filter patterns are interpreted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FilterMode {
+    /// Glob patterns; wildcard-free patterns match as substrings.
+    #[default]
+    Glob,
+    /// Exact, case-sensitive name equality.
+    Exact,
+    /// Regular expressions (unanchored search).
+    Regex,
+}
+
+impl std::str::FromStr for FilterMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "glob" => Ok(FilterMode::Glob),
+            "exact" => Ok(FilterMode::Exact),
+            "regex" => Ok(FilterMode::Regex),
+            other => Err(format!(
+                "unknown filter mode '{}' (expected glob, exact or regex)",
+                other
+            )),
+        }
+    }
+}
+
+/// Error produced when a filter pattern is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterError {
+    /// The offending pattern
+    pub pattern: String,
+    pub message: String,
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid pattern '{}': {}", self.pattern, self.message)
+    }
+}
+
+impl std::error::Error for FilterError {}
+
+/// A compiled set of name patterns.
+///
+/// In glob mode a pattern without `*`, `?` or `[` matches any name that
+/// contains it, so `write` selects `storage/fsync_write`; use
+/// [`FilterMode::Exact`] to match whole names.
+#[derive(Debug, Clone)]
+pub struct NameFilter {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone)]
+enum Pattern {
+    Substring(String),
+    Glob(Vec<GlobToken>),
+    Exact(String),
+    Regex(regex::Regex),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GlobToken {
+    Char(char),
+    /// `?`
+    AnyChar,
+    /// `*`
+    AnyInSegment,
+    /// `**`
+    AnyAcrossSegments,
+    /// `[...]`
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl NameFilter {
+    /// Compile a comma-separated list of patterns.
+    ///
+    /// Commas inside `[]`, `{}` or `()` do not split, so regex quantifiers
+    /// such as `a{1,3}` are kept intact. Empty patterns are ignored.
+    pub fn new(spec: &str, mode: FilterMode) -> Result<Self, FilterError> {
+        let patterns = split_patterns(spec)
+            .into_iter()
+            .filter(|p| !p.is_empty())
+            .map(|p| Pattern::compile(p, mode))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// Whether `name` matches any pattern.
+    pub fn matches(&self, name: &str) -> bool {
+        self.patterns.iter().any(|p| p.matches(name))
+    }
+
+    /// Whether any of the candidate names matches any pattern.
+    pub fn matches_any(&self, names: &[&str]) -> bool {
+        names.iter().any(|name| self.matches(name))
+    }
+}
+
+impl Pattern {
+    fn compile(pattern: &str, mode: FilterMode) -> Result<Self, FilterError> {
+        match mode {
+            FilterMode::Exact => Ok(Pattern::Exact(pattern.to_string())),
+            FilterMode::Regex => {
+                regex::Regex::new(pattern)
+                    .map(Pattern::Regex)
+                    .map_err(|e| FilterError {
+                        pattern: pattern.to_string(),
+                        message: e.to_string(),
+                    })
+            }
+            FilterMode::Glob if !pattern.contains(['*', '?', '[']) => {
+                Ok(Pattern::Substring(pattern.to_lowercase()))
+            }
+            FilterMode::Glob => parse_glob(&pattern.to_lowercase())
+                .map(Pattern::Glob)
+                .map_err(|message| FilterError {
+                    pattern: pattern.to_string(),
+                    message,
+                }),
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Pattern::Substring(s) => name.to_lowercase().contains(s.as_str()),
+            Pattern::Glob(tokens) => {
+                let text: Vec<char> = name.to_lowercase().chars().collect();
+                glob_match(tokens, &text)
+            }
+            Pattern::Exact(s) => name == s,
+            Pattern::Regex(re) => re.is_match(name),
+        }
+    }
+}
+
+/// Split on commas that are not nested inside brackets, braces or parentheses.
+fn split_patterns(spec: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in spec.char_indices() {
+        match c {
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(spec[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(spec[start..].trim());
+    parts
+}
+
+fn parse_glob(pattern: &str) -> Result<Vec<GlobToken>, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                tokens.push(GlobToken::AnyAcrossSegments);
+                i += 2;
+                // Treat runs of more than two stars like `**`
+                while chars.get(i) == Some(&'*') {
+                    i += 1;
+                }
+            }
+            '*' => {
+                tokens.push(GlobToken::AnyInSegment);
+                i += 1;
+            }
+            '?' => {
+                tokens.push(GlobToken::AnyChar);
+                i += 1;
+            }
+            '[' => {
+                let (token, next) = parse_class(&chars, i)?;
+                tokens.push(token);
+                i = next;
+            }
+            c => {
+                tokens.push(GlobToken::Char(c));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Parse a character class starting at `chars[start] == '['`.
+/// Returns the token and the index just past the closing `]`.
+fn parse_class(chars: &[char], start: usize) -> Result<(GlobToken, usize), String> {
+    let mut i = start + 1;
+    let negated = matches!(chars.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut ranges = Vec::new();
+    // A `]` right after the opening bracket is a literal member
+    let mut first = true;
+    loop {
+        let c = match chars.get(i) {
+            Some(&c) => c,
+            None => return Err("unterminated character class".to_string()),
+        };
+        if c == ']' && !first {
+            break;
+        }
+        first = false;
+
+        if chars.get(i + 1) == Some(&'-') && chars.get(i + 2).is_some_and(|&e| e != ']') {
+            let end = chars[i + 2];
+            if end < c {
+                return Err(format!("invalid range {}-{}", c, end));
+            }
+            ranges.push((c, end));
+            i += 3;
+        } else {
+            ranges.push((c, c));
+            i += 1;
+        }
+    }
+
+    Ok((GlobToken::Class { negated, ranges }, i + 1))
+}
+
+fn is_separator(c: char) -> bool {
+    c == '/' || c == ':'
+}
+
+/// Match glob tokens against the whole text (recursive, memoized per token
+/// and text position, so pathological patterns stay polynomial).
+fn glob_match(tokens: &[GlobToken], text: &[char]) -> bool {
+    // memo[t][p]: whether tokens[t..] matches text[p..]
+    let mut memo = vec![vec![None; text.len() + 1]; tokens.len() + 1];
+    glob_match_from(tokens, text, 0, 0, &mut memo)
+}
+
+fn glob_match_from(
+    tokens: &[GlobToken],
+    text: &[char],
+    t: usize,
+    p: usize,
+    memo: &mut Vec<Vec<Option<bool>>>,
+) -> bool {
+    if let Some(result) = memo[t][p] {
+        return result;
+    }
+
+    let result = match tokens.get(t) {
+        None => p == text.len(),
+        Some(GlobToken::AnyInSegment) => {
+            let mut end = p;
+            loop {
+                if glob_match_from(tokens, text, t + 1, end, memo) {
+                    break true;
+                }
+                if end == text.len() || is_separator(text[end]) {
+                    break false;
+                }
+                end += 1;
+            }
+        }
+        Some(GlobToken::AnyAcrossSegments) => {
+            (p..=text.len()).any(|end| glob_match_from(tokens, text, t + 1, end, memo))
+        }
+        Some(token) => match text.get(p) {
+            Some(&c) if token_matches(token, c) => {
+                glob_match_from(tokens, text, t + 1, p + 1, memo)
+            }
+            _ => false,
+        },
+    };
+
+    memo[t][p] = Some(result);
+    result
+}
+
+fn token_matches(token: &GlobToken, c: char) -> bool {
+    match token {
+        GlobToken::Char(expected) => *expected == c,
+        GlobToken::AnyChar => !is_separator(c),
+        GlobToken::Class { negated, ranges } => {
+            let inside = ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi);
+            inside != *negated
+        }
+        GlobToken::AnyInSegment | GlobToken::AnyAcrossSegments => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches_glob(text: &str, pattern: &str) -> bool {
+        NameFilter::new(pattern, FilterMode::Glob)
+            .unwrap()
+            .matches(text)
+    }
+
+    #[test]
+    fn glob_matches_substring() {
+        assert!(matches_glob("foo_bar_baz", "bar"));
+        assert!(!matches_glob("foo_bar_baz", "qux"));
+    }
+
+    #[test]
+    fn glob_matches_wildcard() {
+        assert!(matches_glob("foo_bar_baz", "foo*baz"));
+        assert!(matches_glob("foo_bar_baz", "*bar*"));
+        assert!(matches_glob("foo_bar_baz", "foo*"));
+        assert!(matches_glob("foo_bar_baz", "*baz"));
+        assert!(!matches_glob("foo_bar_baz", "qux*"));
+    }
+
+    #[test]
+    fn glob_is_case_insensitive() {
+        assert!(matches_glob("FooBar", "foobar"));
+        assert!(matches_glob("foobar", "FOO*"));
+    }
+
+    #[test]
+    fn glob_matches_single_character() {
+        assert!(matches_glob("bench_a1", "bench_a?"));
+        assert!(!matches_glob("bench_a12", "bench_a?"));
+        assert!(!matches_glob("a/b", "a?b"));
+    }
+
+    #[test]
+    fn glob_matches_character_classes() {
+        assert!(matches_glob("write_4k", "write_[0-9]k"));
+        assert!(!matches_glob("write_xk", "write_[0-9]k"));
+        assert!(matches_glob("write_xk", "write_[!0-9]k"));
+        assert!(matches_glob("read", "[rw]*"));
+        assert!(NameFilter::new("[a-", FilterMode::Glob).is_err());
+    }
+
+    #[test]
+    fn single_star_stays_within_segment() {
+        assert!(!matches_glob("db::insert", "db*insert"));
+        assert!(matches_glob("db::insert", "db::*"));
+        assert!(!matches_glob("db::wal::insert", "db::*"));
+        assert!(matches_glob("db::wal::insert", "db::**"));
+        assert!(matches_glob("suite/db::wal::insert", "**insert"));
+    }
+
+    #[test]
+    fn should_match_any_comma_separated_pattern() {
+        let filter = NameFilter::new("read*, write*", FilterMode::Glob).unwrap();
+        assert!(filter.matches("read_4k"));
+        assert!(filter.matches("write_4k"));
+        assert!(!filter.matches("scan"));
+    }
+
+    #[test]
+    fn should_match_exact_names_only() {
+        let filter = NameFilter::new("insert", FilterMode::Exact).unwrap();
+        assert!(filter.matches("insert"));
+        assert!(!filter.matches("insert_batch"));
+        assert!(!filter.matches("Insert"));
+    }
+
+    #[test]
+    fn should_match_regex_and_keep_quantifier_commas() {
+        let filter = NameFilter::new(r"^write_\d{1,2}k$,scan", FilterMode::Regex).unwrap();
+        assert!(filter.matches("write_64k"));
+        assert!(!filter.matches("write_128k"));
+        assert!(filter.matches("full_scan"));
+        assert!(NameFilter::new("(", FilterMode::Regex).is_err());
+    }
+
+    #[test]
+    fn should_parse_filter_mode() {
+        assert_eq!("regex".parse::<FilterMode>(), Ok(FilterMode::Regex));
+        assert_eq!("EXACT".parse::<FilterMode>(), Ok(FilterMode::Exact));
+        assert!("fuzzy".parse::<FilterMode>().is_err());
+    }
+}
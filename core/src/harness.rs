@@ -15,8 +15,21 @@
 //! This means each stress binary is self-contained and handles its own argument
 //! parsing - `cargo-stress` just orchestrates which binaries to build and run.
 
-use crate::{BenchRunner, BenchRunnerConfig, StressContext};
+use crate::console::parse_columns;
+use crate::isolate;
+use crate::layout::check_file_name;
+use crate::limits::parse_size;
+use crate::sched::{parse_cpu_list, Priority};
+use crate::select::Selector;
+use crate::soak::{parse_duration, SoakConfig};
+use crate::{
+    AdaptiveConfig, BenchCase, BenchOrder, BenchOverrides, BenchRunner, BenchRunnerConfig,
+    ByteUnits, ColorMode, ConsoleColumn, ConsoleSort, FilterMode, LatestMode, NameFilter,
+    ProgressMode, ReporterKind, ResourceLimits, Retention, StressContext, TimeUnit,
+};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// A registered benchmark entry.
 #[doc(hidden)]
@@ -27,8 +40,168 @@ pub struct BenchmarkEntry {
     pub func: fn(&mut StressContext),
     /// Whether this benchmark is ignored by default
     pub ignored: bool,
+    /// Reason from `#[stress_test(ignore = "...")]`
+    pub ignore_reason: Option<&'static str>,
     /// Module path where the benchmark is defined
     pub module_path: &'static str,
+    /// Source file of the benchmark function
+    pub file: &'static str,
+    /// Line of the benchmark function in `file`
+    pub line: u32,
+    /// Measurement runs from `#[stress_test(runs = N)]`
+    pub runs: Option<usize>,
+    /// Warmup runs from `#[stress_test(warmup = N)]`
+    pub warmup: Option<usize>,
+    /// Per-run timeout in seconds from `#[stress_test(timeout = SECS)]`
+    pub timeout_secs: Option<u64>,
+    /// Tags from `#[stress_test(tags("disk", "slow"))]`
+    pub tags: &'static [&'static str],
+    /// Group from `#[stress_test(group = "io")]`
+    pub group: Option<&'static str>,
+    /// Address space limit in bytes from `#[stress_test(memory = "512M")]`
+    pub memory_limit: Option<u64>,
+    /// CPU cores from `#[stress_test(cpus = N)]`
+    pub cpus: Option<usize>,
+    /// File descriptor limit from `#[stress_test(open_files = N)]`
+    pub open_files: Option<u64>,
+}
+
+impl BenchmarkEntry {
+    /// Per-benchmark overrides declared on the attribute.
+    pub fn overrides(&self) -> BenchOverrides {
+        BenchOverrides {
+            runs: self.runs,
+            warmup: self.warmup,
+            timeout: self.timeout_secs.map(Duration::from_secs),
+            limits: self.limits(),
+        }
+    }
+
+    /// Resource limits declared on the attribute.
+    pub fn limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            memory: self.memory_limit,
+            open_files: self.open_files,
+            cpus: self.cpus,
+        }
+    }
+}
+
+/// Description of a registered benchmark, as printed by `--list --format json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkInfo {
+    /// Benchmark name (function name or custom)
+    pub name: String,
+    /// `module_path::name`
+    pub full_name: String,
+    pub module_path: String,
+    /// Source file, as reported by `file!()`
+    pub file: String,
+    pub line: u32,
+    pub ignored: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_reason: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Settings declared on the attribute
+    #[serde(default)]
+    pub parameters: BenchmarkParameters,
+    /// Stress binary the benchmark belongs to (set by `cargo stress --list`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+}
+
+/// Per-benchmark settings from `#[stress_test(runs = .., timeout = .., memory = .., ..)]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchmarkParameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// `memory`, `open_files` and `cpus`
+    #[serde(flatten)]
+    pub limits: ResourceLimits,
+}
+
+/// Document printed by `--list --format json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkList {
+    pub benchmarks: Vec<BenchmarkInfo>,
+}
+
+impl From<&BenchmarkEntry> for BenchmarkInfo {
+    fn from(entry: &BenchmarkEntry) -> Self {
+        Self {
+            name: entry.name.to_string(),
+            full_name: format!("{}::{}", entry.module_path, entry.name),
+            module_path: entry.module_path.to_string(),
+            file: entry.file.to_string(),
+            line: entry.line,
+            ignored: entry.ignored,
+            ignore_reason: entry.ignore_reason.map(str::to_string),
+            tags: entry.tags.iter().map(|t| t.to_string()).collect(),
+            group: entry.group.map(str::to_string),
+            parameters: BenchmarkParameters {
+                runs: entry.runs,
+                warmup: entry.warmup,
+                timeout_secs: entry.timeout_secs,
+                limits: entry.limits(),
+            },
+            binary: None,
+        }
+    }
+}
+
+/// Output format for `--list`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    /// Human-readable block with groups and tags
+    #[default]
+    Pretty,
+    /// One `full_name: bench` line per benchmark, like libtest
+    Terse,
+    /// A [`BenchmarkList`] JSON document
+    Json,
+}
+
+impl std::str::FromStr for ListFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(ListFormat::Pretty),
+            "terse" => Ok(ListFormat::Terse),
+            "json" => Ok(ListFormat::Json),
+            other => Err(format!(
+                "unknown list format '{}' (expected pretty, terse or json)",
+                other
+            )),
+        }
+    }
+}
+
+/// Render benchmarks in a machine-readable `--list` format.
+///
+/// `Pretty` falls back to the terse form; the human-readable listing is
+/// printed by the binary itself.
+pub fn format_benchmark_list(benchmarks: &[BenchmarkInfo], format: ListFormat) -> String {
+    match format {
+        ListFormat::Json => {
+            let list = BenchmarkList {
+                benchmarks: benchmarks.to_vec(),
+            };
+            serde_json::to_string_pretty(&list).unwrap_or_default()
+        }
+        ListFormat::Pretty | ListFormat::Terse => benchmarks
+            .iter()
+            .map(|b| format!("{}: bench", b.full_name))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
 }
 
 // Re-export linkme for the proc macro
@@ -52,6 +225,14 @@ pub static STRESS_BENCHMARKS: [BenchmarkEntry];
 struct StressBinaryArgs {
     /// Filter benchmarks by glob pattern
     workload: Option<String>,
+    /// Match --workload exactly
+    exact: bool,
+    /// Treat --workload as a regular expression
+    regex: bool,
+    /// Select benchmarks by tag/group expression
+    select: Option<String>,
+    /// Exclude benchmarks matching glob patterns
+    exclude: Vec<String>,
     /// Number of measurement runs
     runs: usize,
     /// Number of warmup runs
@@ -64,27 +245,139 @@ struct StressBinaryArgs {
     include_ignored: bool,
     /// List benchmarks without running
     list: bool,
+    /// Output format for --list
+    list_format: ListFormat,
     /// Output directory for JSON results
     output_dir: Option<PathBuf>,
     /// Baseline JSON for regression comparison
     baseline: Option<PathBuf>,
     /// Regression threshold
     threshold: f64,
+    /// Per-run timeout in seconds
+    timeout: Option<u64>,
+    /// Ignore per-benchmark runs/warmup/timeout
+    force: bool,
+    /// Cap on measurement runs
+    max_runs: Option<usize>,
+    /// Cap on warmup runs
+    max_warmup: Option<usize>,
+    /// Cap on the per-run timeout in seconds
+    max_timeout: Option<u64>,
+    /// Adaptive run count
+    adaptive: bool,
+    /// Minimum runs in adaptive mode (enables it)
+    min_runs: Option<usize>,
+    /// Target relative CI in adaptive mode
+    target_ci: Option<f64>,
+    /// Measured-time budget per benchmark in seconds (adaptive mode)
+    time_budget: Option<u64>,
+    /// Benchmark order
+    order: Option<BenchOrder>,
+    /// Suite seed
+    seed: Option<u64>,
+    /// Round-robin runs across benchmarks
+    interleave: bool,
+    /// Run each benchmark in its own process
+    isolated: bool,
+    /// Qualified name of the single benchmark to run as an isolated child
+    isolated_child: Option<String>,
+    /// Default resource limits
+    limits: ResourceLimits,
+    /// Enforce limits through cgroup v2 as well
+    cgroup: bool,
+    /// CPUs to pin to
+    pin: Vec<usize>,
+    /// Benchmark thread priority
+    priority: Option<Priority>,
+    /// Soak duration
+    soak: Option<Duration>,
+    /// JSONL file for soak samples
+    soak_output: Option<PathBuf>,
+    /// Drift limit in soak mode
+    max_drift: Option<f64>,
+    /// When to print progress heartbeats
+    progress: Option<ProgressMode>,
+    /// Seconds between progress heartbeats
+    progress_interval: Option<u64>,
+    /// Extra reporters
+    reporters: Vec<ReporterKind>,
+    /// When to color console output
+    color: Option<ColorMode>,
+    /// Console line width
+    width: Option<usize>,
+    /// Units for byte throughput
+    byte_units: Option<ByteUnits>,
+    /// Unit for durations
+    time_unit: Option<TimeUnit>,
+    /// Extra console columns
+    columns: Option<Vec<ConsoleColumn>>,
+    /// Order of console result lines
+    sort: Option<ConsoleSort>,
+    /// Results file name template
+    file_name: Option<String>,
+    /// Gzip results files
+    compress: bool,
+    /// Record the host name in results
+    record_hostname: bool,
+    /// Runs to keep per suite
+    keep: Option<Retention>,
+    /// How `latest.json` refers to the newest run
+    latest: Option<LatestMode>,
 }
 
 impl Default for StressBinaryArgs {
     fn default() -> Self {
         Self {
             workload: None,
+            exact: false,
+            regex: false,
+            select: None,
+            exclude: Vec::new(),
             runs: 1,
             warmup: 0,
             verbose: false,
             quiet: false,
             include_ignored: false,
             list: false,
+            list_format: ListFormat::Pretty,
             output_dir: None,
             baseline: None,
             threshold: 0.05,
+            timeout: None,
+            force: false,
+            max_runs: None,
+            max_warmup: None,
+            max_timeout: None,
+            adaptive: false,
+            min_runs: None,
+            target_ci: None,
+            time_budget: None,
+            order: None,
+            seed: None,
+            interleave: false,
+            isolated: false,
+            isolated_child: None,
+            limits: ResourceLimits::default(),
+            cgroup: false,
+            pin: Vec::new(),
+            priority: None,
+            soak: None,
+            soak_output: None,
+            max_drift: None,
+            progress: None,
+            progress_interval: None,
+            reporters: Vec::new(),
+            color: None,
+            width: None,
+            byte_units: None,
+            time_unit: None,
+            columns: None,
+            sort: None,
+            file_name: None,
+            compress: false,
+            record_hostname: false,
+            keep: None,
+            latest: None,
         }
     }
 }
@@ -107,6 +400,24 @@ impl StressBinaryArgs {
                         result.workload = Some(args[i].clone());
                     }
                 }
+                "--exact" => {
+                    result.exact = true;
+                }
+                "--regex" => {
+                    result.regex = true;
+                }
+                "--select" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.select = Some(args[i].clone());
+                    }
+                }
+                "--exclude" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.exclude.push(args[i].clone());
+                    }
+                }
                 "--runs" => {
                     i += 1;
                     if i < args.len() {
@@ -131,6 +442,18 @@ impl StressBinaryArgs {
                 "--list" => {
                     result.list = true;
                 }
+                "--format" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(format) => result.list_format = format,
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
                 "--output-dir" => {
                     i += 1;
                     if i < args.len() {
@@ -149,6 +472,295 @@ impl StressBinaryArgs {
                         result.threshold = args[i].parse().unwrap_or(0.05);
                     }
                 }
+                "--timeout" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.timeout = args[i].parse().ok();
+                    }
+                }
+                "--force" => {
+                    result.force = true;
+                }
+                "--max-runs" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.max_runs = args[i].parse().ok();
+                    }
+                }
+                "--max-warmup" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.max_warmup = args[i].parse().ok();
+                    }
+                }
+                "--max-timeout" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.max_timeout = args[i].parse().ok();
+                    }
+                }
+                "--adaptive" => {
+                    result.adaptive = true;
+                }
+                "--min-runs" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.min_runs = args[i].parse().ok();
+                    }
+                }
+                "--target-ci" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.target_ci = args[i].parse().ok();
+                    }
+                }
+                "--time-budget" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.time_budget = args[i].parse().ok();
+                    }
+                }
+                "--order" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(order) => result.order = Some(order),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--seed" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.seed = args[i].parse().ok();
+                    }
+                }
+                "--interleave" => {
+                    result.interleave = true;
+                }
+                "--isolated" => {
+                    result.isolated = true;
+                }
+                "--memory-limit" => {
+                    i += 1;
+                    if i < args.len() {
+                        match parse_size(&args[i]) {
+                            Ok(bytes) => result.limits.memory = Some(bytes),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--cpus" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.limits.cpus = args[i].parse().ok();
+                    }
+                }
+                "--open-files" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.limits.open_files = args[i].parse().ok();
+                    }
+                }
+                "--cgroup" => {
+                    result.cgroup = true;
+                }
+                "--pin" => {
+                    i += 1;
+                    if i < args.len() {
+                        match parse_cpu_list(&args[i]) {
+                            Ok(cpus) => result.pin = cpus,
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--priority" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(priority) => result.priority = Some(priority),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--soak" => {
+                    i += 1;
+                    if i < args.len() {
+                        match parse_duration(&args[i]) {
+                            Ok(duration) => result.soak = Some(duration),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--soak-output" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.soak_output = Some(PathBuf::from(&args[i]));
+                    }
+                }
+                "--max-drift" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.max_drift = args[i].parse().ok();
+                    }
+                }
+                "--progress" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(mode) => result.progress = Some(mode),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--progress-interval" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.progress_interval = args[i].parse().ok();
+                    }
+                }
+                "--reporter" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(kind) => result.reporters.push(kind),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--color" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.color = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--width" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.width = args[i].parse().ok();
+                    }
+                }
+                "--byte-units" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.byte_units = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--time-unit" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.time_unit = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--columns" => {
+                    i += 1;
+                    if i < args.len() {
+                        match parse_columns(&args[i]) {
+                            Ok(columns) => result.columns = Some(columns),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--sort" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.sort = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--file-name" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Err(e) = check_file_name(&args[i]) {
+                            eprintln!("error: {}", e);
+                            std::process::exit(1);
+                        }
+                        result.file_name = Some(args[i].clone());
+                    }
+                }
+                "--compress" => {
+                    result.compress = true;
+                }
+                "--record-hostname" => {
+                    result.record_hostname = true;
+                }
+                "--keep" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.keep = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--latest" => {
+                    i += 1;
+                    if i < args.len() {
+                        match args[i].parse() {
+                            Ok(value) => result.latest = Some(value),
+                            Err(e) => {
+                                eprintln!("error: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                }
+                "--isolated-child" => {
+                    i += 1;
+                    if i < args.len() {
+                        result.isolated_child = Some(args[i].clone());
+                    }
+                }
                 "--help" | "-h" => {
                     print_help();
                     std::process::exit(0);
@@ -171,14 +783,60 @@ fn print_help() {
     eprintln!("    <binary> [OPTIONS]");
     eprintln!();
     eprintln!("OPTIONS:");
-    eprintln!("    --workload <PATTERN>   Filter benchmarks by glob pattern");
+    eprintln!("    --workload <PATTERN>   Filter benchmarks by glob pattern (comma-separated);");
+    eprintln!("                           patterns without wildcards match as substrings");
+    eprintln!("    --exact                Match --workload patterns exactly");
+    eprintln!("    --regex                Treat --workload patterns as regular expressions");
+    eprintln!("    --select <EXPR>        Select by tags/groups, e.g. 'disk && !slow'");
+    eprintln!("    --exclude <PATTERN>    Skip benchmarks matching glob (repeatable)");
     eprintln!("    --runs <N>             Number of measurement runs (default: 1)");
     eprintln!("    --warmup <N>           Number of warmup runs (default: 0)");
+    eprintln!("    --timeout <SECS>       Fail a benchmark whose run exceeds SECS");
+    eprintln!("    --force                Apply --runs/--warmup/--timeout to every benchmark");
+    eprintln!("    --max-runs <N>         Cap measurement runs for every benchmark; also the");
+    eprintln!("                           maximum in adaptive mode (default: 100)");
+    eprintln!("    --max-warmup <N>       Cap warmup runs for every benchmark");
+    eprintln!("    --max-timeout <SECS>   Cap the timeout of every benchmark");
+    eprintln!("    --adaptive             Run until the median is stable (10..=100 runs)");
+    eprintln!("    --min-runs <N>         Minimum runs; enables adaptive mode (default: 10)");
+    eprintln!("    --target-ci <FLOAT>    Target relative CI of the median (default: 0.02)");
+    eprintln!("    --time-budget <SECS>   Measured-time budget per benchmark (adaptive)");
+    eprintln!("    --order <ORDER>        declared, name or random (default: declared)");
+    eprintln!("    --seed <N>             Suite seed for --order random and ctx.rng()");
+    eprintln!("    --interleave           Round-robin runs across benchmarks");
+    eprintln!("    --isolated             Run each benchmark in its own process");
+    eprintln!("    --memory-limit <SIZE>  Address space limit per benchmark, e.g. 512M");
+    eprintln!("    --cpus <N>             CPU cores per benchmark");
+    eprintln!("    --open-files <N>       Open file limit per benchmark");
+    eprintln!("    --cgroup               Also enforce limits with cgroup v2 when writable");
+    eprintln!("    --pin <CPUS>           Pin the benchmark thread (first) and workers, e.g. 2-5");
+    eprintln!("    --priority <PRIO>      nice:<N> or fifo:<N> for the benchmark thread");
+    eprintln!(
+        "    --soak <DURATION>      Repeat benchmarks for DURATION (e.g. 8h) and check drift"
+    );
+    eprintln!("    --soak-output <PATH>   JSONL file for soak samples");
+    eprintln!("    --max-drift <FLOAT>    Fail if duration or RSS drifts more (default: 0.1)");
+    eprintln!("    --progress <MODE>      Heartbeat lines: auto, always or never (default: auto)");
+    eprintln!("    --progress-interval <SECS>  Seconds between heartbeat lines (default: 10)");
+    eprintln!("    --reporter <NAME>      Extra reporter (repeatable): github, junit, csv, tsv,");
+    eprintln!("                           markdown, html, prometheus or jsonl");
+    eprintln!("    --color <WHEN>         Color output: auto, always or never (default: auto)");
+    eprintln!("    --width <COLS>         Console line width (default: the terminal's)");
+    eprintln!("    --byte-units <UNITS>   Throughput units: si (MB/s) or iec (MiB/s)");
+    eprintln!("    --time-unit <UNIT>     Show durations in auto, ns, us, ms or s");
+    eprintln!("    --columns <LIST>       Extra columns, e.g. min,max,stddev,p99,runs");
+    eprintln!("    --sort <ORDER>         Result order: none, name or duration (slowest first)");
     eprintln!("    -v, --verbose          Verbose output");
     eprintln!("    -q, --quiet            Quiet mode");
     eprintln!("    --include-ignored      Include ignored benchmarks");
     eprintln!("    --list                 List benchmarks without running");
+    eprintln!("    --format <FMT>         --list format: pretty, terse or json (default: pretty)");
     eprintln!("    --output-dir <PATH>    Output directory for JSON results");
+    eprintln!("    --file-name <TEMPLATE> Results file name, e.g. '{{date}}-{{branch}}-{{sha}}'");
+    eprintln!("    --compress             Gzip results files (needs the gzip feature)");
+    eprintln!("    --record-hostname      Record the host name in results");
+    eprintln!("    --keep <N|Nd|all>      Runs to keep per suite: a count, days or all");
+    eprintln!("    --latest <MODE>        latest.json as a copy or symlink (default: copy)");
     eprintln!("    --baseline <PATH>      Baseline JSON for regression comparison");
     eprintln!("    --threshold <FLOAT>    Regression threshold (default: 0.05)");
     eprintln!("    -h, --help             Show this help message");
@@ -204,16 +862,24 @@ fn print_help() {
 pub fn stress_binary_main() {
     let args = StressBinaryArgs::parse();
 
+    exit_on_duplicate_names();
+
     // Handle --list mode
     if args.list {
-        let benchmarks = list_benchmarks();
-        if benchmarks.is_empty() {
+        if args.list_format != ListFormat::Pretty {
+            let output = format_benchmark_list(&list_benchmarks(), args.list_format);
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            return;
+        }
+        if STRESS_BENCHMARKS.is_empty() {
             println!("No benchmarks registered.");
             println!("Add #[stress_test] to your benchmark functions.");
         } else {
-            println!("Registered benchmarks ({}):", benchmarks.len());
-            for name in benchmarks {
-                println!("  {}", name);
+            println!("Registered benchmarks ({}):", STRESS_BENCHMARKS.len());
+            for bench in STRESS_BENCHMARKS.iter() {
+                println!("  {}", format_list_entry(bench));
             }
         }
         return;
@@ -231,12 +897,157 @@ pub fn stress_binary_main() {
         .warmup(args.warmup)
         .verbose(verbose)
         .include_ignored(args.include_ignored)
-        .threshold(args.threshold);
+        .threshold(args.threshold)
+        .force(args.force);
+
+    if let Some(secs) = args.timeout {
+        opts = opts.timeout(Duration::from_secs(secs));
+    }
+
+    if let Some(n) = args.max_runs {
+        opts = opts.max_runs(n);
+    }
+
+    if let Some(n) = args.max_warmup {
+        opts = opts.max_warmup(n);
+    }
+    if let Some(secs) = args.max_timeout {
+        opts = opts.max_timeout(Duration::from_secs(secs));
+    }
+
+    if args.adaptive
+        || args.min_runs.is_some()
+        || args.target_ci.is_some()
+        || args.time_budget.is_some()
+    {
+        let mut adaptive = AdaptiveConfig::new();
+        if let Some(n) = args.min_runs {
+            adaptive = adaptive.min_runs(n);
+        }
+        if let Some(n) = args.max_runs {
+            adaptive = adaptive.max_runs(n);
+        }
+        if let Some(ci) = args.target_ci {
+            adaptive = adaptive.target_ci(ci);
+        }
+        if let Some(secs) = args.time_budget {
+            adaptive = adaptive.time_budget(Duration::from_secs(secs));
+        }
+        opts = opts.adaptive(adaptive);
+    }
+
+    if let Some(order) = args.order {
+        opts = opts.order(order);
+    }
+
+    if let Some(seed) = args.seed {
+        opts = opts.seed(seed);
+    }
+
+    if args.interleave {
+        opts = opts.interleave(true);
+    }
+
+    if args.isolated {
+        opts = opts.isolated(true);
+    }
+
+    opts = opts.limits(args.limits).cgroup(args.cgroup);
+
+    if !args.pin.is_empty() {
+        opts = opts.pin(args.pin);
+    }
+
+    if let Some(priority) = args.priority {
+        opts = opts.priority(priority);
+    }
+
+    if let Some(duration) = args.soak {
+        let mut soak = SoakConfig::new(duration);
+        if let Some(path) = args.soak_output {
+            soak = soak.output(path);
+        }
+        if let Some(max_drift) = args.max_drift {
+            soak = soak.max_drift(max_drift);
+        }
+        opts = opts.soak(soak);
+    }
+
+    if let Some(mode) = args.progress {
+        opts = opts.progress(mode);
+    }
+
+    if let Some(secs) = args.progress_interval {
+        opts = opts.progress_interval(Duration::from_secs(secs.max(1)));
+    }
+
+    for kind in args.reporters {
+        opts = opts.reporter(kind);
+    }
+
+    if let Some(color) = args.color {
+        opts = opts.color(color);
+    }
+    if let Some(width) = args.width {
+        opts = opts.width(width);
+    }
+    if let Some(units) = args.byte_units {
+        opts = opts.byte_units(units);
+    }
+    if let Some(unit) = args.time_unit {
+        opts = opts.time_unit(unit);
+    }
+    if let Some(columns) = args.columns {
+        opts = opts.columns(columns);
+    }
+    if let Some(sort) = args.sort {
+        opts = opts.sort(sort);
+    }
+
+    if let Some(template) = args.file_name {
+        opts = opts.file_name(template);
+    }
+    if args.compress {
+        opts = opts.compress(true);
+    }
+    if args.record_hostname {
+        opts = opts.record_hostname(true);
+    }
+    if let Some(retention) = args.keep {
+        opts = opts.keep(retention);
+    }
+    if let Some(latest) = args.latest {
+        opts = opts.latest(latest);
+    }
+
+    if let Some(name) = args.isolated_child {
+        // Launched by an --isolated parent: run exactly the named benchmark
+        opts = opts
+            .workload(name)
+            .filter_mode(FilterMode::Exact)
+            .include_ignored(true);
+        run_with_options(opts);
+        return;
+    }
 
     if let Some(pattern) = args.workload {
         opts = opts.workload(pattern);
     }
 
+    if args.regex {
+        opts = opts.filter_mode(FilterMode::Regex);
+    } else if args.exact {
+        opts = opts.filter_mode(FilterMode::Exact);
+    }
+
+    if let Some(expr) = args.select {
+        opts = opts.select(expr);
+    }
+
+    for pattern in args.exclude {
+        opts = opts.exclude(pattern);
+    }
+
     if let Some(baseline) = args.baseline {
         opts = opts.baseline(baseline);
     }
@@ -252,8 +1063,14 @@ pub fn stress_binary_main() {
 /// Options for running discovered benchmarks.
 #[derive(Debug, Clone, Default)]
 pub struct StressRunnerOptions {
-    /// Filter benchmarks by glob pattern
+    /// Filter benchmarks by name pattern (comma-separated)
     pub workload: Option<String>,
+    /// How `workload` patterns are interpreted
+    pub filter_mode: FilterMode,
+    /// Tag/group selection expression (e.g. `disk && !slow`)
+    pub select: Option<String>,
+    /// Skip benchmarks whose name or module path matches any of these globs
+    pub exclude: Vec<String>,
     /// Include ignored benchmarks
     pub include_ignored: bool,
     /// Number of measurement runs
@@ -266,6 +1083,64 @@ pub struct StressRunnerOptions {
     pub baseline: Option<std::path::PathBuf>,
     /// Regression threshold (e.g., 0.05 for 5%)
     pub threshold: f64,
+    /// Per-run timeout for benchmarks without their own
+    pub timeout: Option<Duration>,
+    /// Ignore per-benchmark runs/warmup/timeout declared on the attribute
+    pub force: bool,
+    /// Cap on measurement runs for any benchmark
+    pub max_runs: Option<usize>,
+    /// Cap on warmup runs for any benchmark
+    pub max_warmup: Option<usize>,
+    /// Cap on the per-run timeout of any benchmark
+    pub max_timeout: Option<Duration>,
+    /// Sample each benchmark until its median is stable
+    pub adaptive: Option<AdaptiveConfig>,
+    /// Order to run benchmarks in (registration order if unset)
+    pub order: Option<BenchOrder>,
+    /// Suite seed for random order and `ctx.rng()` (random if unset)
+    pub seed: Option<u64>,
+    /// Round-robin measurement runs across benchmarks
+    pub interleave: bool,
+    /// Run each benchmark in its own child process (needed for kill faults)
+    pub isolated: bool,
+    /// Resource limits for benchmarks that do not declare their own
+    pub limits: ResourceLimits,
+    /// Also enforce memory and CPU limits through cgroup v2
+    pub cgroup: bool,
+    /// CPUs to pin the benchmark thread (first) and worker threads to
+    pub pin: Vec<usize>,
+    /// Scheduling priority for the benchmark thread
+    pub priority: Option<Priority>,
+    /// Repeat benchmarks for a duration and check for drift
+    pub soak: Option<SoakConfig>,
+    /// When to print progress heartbeats (config default if unset)
+    pub progress: Option<ProgressMode>,
+    /// Time between progress heartbeats (config default if unset)
+    pub progress_interval: Option<Duration>,
+    /// Reporters installed in addition to the config's
+    pub reporters: Vec<ReporterKind>,
+    /// When to color console output (config default if unset)
+    pub color: Option<ColorMode>,
+    /// Console line width (the terminal's if unset)
+    pub width: Option<usize>,
+    /// Units for byte throughput (config default if unset)
+    pub byte_units: Option<ByteUnits>,
+    /// Unit for durations (config default if unset)
+    pub time_unit: Option<TimeUnit>,
+    /// Extra console columns (config default if unset)
+    pub columns: Option<Vec<ConsoleColumn>>,
+    /// Order of console result lines (config default if unset)
+    pub sort: Option<ConsoleSort>,
+    /// Results file name template (config default if unset)
+    pub file_name: Option<String>,
+    /// Gzip results files
+    pub compress: bool,
+    /// Record the host name in results
+    pub record_hostname: bool,
+    /// Runs to keep per suite (config default if unset)
+    pub keep: Option<Retention>,
+    /// How `latest.json` refers to the newest run (config default if unset)
+    pub latest: Option<LatestMode>,
 }
 
 impl StressRunnerOptions {
@@ -282,6 +1157,21 @@ impl StressRunnerOptions {
         self
     }
 
+    pub fn filter_mode(mut self, mode: FilterMode) -> Self {
+        self.filter_mode = mode;
+        self
+    }
+
+    pub fn select(mut self, expr: impl Into<String>) -> Self {
+        self.select = Some(expr.into());
+        self
+    }
+
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
     pub fn runs(mut self, n: usize) -> Self {
         self.runs = Some(n);
         self
@@ -311,6 +1201,177 @@ impl StressRunnerOptions {
         self.threshold = t;
         self
     }
+
+    pub fn timeout(mut self, d: Duration) -> Self {
+        self.timeout = Some(d);
+        self
+    }
+
+    pub fn force(mut self, v: bool) -> Self {
+        self.force = v;
+        self
+    }
+
+    pub fn max_runs(mut self, n: usize) -> Self {
+        self.max_runs = Some(n);
+        self
+    }
+
+    pub fn max_warmup(mut self, n: usize) -> Self {
+        self.max_warmup = Some(n);
+        self
+    }
+
+    pub fn max_timeout(mut self, d: Duration) -> Self {
+        self.max_timeout = Some(d);
+        self
+    }
+
+    pub fn adaptive(mut self, adaptive: AdaptiveConfig) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    pub fn order(mut self, order: BenchOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn interleave(mut self, interleave: bool) -> Self {
+        self.interleave = interleave;
+        self
+    }
+
+    /// Run each benchmark in its own child process.
+    ///
+    /// Required for [`kill_after`](crate::faults::FaultPlan::kill_after)
+    /// faults. Interleaving does not apply in isolated mode.
+    pub fn isolated(mut self, isolated: bool) -> Self {
+        self.isolated = isolated;
+        self
+    }
+
+    /// Set default resource limits. Benchmarks with limits always run isolated.
+    pub fn limits(mut self, limits: ResourceLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Enforce memory and CPU limits through cgroup v2 when available.
+    pub fn cgroup(mut self, cgroup: bool) -> Self {
+        self.cgroup = cgroup;
+        self
+    }
+
+    /// Pin the benchmark thread to the first of `cpus` and workers to the rest.
+    pub fn pin(mut self, cpus: impl Into<Vec<usize>>) -> Self {
+        self.pin = cpus.into();
+        self
+    }
+
+    /// Set the scheduling priority of the benchmark thread.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Soak the selected benchmarks instead of making a fixed number of runs.
+    ///
+    /// In isolated mode each benchmark soaks for the full duration in its
+    /// own process.
+    pub fn soak(mut self, soak: SoakConfig) -> Self {
+        self.soak = Some(soak);
+        self
+    }
+
+    /// Set when progress heartbeats are printed.
+    pub fn progress(mut self, mode: ProgressMode) -> Self {
+        self.progress = Some(mode);
+        self
+    }
+
+    /// Set the time between progress heartbeats.
+    pub fn progress_interval(mut self, interval: Duration) -> Self {
+        self.progress_interval = Some(interval);
+        self
+    }
+
+    /// Install an extra reporter, e.g. [`ReporterKind::Github`].
+    pub fn reporter(mut self, kind: ReporterKind) -> Self {
+        self.reporters.push(kind);
+        self
+    }
+
+    /// Set when console output is colored.
+    pub fn color(mut self, color: ColorMode) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Fit console lines into `width` columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the units for byte throughput.
+    pub fn byte_units(mut self, units: ByteUnits) -> Self {
+        self.byte_units = Some(units);
+        self
+    }
+
+    /// Show all durations in one unit.
+    pub fn time_unit(mut self, unit: TimeUnit) -> Self {
+        self.time_unit = Some(unit);
+        self
+    }
+
+    /// Show these columns after the median.
+    pub fn columns(mut self, columns: Vec<ConsoleColumn>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set the order of console result lines.
+    pub fn sort(mut self, sort: ConsoleSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the results file name template, e.g. `{date}-{branch}-{sha}`.
+    pub fn file_name(mut self, template: impl Into<String>) -> Self {
+        self.file_name = Some(template.into());
+        self
+    }
+
+    /// Gzip results files (needs the `gzip` feature).
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Record the host name in the suite environment.
+    pub fn record_hostname(mut self, record: bool) -> Self {
+        self.record_hostname = record;
+        self
+    }
+
+    /// Set which runs to keep per suite.
+    pub fn keep(mut self, retention: Retention) -> Self {
+        self.keep = Some(retention);
+        self
+    }
+
+    /// Set how `latest.json` refers to the newest run.
+    pub fn latest(mut self, latest: LatestMode) -> Self {
+        self.latest = Some(latest);
+        self
+    }
 }
 
 /// Run all registered benchmarks with default options.
@@ -350,24 +1411,25 @@ fn get_suite_name() -> String {
 
 /// Run all registered benchmarks with custom options.
 pub fn run_with_options(opts: StressRunnerOptions) {
-    let benchmarks: Vec<_> = STRESS_BENCHMARKS
+    exit_on_duplicate_names();
+
+    let selection = match Selection::from_options(&opts) {
+        Ok(selection) => selection,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Selected but ignored benchmarks are reported as skipped
+    let (benchmarks, ignored): (Vec<_>, Vec<_>) = STRESS_BENCHMARKS
         .iter()
-        .filter(|b| {
-            // Filter by ignored status
-            if b.ignored && !opts.include_ignored {
-                return false;
-            }
-            // Filter by workload pattern
-            if let Some(ref pattern) = opts.workload {
-                return matches_glob(b.name, pattern) || matches_glob(b.module_path, pattern);
-            }
-            true
-        })
-        .collect();
+        .filter(|b| selection.matches(b))
+        .partition(|b| !b.ignored || opts.include_ignored);
 
     if benchmarks.is_empty() {
-        if opts.workload.is_some() {
-            eprintln!("No benchmarks matched the workload pattern");
+        if !selection.is_empty() {
+            eprintln!("No benchmarks matched the selection");
         } else {
             eprintln!("No benchmarks registered. Add #[stress_test] to your benchmark functions.");
         }
@@ -382,82 +1444,324 @@ pub fn run_with_options(opts: StressRunnerOptions) {
     if let Some(w) = opts.warmup {
         config.warmup_runs = w;
     }
+    if let Some(t) = opts.timeout {
+        config.timeout = Some(t);
+    }
+    if let Some(n) = opts.max_runs {
+        config.max_runs = Some(n);
+    }
+    if let Some(n) = opts.max_warmup {
+        config.max_warmup = Some(n);
+    }
+    if let Some(t) = opts.max_timeout {
+        config.max_timeout = Some(t);
+    }
+    if let Some(adaptive) = opts.adaptive {
+        config.adaptive = Some(adaptive);
+    }
+    if let Some(seed) = opts.seed {
+        config.seed = Some(seed);
+    }
+    if let Some(order) = opts.order {
+        config.order = order;
+    }
+    config.force = config.force || opts.force;
+    config.interleave = config.interleave || opts.interleave;
+    config.limits = opts.limits.or(config.limits);
+    config.cgroup = config.cgroup || opts.cgroup;
+    if !opts.pin.is_empty() {
+        config.pin = opts.pin;
+    }
+    if let Some(priority) = opts.priority {
+        config.priority = Some(priority);
+    }
+    if let Some(soak) = opts.soak {
+        config.soak = Some(soak);
+    }
+    if let Some(mode) = opts.progress {
+        config.progress = mode;
+    }
+    if let Some(interval) = opts.progress_interval {
+        config.progress_interval = interval;
+    }
+    for kind in opts.reporters {
+        config = config.reporter(kind);
+    }
+    if let Some(color) = opts.color {
+        config.console.color = color;
+    }
+    if let Some(width) = opts.width {
+        config.console.width = Some(width);
+    }
+    if let Some(units) = opts.byte_units {
+        config.console.byte_units = units;
+    }
+    if let Some(unit) = opts.time_unit {
+        config.console.time_unit = unit;
+    }
+    if let Some(columns) = opts.columns {
+        config.console.columns = columns;
+    }
+    if let Some(sort) = opts.sort {
+        config.console.sort = sort;
+    }
+    if let Some(template) = opts.file_name {
+        config.layout.file_name = template;
+    }
+    config.layout.compress = config.layout.compress || opts.compress;
+    config.record_hostname = config.record_hostname || opts.record_hostname;
+    if let Some(retention) = opts.keep {
+        config.layout.retention = retention;
+    }
+    if let Some(latest) = opts.latest {
+        config.layout.latest = latest;
+    }
+    if let Some(baseline) = &opts.baseline {
+        config.baseline = Some(baseline.clone());
+    }
+    config.threshold = opts.threshold;
     config.verbose = opts.verbose;
 
     let suite_name = get_suite_name();
     let mut runner = BenchRunner::with_config(&suite_name, config);
+    // Failures are reported and set the exit code
+    runner.contain_failures();
+    if !isolate::is_child() {
+        for bench in &ignored {
+            let name = format!("{}::{}", bench.module_path, bench.name);
+            runner.skip(&name, bench.ignore_reason.unwrap_or("ignored"));
+        }
+    }
+
+    // Run benchmarks in the configured order
+    let cases: Vec<_> = benchmarks
+        .iter()
+        .map(|bench| {
+            let name = format!("{}::{}", bench.module_path, bench.name);
+            let mut case = BenchCase::new(name, &bench.func).overrides(bench.overrides());
+            for tag in bench.tags {
+                case = case.tag(*tag);
+            }
+            if let Some(group) = bench.group {
+                case = case.group(group);
+            }
+            case
+        })
+        .collect();
 
-    // Run each benchmark
-    for bench in &benchmarks {
-        let name = format!("{}::{}", bench.module_path, bench.name);
-        runner.run(&name, bench.func);
+    if isolate::is_child() {
+        // The parent reports, compares and decides the exit status
+        runner.run_all(cases);
+        runner.finish();
+        return;
     }
 
+    // Limits are process-wide, so limited benchmarks need their own process
+    let isolated = opts.isolated
+        || cases
+            .iter()
+            .any(|c| !runner.settings(c.overrides_ref()).limits.is_empty());
+
+    if isolated {
+        let cases: Vec<_> = cases
+            .iter()
+            .map(|c| (c.name().to_string(), c.overrides_ref().clone()))
+            .collect();
+        let cases = runner.schedule(cases, |(name, _)| name.as_str());
+        isolate::run_isolated(&mut runner, &cases);
+    } else {
+        runner.run_all(cases);
+    }
+    let failed = runner.failures().to_vec();
+
     // Finish and check for regressions
-    if let Some(baseline_path) = opts.baseline {
-        let (_results, regressions) = runner.finish_with_baseline(baseline_path, opts.threshold);
-        if !regressions.is_empty() {
-            eprintln!("\n❌ {} regression(s) detected!", regressions.len());
-
-            for (result, ratio) in &regressions {
-                let pct = (ratio - 1.0) * 100.0;
-                eprintln!("  {} is {:.1}% slower", result.name, pct);
-            }
-            std::process::exit(1);
-        }
+    let (results, regressions) = if let Some(baseline_path) = opts.baseline {
+        runner.finish_with_baseline(baseline_path, opts.threshold)
     } else {
-        let _results = runner.finish();
         // Summary already printed by ConsoleReporter
+        (runner.finish(), Vec::new())
+    };
+
+    let timed_out: Vec<_> = results.iter().filter(|r| r.timed_out).collect();
+    if !timed_out.is_empty() {
+        eprintln!("\n❌ {} benchmark(s) timed out!", timed_out.len());
+        for result in &timed_out {
+            let limit = result.timeout.unwrap_or_default();
+            eprintln!("  {} exceeded {:.2}s", result.name, limit.as_secs_f64());
+        }
+    }
+
+    let drifted: Vec<_> = results
+        .iter()
+        .filter_map(|r| r.soak.as_ref().filter(|s| s.drift_exceeded).map(|s| (r, s)))
+        .collect();
+    if !drifted.is_empty() {
+        eprintln!(
+            "\n❌ {} benchmark(s) drifted during the soak!",
+            drifted.len()
+        );
+        for (result, soak) in &drifted {
+            let mut drifts = Vec::new();
+            if let Some(d) = soak.duration_drift {
+                drifts.push(format!("duration {:+.1}%", d * 100.0));
+            }
+            if let Some(d) = soak.rss_drift {
+                drifts.push(format!("RSS {:+.1}%", d * 100.0));
+            }
+            eprintln!(
+                "  {}: {} (limit {:.1}%)",
+                result.name,
+                drifts.join(", "),
+                soak.max_drift * 100.0
+            );
+        }
+    }
+
+    // Each regression is marked on its console line
+    if !regressions.is_empty() {
+        eprintln!("\n❌ {} regression(s) detected!", regressions.len());
+    }
+
+    if !failed.is_empty() {
+        eprintln!("\n❌ {} benchmark(s) failed!", failed.len());
+        for failure in &failed {
+            eprintln!("  {}: {}", failure.name, failure.message);
+        }
     }
+
+    if !failed.is_empty() || !timed_out.is_empty() || !drifted.is_empty() || !regressions.is_empty()
+    {
+        std::process::exit(1);
+    }
+}
+
+/// Compiled `--workload`, `--select` and `--exclude` filters.
+#[derive(Default)]
+struct Selection {
+    workload: Option<NameFilter>,
+    selector: Option<Selector>,
+    exclude: Option<NameFilter>,
 }
 
-/// Simple glob matching supporting * and ?
-fn matches_glob(text: &str, pattern: &str) -> bool {
-    let pattern = pattern.to_lowercase();
-    let text = text.to_lowercase();
+impl Selection {
+    fn from_options(opts: &StressRunnerOptions) -> Result<Self, String> {
+        let workload = opts
+            .workload
+            .as_deref()
+            .map(|spec| NameFilter::new(spec, opts.filter_mode))
+            .transpose()
+            .map_err(|e| format!("invalid --workload: {}", e))?;
+        let selector = opts
+            .select
+            .as_deref()
+            .map(Selector::parse)
+            .transpose()
+            .map_err(|e| format!("invalid --select expression: {}", e))?;
+        let exclude = if opts.exclude.is_empty() {
+            None
+        } else {
+            let spec = opts.exclude.join(",");
+            Some(
+                NameFilter::new(&spec, FilterMode::Glob)
+                    .map_err(|e| format!("invalid --exclude: {}", e))?,
+            )
+        };
 
-    // Convert glob to a simple check
-    if pattern.contains('*') {
-        // Split by * and check if all parts appear in order
-        let parts: Vec<&str> = pattern.split('*').collect();
-        let mut remaining = text.as_str();
+        Ok(Self {
+            workload,
+            selector,
+            exclude,
+        })
+    }
 
-        for (i, part) in parts.iter().enumerate() {
-            if part.is_empty() {
-                continue;
+    fn is_empty(&self) -> bool {
+        self.workload.is_none() && self.selector.is_none() && self.exclude.is_none()
+    }
+
+    /// Whether a benchmark passes all filters.
+    ///
+    /// Name patterns are tried against the bare name, the module path and the
+    /// qualified `module::name`.
+    fn matches(&self, bench: &BenchmarkEntry) -> bool {
+        let qualified = format!("{}::{}", bench.module_path, bench.name);
+        let names = [bench.name, bench.module_path, qualified.as_str()];
+
+        if let Some(ref workload) = self.workload {
+            if !workload.matches_any(&names) {
+                return false;
             }
-            if i == 0 {
-                // First part must be at the start
-                if !remaining.starts_with(part) {
-                    return false;
-                }
-                remaining = &remaining[part.len()..];
-            } else if i == parts.len() - 1 && !pattern.ends_with('*') {
-                // Last part must be at the end
-                if !remaining.ends_with(part) {
-                    return false;
-                }
-            } else {
-                // Middle parts just need to exist
-                if let Some(pos) = remaining.find(part) {
-                    remaining = &remaining[pos + part.len()..];
-                } else {
-                    return false;
-                }
+        }
+        if let Some(ref selector) = self.selector {
+            if !selector.matches(bench.tags, bench.group) {
+                return false;
             }
         }
-        true
-    } else {
-        // No wildcards - substring match
-        text.contains(&pattern)
+        !self
+            .exclude
+            .as_ref()
+            .is_some_and(|exclude| exclude.matches_any(&names))
+    }
+}
+
+/// Format one `--list` line: name followed by group and tags, if any.
+fn format_list_entry(bench: &BenchmarkEntry) -> String {
+    let mut line = bench.name.to_string();
+    if let Some(group) = bench.group {
+        line.push_str(&format!("  [group: {}]", group));
+    }
+    if !bench.tags.is_empty() {
+        line.push_str(&format!("  [tags: {}]", bench.tags.join(", ")));
+    }
+    match (bench.ignored, bench.ignore_reason) {
+        (true, Some(reason)) => line.push_str(&format!("  [ignored: {}]", reason)),
+        (true, None) => line.push_str("  [ignored]"),
+        _ => {}
+    }
+    line
+}
+
+/// Find fully qualified benchmark names that are registered more than once.
+///
+/// Names are qualified by module path, so only entries in the same module with
+/// the same (custom) name collide. Returned names are sorted and deduplicated.
+fn find_duplicate_names(entries: &[BenchmarkEntry]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates: Vec<String> = entries
+        .iter()
+        .map(|b| format!("{}::{}", b.module_path, b.name))
+        .filter(|name| !seen.insert(name.clone()))
+        .collect();
+    duplicates.sort();
+    duplicates.dedup();
+    duplicates
+}
+
+/// Abort the binary if two registered benchmarks share a name.
+///
+/// Results are keyed by name, so duplicates would silently overwrite each
+/// other in JSON output and baseline comparisons.
+fn exit_on_duplicate_names() {
+    let duplicates = find_duplicate_names(&STRESS_BENCHMARKS);
+    if duplicates.is_empty() {
+        return;
+    }
+
+    eprintln!(
+        "error: {} benchmark name(s) registered more than once:",
+        duplicates.len()
+    );
+    for name in &duplicates {
+        eprintln!("  {}", name);
     }
+    eprintln!("Rename the functions or use #[stress_test(name = \"...\")] to disambiguate.");
+    std::process::exit(1);
 }
 
-/// Get a list of all registered benchmark names.
+/// Describe all registered benchmarks.
 ///
 /// Useful for tooling and IDE integration.
-pub fn list_benchmarks() -> Vec<&'static str> {
-    STRESS_BENCHMARKS.iter().map(|b| b.name).collect()
+pub fn list_benchmarks() -> Vec<BenchmarkInfo> {
+    STRESS_BENCHMARKS.iter().map(BenchmarkInfo::from).collect()
 }
 
 /// Get count of registered benchmarks.
@@ -469,24 +1773,166 @@ pub fn benchmark_count() -> usize {
 mod tests {
     use super::*;
 
+    fn entry(name: &'static str, module_path: &'static str) -> BenchmarkEntry {
+        fn noop(ctx: &mut StressContext) {
+            ctx.measure(|| {});
+        }
+        BenchmarkEntry {
+            name,
+            func: noop,
+            ignored: false,
+            ignore_reason: None,
+            module_path,
+            file: "benches/db.rs",
+            line: 1,
+            runs: None,
+            warmup: None,
+            timeout_secs: None,
+            tags: &[],
+            group: None,
+            memory_limit: None,
+            cpus: None,
+            open_files: None,
+        }
+    }
+
     #[test]
-    fn glob_matches_substring() {
-        assert!(matches_glob("foo_bar_baz", "bar"));
-        assert!(!matches_glob("foo_bar_baz", "qux"));
+    fn should_detect_duplicate_names_in_same_module() {
+        let entries = [
+            entry("insert", "bench::db"),
+            entry("insert", "bench::db"),
+            entry("insert", "bench::kv"),
+            entry("scan", "bench::db"),
+        ];
+        assert_eq!(find_duplicate_names(&entries), vec!["bench::db::insert"]);
     }
 
     #[test]
-    fn glob_matches_wildcard() {
-        assert!(matches_glob("foo_bar_baz", "foo*baz"));
-        assert!(matches_glob("foo_bar_baz", "*bar*"));
-        assert!(matches_glob("foo_bar_baz", "foo*"));
-        assert!(matches_glob("foo_bar_baz", "*baz"));
-        assert!(!matches_glob("foo_bar_baz", "qux*"));
+    fn should_allow_same_name_in_different_modules() {
+        let entries = [entry("same", "bench::a"), entry("same", "bench::b")];
+        assert!(find_duplicate_names(&entries).is_empty());
+    }
+
+    #[test]
+    fn should_convert_attribute_settings_to_overrides() {
+        let mut e = entry("compaction", "bench::db");
+        e.runs = Some(1);
+        e.timeout_secs = Some(1200);
+
+        let overrides = e.overrides();
+        assert_eq!(overrides.runs, Some(1));
+        assert_eq!(overrides.warmup, None);
+        assert_eq!(overrides.timeout, Some(Duration::from_secs(1200)));
+    }
+
+    #[test]
+    fn should_select_by_tags_and_exclude_by_glob() {
+        let mut disk = entry("write_log", "bench::wal");
+        disk.tags = &["disk"];
+        let mut slow = entry("compact_all", "bench::lsm");
+        slow.tags = &["disk", "slow"];
+
+        let opts = StressRunnerOptions::new().select("disk && !slow");
+        let selection = Selection::from_options(&opts).unwrap();
+        assert!(selection.matches(&disk));
+        assert!(!selection.matches(&slow));
+
+        let opts = StressRunnerOptions::new().exclude("bench::wal::*");
+        let selection = Selection::from_options(&opts).unwrap();
+        assert!(!selection.matches(&disk));
+        assert!(selection.matches(&slow));
+    }
+
+    #[test]
+    fn should_match_workload_against_qualified_name() {
+        let e = entry("insert", "bench::db");
+
+        let opts = StressRunnerOptions::new().workload("bench::db::ins*");
+        assert!(Selection::from_options(&opts).unwrap().matches(&e));
+
+        let opts = StressRunnerOptions::new()
+            .workload("bench::db::insert")
+            .filter_mode(FilterMode::Exact);
+        assert!(Selection::from_options(&opts).unwrap().matches(&e));
+
+        let opts = StressRunnerOptions::new()
+            .workload("^ins")
+            .filter_mode(FilterMode::Regex);
+        assert!(Selection::from_options(&opts).unwrap().matches(&e));
+
+        let opts = StressRunnerOptions::new()
+            .workload("[")
+            .filter_mode(FilterMode::Glob);
+        assert!(Selection::from_options(&opts).is_err());
+    }
+
+    #[test]
+    fn should_show_group_and_tags_in_list() {
+        let mut e = entry("write_log", "bench::wal");
+        e.tags = &["disk", "slow"];
+        e.group = Some("io");
+        assert_eq!(
+            format_list_entry(&e),
+            "write_log  [group: io]  [tags: disk, slow]"
+        );
+    }
+
+    #[test]
+    fn should_show_ignore_reason_in_list() {
+        let mut e = entry("raw_device", "bench::disk");
+        e.ignored = true;
+        e.ignore_reason = Some("needs /dev/nvme0");
+        assert_eq!(
+            format_list_entry(&e),
+            "raw_device  [ignored: needs /dev/nvme0]"
+        );
+    }
+
+    #[test]
+    fn should_describe_entry_as_benchmark_info() {
+        let mut e = entry("insert", "bench::db");
+        e.runs = Some(3);
+        e.tags = &["disk"];
+        e.line = 42;
+
+        let info = BenchmarkInfo::from(&e);
+        assert_eq!(info.full_name, "bench::db::insert");
+        assert_eq!(info.file, "benches/db.rs");
+        assert_eq!(info.line, 42);
+        assert_eq!(info.tags, vec!["disk"]);
+        assert_eq!(info.parameters.runs, Some(3));
+        assert_eq!(info.parameters.timeout_secs, None);
+    }
+
+    #[test]
+    fn should_format_list_as_terse_lines() {
+        let infos = [
+            BenchmarkInfo::from(&entry("insert", "bench::db")),
+            BenchmarkInfo::from(&entry("scan", "bench::db")),
+        ];
+        assert_eq!(
+            format_benchmark_list(&infos, ListFormat::Terse),
+            "bench::db::insert: bench\nbench::db::scan: bench"
+        );
+    }
+
+    #[test]
+    fn should_round_trip_list_as_json() {
+        let mut e = entry("insert", "bench::db");
+        e.ignored = true;
+        e.ignore_reason = Some("slow");
+        let infos = vec![BenchmarkInfo::from(&e)];
+
+        let json = format_benchmark_list(&infos, ListFormat::Json);
+        let list: BenchmarkList = serde_json::from_str(&json).unwrap();
+        assert_eq!(list.benchmarks, infos);
+        assert!(!json.contains("\"binary\""));
     }
 
     #[test]
-    fn glob_is_case_insensitive() {
-        assert!(matches_glob("FooBar", "foobar"));
-        assert!(matches_glob("foobar", "FOO*"));
+    fn should_parse_list_format() {
+        assert_eq!("JSON".parse::<ListFormat>(), Ok(ListFormat::Json));
+        assert_eq!("terse".parse::<ListFormat>(), Ok(ListFormat::Terse));
+        assert!("xml".parse::<ListFormat>().is_err());
     }
 }
@@ -0,0 +1,476 @@
+//! Self-contained HTML report for sharing results outside the terminal.
+//!
+//! Each suite is written to `{output_dir}/{suite}/report.html`: a single
+//! file with inline styles, a small inline script that makes the results
+//! table sortable, and inline SVG charts, so it opens offline and can be
+//! attached to an email as is. It contains:
+//!
+//! - the results table, with the change against the baseline if one is set;
+//! - a box plot of the measured runs of each benchmark, with every run as a
+//!   dot and the baseline median as a dashed line;
+//! - throughput bars for benchmarks that report bytes or elements;
+//! - the git SHA, seed and machine the suite ran on.
+
+use crate::report::{
+    escape_xml, format_duration, load_baseline, short_name, suite_path_name, ConsoleReporter,
+    Reporter,
+};
+use crate::result::{BenchResult, SuiteResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const STYLE: &str = "\
+body{font-family:system-ui,sans-serif;margin:2em auto;max-width:1100px;padding:0 1em;color:#222}
+h1{margin-bottom:.2em}.meta{color:#666;margin-top:0}
+table{border-collapse:collapse;width:100%;margin:1em 0}
+th,td{padding:.35em .6em;border-bottom:1px solid #ddd;text-align:right;white-space:nowrap}
+th:first-child,td:first-child{text-align:left}
+table.sortable th{cursor:pointer;user-select:none;background:#f5f5f5}
+table.sortable th:hover{background:#e8e8e8}
+tr.regression td{background:#fdecea}tr.improvement td{background:#e9f7ef}
+.env th{text-align:left;width:12em;background:none;font-weight:normal;color:#666}
+.env td{text-align:left}
+figure{margin:1em 0}figcaption{font-family:monospace;margin-bottom:.2em}
+svg text{font:11px system-ui,sans-serif;fill:#444}
+";
+
+/// Sorts the clicked column of a `table.sortable`, numerically by the
+/// cells' `data-value` where present.
+const SCRIPT: &str = "\
+document.querySelectorAll('table.sortable th').forEach(function(th,col){
+th.addEventListener('click',function(){
+var body=th.closest('table').tBodies[0],asc=th.dataset.dir!=='asc';
+th.parentNode.querySelectorAll('th').forEach(function(h){delete h.dataset.dir});
+th.dataset.dir=asc?'asc':'desc';
+var key=function(row){var c=row.cells[col],v=c.dataset.value;
+return v!==undefined&&v!==''?parseFloat(v):c.textContent.toLowerCase()};
+Array.from(body.rows).sort(function(a,b){var x=key(a),y=key(b);
+var o=(typeof x===typeof y)?(x<y?-1:x>y?1:0):(typeof x==='number'?-1:1);
+return asc?o:-o}).forEach(function(r){body.appendChild(r)});
+})});
+";
+
+const PLOT_WIDTH: f64 = 640.0;
+const PLOT_PADDING: f64 = 12.0;
+
+/// Reporter that writes a self-contained HTML report per suite.
+pub struct HtmlReporter {
+    output_dir: PathBuf,
+    baseline: Option<SuiteResult>,
+    threshold: f64,
+}
+
+impl HtmlReporter {
+    /// Write `{output_dir}/{suite}/report.html`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            baseline: None,
+            threshold: 0.05,
+        }
+    }
+
+    /// Load baseline from a file for comparison.
+    pub fn with_baseline(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.baseline = load_baseline(path.as_ref());
+        self
+    }
+
+    /// Set the regression threshold (default: 0.05 = 5%).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn format_suite(&self, suite: &SuiteResult) -> String {
+        let previous: HashMap<&str, &BenchResult> = self
+            .baseline
+            .iter()
+            .flat_map(|b| &b.results)
+            .map(|r| (r.name.as_str(), r))
+            .collect();
+
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Benchmarks: {suite}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n\
+             <h1>Benchmarks: {suite}</h1>\n<p class=\"meta\">{count} benchmark(s) in {total}</p>\n",
+            suite = escape_xml(&suite.suite),
+            count = suite.results.len(),
+            total = format_duration(suite.total_duration),
+        );
+
+        html.push_str("<h2>Results</h2>\n");
+        html.push_str(&self.results_table(suite, &previous));
+        html.push_str(&Self::problems(suite));
+
+        if suite.results.iter().any(|r| !r.all_runs.is_empty()) {
+            html.push_str("<h2>Run distribution</h2>\n");
+            for result in suite.results.iter().filter(|r| !r.all_runs.is_empty()) {
+                html.push_str(&format!(
+                    "<figure>\n<figcaption>{}</figcaption>\n{}</figure>\n",
+                    escape_xml(short_name(&result.name)),
+                    box_plot(result, previous.get(result.name.as_str()).copied())
+                ));
+            }
+        }
+
+        let bytes: Vec<_> = suite
+            .results
+            .iter()
+            .filter_map(|r| r.bytes_per_sec().map(|v| (r, v)))
+            .collect();
+        let elements: Vec<_> = suite
+            .results
+            .iter()
+            .filter(|r| r.bytes_per_sec().is_none())
+            .filter_map(|r| r.elements_per_sec().map(|v| (r, v)))
+            .collect();
+        if !bytes.is_empty() || !elements.is_empty() {
+            html.push_str("<h2>Throughput</h2>\n");
+            for bars in [bytes, elements].iter().filter(|b| !b.is_empty()) {
+                html.push_str(&throughput_chart(bars));
+            }
+        }
+
+        html.push_str("<h2>Environment</h2>\n");
+        html.push_str(&Self::environment(suite));
+        html.push_str(&format!(
+            "<script>\n{}</script>\n</body>\n</html>\n",
+            SCRIPT
+        ));
+        html
+    }
+
+    fn results_table(&self, suite: &SuiteResult, previous: &HashMap<&str, &BenchResult>) -> String {
+        let compare = self.baseline.is_some();
+        let mut table = String::from(
+            "<table class=\"sortable\">\n<thead><tr><th>Benchmark</th><th>Median</th>\
+             <th>Mean</th><th>Min</th><th>Max</th><th>Std dev</th><th>p95</th><th>Runs</th>\
+             <th>Throughput</th>",
+        );
+        if compare {
+            table.push_str("<th>Baseline</th><th>Change</th>");
+        }
+        table.push_str("</tr></thead>\n<tbody>\n");
+
+        for result in &suite.results {
+            let before = previous.get(result.name.as_str()).copied();
+            let ratio = before.map(|b| result.compare(b));
+            let class = match ratio {
+                Some(ratio) if ratio > 1.0 + self.threshold => " class=\"regression\"",
+                Some(ratio) if ratio < 1.0 - self.threshold => " class=\"improvement\"",
+                _ => "",
+            };
+            let throughput = result
+                .bytes_per_sec()
+                .or_else(|| result.elements_per_sec())
+                .map(|v| format!("{:.0}", v))
+                .unwrap_or_default();
+
+            table.push_str(&format!(
+                "<tr{}><td>{}</td>{}{}{}{}{}{}<td>{}</td><td data-value=\"{}\">{}</td>",
+                class,
+                escape_xml(short_name(&result.name)),
+                duration_cell(Some(result.duration)),
+                duration_cell(Some(result.mean())),
+                duration_cell(Some(result.min_duration())),
+                duration_cell(Some(result.max_duration())),
+                duration_cell(result.std_dev()),
+                duration_cell(Some(result.percentile(95.0))),
+                result.runs,
+                throughput,
+                escape_xml(&ConsoleReporter::format_throughput(result)),
+            ));
+            if compare {
+                table.push_str(&duration_cell(before.map(|b| b.duration)));
+                table.push_str(&match ratio {
+                    Some(ratio) => format!(
+                        "<td data-value=\"{:.4}\">{:+.1}%</td>",
+                        ratio - 1.0,
+                        (ratio - 1.0) * 100.0
+                    ),
+                    None => "<td data-value=\"\">new</td>".to_string(),
+                });
+            }
+            table.push_str("</tr>\n");
+        }
+        table.push_str("</tbody>\n</table>\n");
+        table
+    }
+
+    /// Failed and skipped benchmarks, if any.
+    fn problems(suite: &SuiteResult) -> String {
+        let mut out = String::new();
+        if !suite.failures.is_empty() {
+            out.push_str("<h3>Failed</h3>\n<ul>\n");
+            for f in &suite.failures {
+                out.push_str(&format!(
+                    "<li><code>{}</code>: {}</li>\n",
+                    escape_xml(short_name(&f.name)),
+                    escape_xml(&f.message)
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+        if !suite.skipped.is_empty() {
+            out.push_str("<h3>Skipped</h3>\n<ul>\n");
+            for s in &suite.skipped {
+                out.push_str(&format!(
+                    "<li><code>{}</code>: {}</li>\n",
+                    escape_xml(short_name(&s.name)),
+                    escape_xml(&s.reason)
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+        out
+    }
+
+    fn environment(suite: &SuiteResult) -> String {
+        let mut rows: Vec<(String, String)> = Vec::new();
+        if let Some(sha) = &suite.git_sha {
+            rows.push(("Git SHA".to_string(), sha.clone()));
+        }
+        rows.push(("Started at".to_string(), suite.started_at.clone()));
+        if let Some(seed) = suite.seed {
+            rows.push(("Seed".to_string(), seed.to_string()));
+        }
+        if let Some(env) = &suite.environment {
+            rows.push(("OS".to_string(), format!("{} ({})", env.os, env.arch)));
+            rows.push(("CPUs".to_string(), env.cpus.to_string()));
+            let optional = [
+                ("CPU model", env.cpu_model.clone()),
+                ("Kernel", env.kernel.clone()),
+                (
+                    "Memory",
+                    env.memory
+                        .map(|m| format!("{:.1} GiB", m as f64 / (1u64 << 30) as f64)),
+                ),
+                ("Host", env.hostname.clone()),
+            ];
+            for (name, value) in optional {
+                if let Some(value) = value {
+                    rows.push((name.to_string(), value));
+                }
+            }
+            rows.push(("cntryl-stress".to_string(), env.version.clone()));
+        }
+        let mut metadata: Vec<_> = suite.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            rows.push((key.clone(), value.clone()));
+        }
+
+        let mut table = String::from("<table class=\"env\">\n");
+        for (name, value) in rows {
+            table.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                escape_xml(&name),
+                escape_xml(&value)
+            ));
+        }
+        table.push_str("</table>\n");
+        table
+    }
+
+    fn write(&self, suite: &SuiteResult) -> std::io::Result<PathBuf> {
+        let suite_dir = self.output_dir.join(suite_path_name(&suite.suite));
+        std::fs::create_dir_all(&suite_dir)?;
+        let path = suite_dir.join("report.html");
+        std::fs::write(&path, self.format_suite(suite))?;
+        Ok(path)
+    }
+}
+
+impl Reporter for HtmlReporter {
+    fn suite_end(&self, result: &SuiteResult) {
+        match self.write(result) {
+            Ok(path) => eprintln!("  HTML report at: {}", path.display()),
+            Err(e) => eprintln!("Warning: failed to write HTML report: {}", e),
+        }
+    }
+}
+
+/// Table cell with the duration shown and its nanoseconds to sort by.
+fn duration_cell(duration: Option<Duration>) -> String {
+    match duration {
+        Some(d) => format!(
+            "<td data-value=\"{}\">{}</td>",
+            d.as_nanos(),
+            format_duration(d)
+        ),
+        None => "<td data-value=\"\"></td>".to_string(),
+    }
+}
+
+/// Horizontal box plot of the measured runs: whiskers at min and max, box
+/// from p25 to p75, the median, each run as a dot, and the baseline median
+/// as a dashed line.
+fn box_plot(result: &BenchResult, baseline: Option<&BenchResult>) -> String {
+    let nanos = |d: Duration| d.as_nanos() as f64;
+    let min = nanos(result.min_duration());
+    let max = nanos(result.max_duration());
+    let reference = baseline.map(|b| nanos(b.duration));
+    let lo = reference.map_or(min, |r| r.min(min));
+    let hi = reference.map_or(max, |r| r.max(max));
+    let span = if hi > lo { hi - lo } else { 1.0 };
+    let x = |v: f64| PLOT_PADDING + (v - lo) / span * (PLOT_WIDTH - 2.0 * PLOT_PADDING);
+
+    let (q1, median, q3) = (
+        x(nanos(result.percentile(25.0))),
+        x(nanos(result.percentile(50.0))),
+        x(nanos(result.percentile(75.0))),
+    );
+    let mut svg = format!(
+        "<svg width=\"{w}\" height=\"70\" viewBox=\"0 0 {w} 70\" role=\"img\">\n\
+         <line x1=\"{:.1}\" y1=\"28\" x2=\"{:.1}\" y2=\"28\" stroke=\"#888\"/>\n\
+         <rect x=\"{:.1}\" y=\"16\" width=\"{:.1}\" height=\"24\" fill=\"#cfe2f3\" stroke=\"#3d85c6\"/>\n\
+         <line x1=\"{m:.1}\" y1=\"16\" x2=\"{m:.1}\" y2=\"40\" stroke=\"#0b5394\" stroke-width=\"2\"/>\n",
+        x(min),
+        x(max),
+        q1,
+        (q3 - q1).max(1.0),
+        w = PLOT_WIDTH,
+        m = median,
+    );
+    for (i, run) in result.all_runs.iter().enumerate() {
+        // Spread dots vertically so repeated values stay visible
+        let jitter = (i * 7 % 17) as f64 - 8.0;
+        svg.push_str(&format!(
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"2\" fill=\"#333\" fill-opacity=\"0.5\"><title>run {}: {}</title></circle>\n",
+            x(nanos(*run)),
+            28.0 + jitter,
+            i,
+            format_duration(*run)
+        ));
+    }
+    if let Some(reference) = reference {
+        svg.push_str(&format!(
+            "<line x1=\"{r:.1}\" y1=\"8\" x2=\"{r:.1}\" y2=\"48\" stroke=\"#cc0000\" stroke-dasharray=\"4 3\">\
+             <title>baseline median</title></line>\n",
+            r = x(reference)
+        ));
+    }
+    svg.push_str(&format!(
+        "<text x=\"{:.1}\" y=\"64\">{}</text>\n<text x=\"{:.1}\" y=\"64\" text-anchor=\"end\">{}</text>\n</svg>\n",
+        PLOT_PADDING,
+        format_duration(Duration::from_nanos(lo as u64)),
+        PLOT_WIDTH - PLOT_PADDING,
+        format_duration(Duration::from_nanos(hi as u64))
+    ));
+    svg
+}
+
+/// Horizontal bars scaled to the largest throughput.
+fn throughput_chart(bars: &[(&BenchResult, f64)]) -> String {
+    let label_width = 220.0;
+    let bar_width = PLOT_WIDTH - label_width - 110.0;
+    let max = bars.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+    let height = bars.len() * 22 + 4;
+    let mut svg = format!(
+        "<svg width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" role=\"img\">\n",
+        w = PLOT_WIDTH,
+        h = height
+    );
+    for (i, (result, value)) in bars.iter().enumerate() {
+        let y = i as f64 * 22.0 + 4.0;
+        let width = if max > 0.0 {
+            value / max * bar_width
+        } else {
+            0.0
+        };
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>\n\
+             <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"16\" fill=\"#6aa84f\"/>\n\
+             <text x=\"{:.1}\" y=\"{:.1}\">{}</text>\n",
+            label_width - 6.0,
+            y + 12.0,
+            escape_xml(short_name(&result.name)),
+            label_width,
+            y,
+            width,
+            label_width + width + 6.0,
+            y + 12.0,
+            escape_xml(&ConsoleReporter::format_throughput(result))
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::Environment;
+    use crate::result::BenchFailure;
+
+    #[test]
+    fn should_write_offline_report_with_charts_comparison_and_environment() {
+        let scan = BenchResult::fixture("storage/scan <full>", 20)
+            .measured(&[10, 20, 30])
+            .bytes(2_000_000);
+        let suite = SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![
+                scan,
+                BenchResult::fixture("storage/read", 5).measured(&[5, 5, 6]),
+            ],
+            failures: vec![BenchFailure {
+                name: "storage/compact".to_string(),
+                message: "panicked: boom".to_string(),
+            }],
+            git_sha: Some("36d2a432".to_string()),
+            environment: Some(Environment {
+                os: "linux".to_string(),
+                arch: "x86_64".to_string(),
+                cpus: 8,
+                hostname: Some("bench-01".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut reporter = HtmlReporter::new("unused");
+        reporter.baseline = Some(SuiteResult {
+            results: vec![BenchResult::fixture("storage/scan <full>", 10).measured(&[10])],
+            ..Default::default()
+        });
+
+        let html = reporter.format_suite(&suite);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.ends_with("</html>\n"));
+        // Nothing is fetched from the network
+        assert!(!html.contains("http://") && !html.contains("https://"));
+        assert!(!html.contains(" src=") && !html.contains("<link"));
+
+        assert!(html.contains("<tr class=\"regression\"><td>scan &lt;full&gt;</td>"));
+        assert!(html.contains("<td data-value=\"1.0000\">+100.0%</td>"));
+        assert!(html.contains("<td data-value=\"\">new</td>"));
+        assert_eq!(html.matches("<figure>").count(), 2);
+        assert!(html.contains("<title>baseline median</title>"));
+        assert!(html.contains(">100.00 MB/s</text>"));
+        assert!(html.contains("<li><code>compact</code>: panicked: boom</li>"));
+        assert!(html.contains("<tr><th>Git SHA</th><td>36d2a432</td></tr>"));
+        assert!(html.contains("<tr><th>Host</th><td>bench-01</td></tr>"));
+    }
+
+    #[test]
+    fn should_scale_box_plot_to_runs_and_baseline() {
+        let plot = box_plot(
+            &BenchResult::fixture("storage/scan", 20).measured(&[10, 20, 30]),
+            None,
+        );
+        // Whiskers span the plot; the median sits in the middle
+        assert!(plot.contains("<line x1=\"12.0\" y1=\"28\" x2=\"628.0\" y2=\"28\""));
+        assert!(plot.contains("<line x1=\"320.0\" y1=\"16\" x2=\"320.0\" y2=\"40\""));
+        assert_eq!(plot.matches("<circle").count(), 3);
+
+        // A slower baseline widens the scale
+        let plot = box_plot(
+            &BenchResult::fixture("storage/scan", 20).measured(&[10, 20, 30]),
+            Some(&BenchResult::fixture("storage/scan", 50).measured(&[50])),
+        );
+        assert!(plot.contains("<line x1=\"628.0\" y1=\"8\""));
+        assert!(plot.contains("text-anchor=\"end\">50.00ms</text>"));
+    }
+}
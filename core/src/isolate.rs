@@ -0,0 +1,265 @@
+//! Isolated mode: one child process per benchmark.
+//!
+//! With `--isolated`, the stress binary re-executes itself once per benchmark
+//! with `--isolated-child <name>`. The child reports its result to the parent
+//! over stdout, which lets a [`kill_after`](crate::faults::FaultPlan::kill_after)
+//! fault terminate the child mid-measurement and restart it without losing
+//! the rest of the suite.
+//!
+//! Protocol lines on the child's stdout (everything else is forwarded):
+//!
+//! - `@@cntryl-stress:kill <ms> <run>`: kill me `<ms>` from now
+//! - `@@cntryl-stress:measured`: the measured region ended, cancel the kill
+//! - `@@cntryl-stress:run <json>`: a finished run, as a `RunInfo`
+//! - `@@cntryl-stress:result <json>`: the finished `BenchResult`
+//! - `@@cntryl-stress:failed <json>`: the benchmark panicked, with the message
+
+use crate::config::BenchOverrides;
+use crate::limits;
+use crate::report::Reporter;
+use crate::result::{BenchFailure, BenchResult, FaultKind, InjectedFault, RunInfo};
+use crate::runner::BenchRunner;
+use crate::sched;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// Set on children; holds how often the benchmark was restarted.
+const RESTART_ENV: &str = "CNTRYL_STRESS_RESTART";
+
+const KILL_MARKER: &str = "@@cntryl-stress:kill";
+const MEASURED_MARKER: &str = "@@cntryl-stress:measured";
+const RUN_MARKER: &str = "@@cntryl-stress:run";
+const RESULT_MARKER: &str = "@@cntryl-stress:result";
+const FAILED_MARKER: &str = "@@cntryl-stress:failed";
+
+/// Restarts after which a benchmark is given up on.
+const MAX_RESTARTS: u32 = 8;
+
+/// Whether this process is an isolated-mode child.
+pub(crate) fn is_child() -> bool {
+    std::env::var_os(RESTART_ENV).is_some()
+}
+
+/// How often the parent restarted this benchmark (0 outside isolated mode).
+pub(crate) fn restart_count() -> u32 {
+    std::env::var(RESTART_ENV)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Ask the parent to kill this process `after` from now.
+///
+/// # Panics
+///
+/// Panics outside isolated mode, where killing the process would take the
+/// whole suite down with it.
+pub(crate) fn schedule_kill(after: Duration, run: usize) {
+    if !is_child() {
+        panic!("kill_after faults need isolated mode; run the stress binary with --isolated");
+    }
+    emit(&format!("{} {} {}", KILL_MARKER, after.as_millis(), run));
+}
+
+/// Tell the parent the measured region ended before the kill deadline.
+pub(crate) fn cancel_kill() {
+    emit(MEASURED_MARKER);
+}
+
+fn emit(line: &str) {
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{}", line);
+    let _ = stdout.flush();
+}
+
+/// Reporter used by children: sends runs, results and failures to the parent.
+pub(crate) struct ChildReporter;
+
+impl ChildReporter {
+    fn send(marker: &str, value: &impl serde::Serialize) {
+        match serde_json::to_string(value) {
+            Ok(json) => emit(&format!("{} {}", marker, json)),
+            Err(e) => eprintln!("error: failed to serialize {}: {}", marker, e),
+        }
+    }
+}
+
+impl Reporter for ChildReporter {
+    fn run_end(&self, _name: &str, run: &RunInfo) {
+        Self::send(RUN_MARKER, run);
+    }
+
+    fn bench_end(&self, result: &BenchResult) {
+        Self::send(RESULT_MARKER, result);
+    }
+
+    fn bench_failed(&self, failure: &BenchFailure) {
+        Self::send(FAILED_MARKER, &failure.message);
+    }
+}
+
+/// Run each benchmark in its own child process, feeding its events and result
+/// to `runner`. Benchmarks that produce no result are recorded as failures.
+pub(crate) fn run_isolated(runner: &mut BenchRunner, cases: &[(String, BenchOverrides)]) {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            let message = format!("cannot locate stress binary for --isolated: {}", e);
+            for (name, _) in cases {
+                runner.record_failure(name, message.clone());
+            }
+            return;
+        }
+    };
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != "--isolated")
+        .collect();
+    let seed = runner.suite_seed();
+
+    for (name, overrides) in cases {
+        runner.announce(name, overrides);
+        match run_child(&exe, &args, name, seed, runner) {
+            Ok(result) => runner.record_result(result),
+            Err(message) => runner.record_failure(name, message),
+        }
+    }
+}
+
+/// Outcome of one child process.
+enum Attempt {
+    Finished(Box<BenchResult>),
+    Killed(InjectedFault),
+}
+
+/// Run `name` to completion, restarting the child after each kill.
+fn run_child(
+    exe: &Path,
+    args: &[String],
+    name: &str,
+    seed: u64,
+    runner: &BenchRunner,
+) -> Result<BenchResult, String> {
+    let mut kills = Vec::new();
+    for restart in 0..=MAX_RESTARTS {
+        match run_attempt(exe, args, name, seed, restart, runner)? {
+            Attempt::Finished(mut result) => {
+                kills.append(&mut result.faults);
+                result.faults = kills;
+                return Ok(*result);
+            }
+            Attempt::Killed(fault) => kills.push(fault),
+        }
+    }
+    Err(format!("killed {} times without finishing", kills.len()))
+}
+
+fn run_attempt(
+    exe: &Path,
+    args: &[String],
+    name: &str,
+    seed: u64,
+    restart: u32,
+    runner: &BenchRunner,
+) -> Result<Attempt, String> {
+    let mut command = Command::new(exe);
+    command
+        .args(args)
+        .arg("--isolated-child")
+        .arg(name)
+        .arg("--seed")
+        .arg(seed.to_string())
+        .env(RESTART_ENV, restart.to_string())
+        .stdout(Stdio::piped());
+    if let Ok(cpus) = sched::process_cpus() {
+        let list: Vec<_> = cpus.iter().map(usize::to_string).collect();
+        command.env(sched::PROCESS_CPUS_ENV, list.join(","));
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("failed to start child process: {}", e))?;
+
+    // Read on a separate thread so the kill deadline can be enforced while
+    // the child is silent
+    let stdout = child.stdout.take().expect("child stdout is piped");
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut result = None;
+    let mut failure = None;
+    // (deadline, delay, run) of a pending kill
+    let mut kill: Option<(Instant, Duration, usize)> = None;
+    loop {
+        let line = match kill {
+            Some((deadline, after, run)) => {
+                match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(line) => line,
+                    Err(RecvTimeoutError::Timeout) => {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        limits::remove_child_cgroup(child.id());
+                        return Ok(Attempt::Killed(InjectedFault {
+                            kind: FaultKind::Kill,
+                            failpoint: None,
+                            at: after,
+                            run,
+                            detail: Some(format!("restart {}", restart + 1)),
+                        }));
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            None => match rx.recv() {
+                Ok(line) => line,
+                Err(_) => break,
+            },
+        };
+
+        if let Some(rest) = line.strip_prefix(KILL_MARKER) {
+            let mut fields = rest.split_whitespace().map(|f| f.parse::<u64>());
+            if let (Some(Ok(ms)), Some(Ok(run))) = (fields.next(), fields.next()) {
+                let after = Duration::from_millis(ms);
+                kill = Some((Instant::now() + after, after, run as usize));
+            }
+        } else if line == MEASURED_MARKER {
+            kill = None;
+        } else if let Some(json) = line.strip_prefix(RUN_MARKER) {
+            if let Ok(run) = serde_json::from_str::<RunInfo>(json.trim()) {
+                runner.record_run(name, &run);
+            }
+        } else if let Some(json) = line.strip_prefix(FAILED_MARKER) {
+            failure = serde_json::from_str::<String>(json.trim()).ok();
+        } else if let Some(json) = line.strip_prefix(RESULT_MARKER) {
+            // Reported after the child has exited, so it is still reaped
+            result = Some(
+                serde_json::from_str(json.trim())
+                    .map_err(|e| format!("invalid result from child: {}", e)),
+            );
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("failed to wait for child process: {}", e))?;
+    limits::remove_child_cgroup(child.id());
+    match (result, failure) {
+        (Some(Ok(result)), _) => Ok(Attempt::Finished(Box::new(result))),
+        (Some(Err(message)), _) => Err(message),
+        (None, Some(message)) => Err(message),
+        (None, None) => Err(format!(
+            "child process exited with {} without a result",
+            status
+        )),
+    }
+}
@@ -0,0 +1,440 @@
+//! Streaming JSON Lines output, so a crash mid-suite keeps what ran.
+//!
+//! [`JsonLinesReporter`] appends one [`JsonLinesEvent`] per line to
+//! `{output_dir}/{suite}/{timestamp}.jsonl` and flushes after each line:
+//!
+//! ```text
+//! {"event":"suite_start","suite":"storage","started_at":"1771376841729","runs":10,"warmup_runs":2}
+//! {"event":"bench_start","name":"storage/scan"}
+//! {"event":"run","name":"storage/scan","run":{"run":0,"warmup":true,"duration":4210331}}
+//! ...
+//! {"event":"bench_end","result":{"name":"storage/scan",...}}
+//! {"event":"suite_end","result":{"suite":"storage",...}}
+//! ```
+//!
+//! [`SuiteResult::load_jsonl`] rebuilds a suite from such a file, up to the
+//! last complete line if the process died while writing.
+
+use crate::config::{BenchRunnerConfig, BenchSettings};
+use crate::layout::{record_and_prune, Retention};
+use crate::report::{suite_path_name, Reporter};
+use crate::result::{BenchFailure, BenchResult, RunInfo, SkippedBench, SuiteResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// One line of a JSON Lines results file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum JsonLinesEvent {
+    /// The suite started.
+    SuiteStart {
+        suite: String,
+        started_at: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        git_sha: Option<String>,
+        runs: usize,
+        warmup_runs: usize,
+    },
+    /// A benchmark started.
+    BenchStart { name: String },
+    /// A warmup or measured run finished.
+    Run { name: String, run: RunInfo },
+    /// A benchmark finished.
+    BenchEnd { result: Box<BenchResult> },
+    /// A benchmark panicked or its isolated child died.
+    BenchFailed { failure: BenchFailure },
+    /// A selected benchmark was not run.
+    BenchSkipped { skipped: SkippedBench },
+    /// The suite finished; `result` is the same as in `latest.json`.
+    SuiteEnd { result: Box<SuiteResult> },
+}
+
+/// Reporter that streams events to a JSON Lines file as they happen.
+///
+/// The file is opened in [`Reporter::suite_start`], so install it before the
+/// suite starts, e.g. with `--reporter jsonl`.
+pub struct JsonLinesReporter {
+    output_dir: PathBuf,
+    retention: Retention,
+    file: Mutex<Option<File>>,
+    /// Prefix for the bare benchmark names of start and run events
+    suite: Mutex<String>,
+}
+
+impl JsonLinesReporter {
+    /// Write `{output_dir}/{suite}/{timestamp}.jsonl`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            retention: Retention::All,
+            file: Mutex::new(None),
+            suite: Mutex::new(String::new()),
+        }
+    }
+
+    /// Set which logs to keep per suite.
+    pub fn retention(mut self, retention: Retention) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// `suite/benchmark`, as in results and failures.
+    fn full_name(&self, name: &str) -> String {
+        let suite = self.suite.lock().unwrap_or_else(|e| e.into_inner());
+        format!("{}/{}", suite, name)
+    }
+
+    fn open(&self, suite: &str, started_at: &str) -> std::io::Result<PathBuf> {
+        let suite_dir = self.output_dir.join(suite_path_name(suite));
+        std::fs::create_dir_all(&suite_dir)?;
+        let file_name = format!("{}.jsonl", started_at);
+        let path = suite_dir.join(&file_name);
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        *self.file.lock().unwrap_or_else(|e| e.into_inner()) = Some(file);
+
+        match record_and_prune(&suite_dir, "jsonl", &[file_name], self.retention) {
+            Ok(0) => {}
+            Ok(removed) => eprintln!(
+                "  Removed {} old log(s) from: {}",
+                removed,
+                suite_dir.display()
+            ),
+            Err(e) => eprintln!("Warning: failed to prune JSON Lines logs: {}", e),
+        }
+        Ok(path)
+    }
+
+    /// Append one event as a line and flush it.
+    fn send(&self, event: &JsonLinesEvent) {
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(file) = file.as_mut() else {
+            return;
+        };
+        let written = serde_json::to_string(event)
+            .map_err(std::io::Error::other)
+            .and_then(|mut line| {
+                // One write per line, so a crash cannot interleave two events
+                line.push('\n');
+                file.write_all(line.as_bytes())?;
+                file.flush()
+            });
+        if let Err(e) = written {
+            eprintln!("Warning: failed to write JSON Lines event: {}", e);
+        }
+    }
+}
+
+impl Reporter for JsonLinesReporter {
+    fn suite_start(&self, suite: &str, config: &BenchRunnerConfig) {
+        *self.suite.lock().unwrap_or_else(|e| e.into_inner()) = suite.to_string();
+        let started_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            .to_string();
+        match self.open(suite, &started_at) {
+            Ok(path) => eprintln!("  Streaming events to: {}", path.display()),
+            Err(e) => {
+                eprintln!("Warning: failed to open JSON Lines file: {}", e);
+                return;
+            }
+        }
+        self.send(&JsonLinesEvent::SuiteStart {
+            suite: suite.to_string(),
+            started_at,
+            git_sha: config.git_sha.clone(),
+            runs: config.runs,
+            warmup_runs: config.warmup_runs,
+        });
+    }
+
+    fn bench_start(&self, name: &str, _settings: &BenchSettings) {
+        self.send(&JsonLinesEvent::BenchStart {
+            name: self.full_name(name),
+        });
+    }
+
+    fn run_end(&self, name: &str, run: &RunInfo) {
+        self.send(&JsonLinesEvent::Run {
+            name: self.full_name(name),
+            run: *run,
+        });
+    }
+
+    fn bench_end(&self, result: &BenchResult) {
+        self.send(&JsonLinesEvent::BenchEnd {
+            result: Box::new(result.clone()),
+        });
+    }
+
+    fn bench_failed(&self, failure: &BenchFailure) {
+        self.send(&JsonLinesEvent::BenchFailed {
+            failure: failure.clone(),
+        });
+    }
+
+    fn bench_skipped(&self, skipped: &SkippedBench) {
+        self.send(&JsonLinesEvent::BenchSkipped {
+            skipped: skipped.clone(),
+        });
+    }
+
+    fn suite_end(&self, result: &SuiteResult) {
+        self.send(&JsonLinesEvent::SuiteEnd {
+            result: Box::new(result.clone()),
+        });
+        // Close the file; later events have no suite to belong to
+        self.file.lock().unwrap_or_else(|e| e.into_inner()).take();
+    }
+}
+
+/// Rebuild a suite from JSON Lines, stopping at the first line that does
+/// not parse (a line cut off by a crash).
+///
+/// A complete file yields the `suite_end` result. Otherwise the suite holds
+/// the benchmarks that finished, and a benchmark that started but did not
+/// finish is listed in `failures`; in interleaved and soak runs that can be
+/// several. A second `suite_start` starts over.
+pub(crate) fn replay(content: &str) -> std::io::Result<SuiteResult> {
+    let mut suite: Option<SuiteResult> = None;
+    // Benchmarks in progress and their measured runs so far
+    let mut running: BTreeMap<String, usize> = BTreeMap::new();
+
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let Ok(event) = serde_json::from_str::<JsonLinesEvent>(line) else {
+            break;
+        };
+        if let JsonLinesEvent::SuiteStart {
+            suite: name,
+            started_at,
+            git_sha,
+            runs,
+            warmup_runs,
+        } = event
+        {
+            suite = Some(SuiteResult {
+                suite: name,
+                started_at,
+                git_sha,
+                runs,
+                warmup_runs,
+                ..Default::default()
+            });
+            running.clear();
+            continue;
+        }
+        let Some(current) = suite.as_mut() else {
+            continue;
+        };
+        match event {
+            JsonLinesEvent::SuiteStart { .. } => unreachable!(),
+            JsonLinesEvent::BenchStart { name } => {
+                running.insert(name, 0);
+            }
+            JsonLinesEvent::Run { name, run } => {
+                if let Some(measured) = running.get_mut(&name) {
+                    if !run.warmup {
+                        *measured += 1;
+                    }
+                }
+            }
+            JsonLinesEvent::BenchEnd { result } => {
+                running.remove(&result.name);
+                current.results.push(*result);
+            }
+            JsonLinesEvent::BenchFailed { failure } => {
+                running.remove(&failure.name);
+                current.failures.push(failure);
+            }
+            JsonLinesEvent::BenchSkipped { skipped } => current.skipped.push(skipped),
+            JsonLinesEvent::SuiteEnd { result } => {
+                *current = *result;
+                running.clear();
+            }
+        }
+    }
+
+    let mut suite = suite.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "no suite_start event found",
+        )
+    })?;
+    for (name, measured) in running {
+        suite.failures.push(BenchFailure {
+            name,
+            message: format!(
+                "incomplete: {} measured run(s) before the log ended",
+                measured
+            ),
+        });
+    }
+    Ok(suite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn run(run: usize, warmup: bool) -> RunInfo {
+        RunInfo {
+            run,
+            warmup,
+            duration: Duration::from_millis(5),
+        }
+    }
+
+    #[test]
+    fn should_append_a_flushed_line_per_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let reporter = JsonLinesReporter::new(dir.path());
+        let config = BenchRunnerConfig::default();
+        reporter.suite_start("storage", &config);
+        reporter.run_end("scan", &run(0, false));
+
+        // Readable before the suite ends
+        let suite_dir = dir.path().join("storage");
+        let path = std::fs::read_dir(&suite_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+            .unwrap();
+        assert!(suite_dir.join(".index").exists());
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"event\":\"suite_start\",\"suite\":\"storage\""));
+        assert_eq!(
+            lines[1],
+            "{\"event\":\"run\",\"name\":\"storage/scan\",\"run\":{\"run\":0,\"warmup\":false,\"duration\":5000000}}"
+        );
+
+        reporter.bench_end(&BenchResult::fixture("storage/scan", 5).measured(&[5]));
+        reporter.suite_end(&SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![BenchResult::fixture("storage/scan", 5).measured(&[5])],
+            ..Default::default()
+        });
+        let suite = SuiteResult::load_jsonl(&path).unwrap();
+        assert_eq!(suite.results.len(), 1);
+        assert_eq!(suite.results[0].all_runs, [Duration::from_millis(5)]);
+    }
+
+    #[test]
+    fn should_rebuild_partial_suite_from_truncated_file() {
+        let events = [
+            JsonLinesEvent::SuiteStart {
+                suite: "storage".to_string(),
+                started_at: "1771376841729".to_string(),
+                git_sha: Some("36d2a432".to_string()),
+                runs: 3,
+                warmup_runs: 1,
+            },
+            JsonLinesEvent::BenchStart {
+                name: "storage/scan".to_string(),
+            },
+            JsonLinesEvent::BenchEnd {
+                result: Box::new(BenchResult::fixture("storage/scan", 5).measured(&[5])),
+            },
+            JsonLinesEvent::BenchSkipped {
+                skipped: SkippedBench {
+                    name: "storage/raw".to_string(),
+                    reason: "ignored".to_string(),
+                },
+            },
+            JsonLinesEvent::BenchStart {
+                name: "storage/compact".to_string(),
+            },
+            JsonLinesEvent::Run {
+                name: "storage/compact".to_string(),
+                run: run(0, true),
+            },
+            JsonLinesEvent::Run {
+                name: "storage/compact".to_string(),
+                run: run(0, false),
+            },
+        ];
+        let mut content: String = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+        // The process died halfway through the next line
+        content.push_str("{\"event\":\"run\",\"name\":\"storage/comp");
+
+        let suite = replay(&content).unwrap();
+        assert_eq!(suite.suite, "storage");
+        assert_eq!(suite.git_sha.as_deref(), Some("36d2a432"));
+        assert_eq!(suite.runs, 3);
+        assert_eq!(suite.results.len(), 1);
+        assert_eq!(suite.results[0].name, "storage/scan");
+        assert_eq!(suite.skipped.len(), 1);
+        assert_eq!(
+            suite.failures,
+            vec![BenchFailure {
+                name: "storage/compact".to_string(),
+                message: "incomplete: 1 measured run(s) before the log ended".to_string(),
+            }]
+        );
+
+        assert!(replay("{\"event\":\"bench_st").is_err());
+    }
+
+    #[test]
+    fn should_list_every_unfinished_benchmark_when_interleaved() {
+        let mut events = vec![JsonLinesEvent::SuiteStart {
+            suite: "storage".to_string(),
+            started_at: "1771376841729".to_string(),
+            git_sha: None,
+            runs: 3,
+            warmup_runs: 0,
+        }];
+        // Interleaved runs start every benchmark before measuring any
+        for name in ["storage/write", "storage/read", "storage/scan"] {
+            events.push(JsonLinesEvent::BenchStart {
+                name: name.to_string(),
+            });
+        }
+        for (round, name) in [
+            (0, "storage/write"),
+            (0, "storage/read"),
+            (1, "storage/write"),
+        ] {
+            events.push(JsonLinesEvent::Run {
+                name: name.to_string(),
+                run: run(round, false),
+            });
+        }
+        events.push(JsonLinesEvent::BenchEnd {
+            result: Box::new(BenchResult::fixture("storage/scan", 5).measured(&[5])),
+        });
+        let content: String = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+
+        let suite = replay(&content).unwrap();
+        assert_eq!(suite.results.len(), 1);
+        assert_eq!(suite.results[0].name, "storage/scan");
+        assert_eq!(
+            suite.failures,
+            vec![
+                BenchFailure {
+                    name: "storage/read".to_string(),
+                    message: "incomplete: 1 measured run(s) before the log ended".to_string(),
+                },
+                BenchFailure {
+                    name: "storage/write".to_string(),
+                    message: "incomplete: 2 measured run(s) before the log ended".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! JUnit XML output for CI test dashboards (Jenkins, GitLab).
+//!
+//! Each suite becomes one `<testsuite>` with a `<testcase>` per benchmark,
+//! written to `{output_dir}/{suite}/junit.xml`:
+//!
+//! ```xml
+//! <testsuite name="storage" tests="3" failures="1" errors="0" skipped="1" time="12.400000">
+//!   <testcase name="compaction" classname="storage" time="4.200000">
+//!     <properties>
+//!       <property name="bytes_per_sec" value="104857600"/>
+//!       <property name="tag.disk" value="nvme"/>
+//!     </properties>
+//!     <failure type="regression" message="12.3% slower than baseline"/>
+//!   </testcase>
+//!   ...
+//! </testsuite>
+//! ```
+//!
+//! Panics, timeouts, soak drift and regressions against the baseline are
+//! failures; ignored benchmarks are skipped test cases.
+
+use crate::report::{escape_xml, load_baseline, short_name, suite_path_name, Reporter};
+use crate::result::{BenchResult, SuiteResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Reporter that writes a JUnit XML file per suite.
+pub struct JunitReporter {
+    output_dir: PathBuf,
+    baseline: Option<SuiteResult>,
+    threshold: f64,
+}
+
+impl JunitReporter {
+    /// Write `{output_dir}/{suite}/junit.xml`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            baseline: None,
+            threshold: 0.05,
+        }
+    }
+
+    /// Load baseline from a file; regressions become failures.
+    pub fn with_baseline(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.baseline = load_baseline(path.as_ref());
+        self
+    }
+
+    /// Set the regression threshold (default: 0.05 = 5%).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn format_suite(&self, suite: &SuiteResult) -> String {
+        let regressions: HashMap<&str, f64> = self
+            .baseline
+            .as_ref()
+            .map(|baseline| {
+                suite
+                    .find_regressions(baseline, self.threshold)
+                    .into_iter()
+                    .map(|(r, ratio)| (r.name.as_str(), ratio))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut cases = Vec::new();
+        let mut failures = 0;
+        for result in &suite.results {
+            let failure = Self::failure(result, regressions.get(result.name.as_str()));
+            failures += usize::from(failure.is_some());
+            cases.push(Self::format_case(suite, result, failure));
+        }
+        for failure in &suite.failures {
+            failures += 1;
+            cases.push(format!(
+                "  <testcase name=\"{}\" classname=\"{}\" time=\"0\">\n    <failure type=\"panic\" message=\"{}\"/>\n  </testcase>\n",
+                escape_xml(short_name(&failure.name)),
+                escape_xml(&suite.suite),
+                escape_xml(&failure.message)
+            ));
+        }
+        for skipped in &suite.skipped {
+            cases.push(format!(
+                "  <testcase name=\"{}\" classname=\"{}\" time=\"0\">\n    <skipped message=\"{}\"/>\n  </testcase>\n",
+                escape_xml(short_name(&skipped.name)),
+                escape_xml(&suite.suite),
+                escape_xml(&skipped.reason)
+            ));
+        }
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{:.6}\">\n",
+            escape_xml(&suite.suite),
+            cases.len(),
+            failures,
+            suite.skipped.len(),
+            suite.total_duration.as_secs_f64()
+        ));
+        if let Some(sha) = &suite.git_sha {
+            xml.push_str(&format!(
+                "  <properties>\n    <property name=\"git_sha\" value=\"{}\"/>\n  </properties>\n",
+                escape_xml(sha)
+            ));
+        }
+        for case in cases {
+            xml.push_str(&case);
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+
+    /// Why a finished benchmark failed, as `(type, message)`.
+    fn failure(result: &BenchResult, regression: Option<&f64>) -> Option<(&'static str, String)> {
+        if result.timed_out {
+            let limit = result.timeout.unwrap_or_default();
+            return Some((
+                "timeout",
+                format!("run exceeded {:.2}s", limit.as_secs_f64()),
+            ));
+        }
+        if let Some(soak) = result.soak.as_ref().filter(|s| s.drift_exceeded) {
+            return Some((
+                "drift",
+                format!(
+                    "drifted more than {:.1}% during the soak",
+                    soak.max_drift * 100.0
+                ),
+            ));
+        }
+        regression.map(|ratio| {
+            (
+                "regression",
+                format!("{:.1}% slower than baseline", (ratio - 1.0) * 100.0),
+            )
+        })
+    }
+
+    fn format_case(
+        suite: &SuiteResult,
+        result: &BenchResult,
+        failure: Option<(&str, String)>,
+    ) -> String {
+        let mut properties = Vec::new();
+        if let Some(bps) = result.bytes_per_sec() {
+            properties.push(("bytes_per_sec".to_string(), format!("{:.0}", bps)));
+        }
+        if let Some(eps) = result.elements_per_sec() {
+            properties.push(("elements_per_sec".to_string(), format!("{:.0}", eps)));
+        }
+        // Sorted so identical results produce identical files
+        let mut tags: Vec<_> = result.tags.iter().collect();
+        tags.sort();
+        for (key, value) in tags {
+            properties.push((format!("tag.{}", key), value.clone()));
+        }
+        for label in &result.declared_tags {
+            properties.push(("label".to_string(), label.clone()));
+        }
+
+        let mut case = format!(
+            "  <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\"",
+            escape_xml(short_name(&result.name)),
+            escape_xml(&suite.suite),
+            result.duration.as_secs_f64()
+        );
+        if properties.is_empty() && failure.is_none() {
+            case.push_str("/>\n");
+            return case;
+        }
+        case.push_str(">\n");
+        if !properties.is_empty() {
+            case.push_str("    <properties>\n");
+            for (name, value) in properties {
+                case.push_str(&format!(
+                    "      <property name=\"{}\" value=\"{}\"/>\n",
+                    escape_xml(&name),
+                    escape_xml(&value)
+                ));
+            }
+            case.push_str("    </properties>\n");
+        }
+        if let Some((kind, message)) = failure {
+            case.push_str(&format!(
+                "    <failure type=\"{}\" message=\"{}\"/>\n",
+                kind,
+                escape_xml(&message)
+            ));
+        }
+        case.push_str("  </testcase>\n");
+        case
+    }
+
+    fn write(&self, suite: &SuiteResult) -> std::io::Result<PathBuf> {
+        let suite_dir = self.output_dir.join(suite_path_name(&suite.suite));
+        std::fs::create_dir_all(&suite_dir)?;
+        let path = suite_dir.join("junit.xml");
+        std::fs::write(&path, self.format_suite(suite))?;
+        Ok(path)
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn suite_end(&self, result: &SuiteResult) {
+        match self.write(result) {
+            Ok(path) => eprintln!("  JUnit report at: {}", path.display()),
+            Err(e) => eprintln!("Warning: failed to write JUnit report: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::{BenchFailure, SkippedBench};
+    use std::time::Duration;
+
+    #[test]
+    fn should_escape_xml_attributes() {
+        assert_eq!(
+            escape_xml("a<b> & \"c\"\n\u{1}"),
+            "a&lt;b&gt; &amp; &quot;c&quot;&#10;"
+        );
+    }
+
+    #[test]
+    fn should_write_testcase_per_benchmark_with_properties() {
+        let scan = BenchResult::fixture("storage/scan", 1500)
+            .bytes(3000)
+            .tag("disk", "nvme");
+        let suite = SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![scan, BenchResult::fixture("storage/point_read", 2)],
+            total_duration: Duration::from_secs(2),
+            ..Default::default()
+        };
+
+        let xml = JunitReporter::new("unused").format_suite(&suite);
+        assert!(xml.contains(
+            "<testsuite name=\"storage\" tests=\"2\" failures=\"0\" errors=\"0\" skipped=\"0\" time=\"2.000000\">"
+        ));
+        assert!(xml.contains("<testcase name=\"scan\" classname=\"storage\" time=\"1.500000\">"));
+        assert!(xml.contains("<property name=\"bytes_per_sec\" value=\"2000\"/>"));
+        assert!(xml.contains("<property name=\"tag.disk\" value=\"nvme\"/>"));
+        assert!(
+            xml.contains("<testcase name=\"point_read\" classname=\"storage\" time=\"0.002000\"/>")
+        );
+        assert!(xml.ends_with("</testsuite>\n"));
+    }
+
+    #[test]
+    fn should_report_panics_timeouts_regressions_and_skips() {
+        let mut slow = BenchResult::fixture("storage/flush", 200);
+        slow.timed_out = true;
+        slow.timeout = Some(Duration::from_millis(100));
+        let suite = SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![
+                slow,
+                BenchResult::fixture("storage/scan", 130),
+                BenchResult::fixture("storage/read", 100),
+            ],
+            failures: vec![BenchFailure {
+                name: "storage/compact".to_string(),
+                message: "panicked: <corrupt>".to_string(),
+            }],
+            skipped: vec![SkippedBench {
+                name: "storage/raw".to_string(),
+                reason: "needs /dev/nvme0".to_string(),
+            }],
+            ..Default::default()
+        };
+        let mut reporter = JunitReporter::new("unused").threshold(0.1);
+        reporter.baseline = Some(SuiteResult {
+            results: vec![
+                BenchResult::fixture("storage/scan", 100),
+                BenchResult::fixture("storage/read", 100),
+            ],
+            ..Default::default()
+        });
+
+        let xml = reporter.format_suite(&suite);
+        assert!(xml.contains("tests=\"5\" failures=\"3\" errors=\"0\" skipped=\"1\""));
+        assert!(xml.contains("<failure type=\"timeout\" message=\"run exceeded 0.10s\"/>"));
+        assert!(
+            xml.contains("<failure type=\"regression\" message=\"30.0% slower than baseline\"/>")
+        );
+        assert!(xml.contains("<failure type=\"panic\" message=\"panicked: &lt;corrupt&gt;\"/>"));
+        assert!(xml.contains("<skipped message=\"needs /dev/nvme0\"/>"));
+        assert!(xml.contains("<testcase name=\"read\" classname=\"storage\" time=\"0.100000\"/>"));
+    }
+}
@@ -0,0 +1,547 @@
+//! Results file layout for [`JsonReporter`](crate::JsonReporter): file name
+//! template, compression, retention and how `latest.json` is kept.
+
+use crate::report::write_atomic;
+use crate::result::SuiteResult;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// File name template used when none is set: the suite's start time in
+/// unix milliseconds.
+pub const DEFAULT_FILE_NAME: &str = "{timestamp}";
+
+/// Index of the runs reporters wrote to a suite directory; retention only
+/// ever removes files listed here.
+const INDEX_FILE: &str = ".index";
+
+/// Placeholders a file name template may use.
+const PLACEHOLDERS: [&str; 7] = [
+    "suite",
+    "timestamp",
+    "date",
+    "time",
+    "sha",
+    "git_sha",
+    "branch",
+];
+
+/// Where and how [`JsonReporter`](crate::JsonReporter) writes each run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLayout {
+    /// File name template without extension, e.g. `{date}-{branch}-{sha}`.
+    pub file_name: String,
+    /// Gzip each run's JSON file (needs the `gzip` feature).
+    pub compress: bool,
+    /// Which runs to keep per suite.
+    pub retention: Retention,
+    /// How `latest.json` and `latest.txt` refer to the newest run.
+    pub latest: LatestMode,
+}
+
+impl Default for OutputLayout {
+    fn default() -> Self {
+        Self {
+            file_name: DEFAULT_FILE_NAME.to_string(),
+            compress: false,
+            retention: Retention::All,
+            latest: LatestMode::Copy,
+        }
+    }
+}
+
+impl OutputLayout {
+    /// Default layout: `{timestamp}` file names, uncompressed, every run
+    /// kept and `latest.*` written as copies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the file name template; see [`check_file_name`] for placeholders.
+    pub fn file_name(mut self, template: impl Into<String>) -> Self {
+        self.file_name = template.into();
+        self
+    }
+
+    /// Gzip each run's JSON file as `{name}.json.gz`.
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Set which runs to keep per suite.
+    pub fn retention(mut self, retention: Retention) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Set how `latest.json` and `latest.txt` refer to the newest run.
+    pub fn latest(mut self, latest: LatestMode) -> Self {
+        self.latest = latest;
+        self
+    }
+
+    /// File name (without extension) for a suite's run.
+    ///
+    /// Values are sanitized so a branch such as `feature/x` cannot create
+    /// a subdirectory; unknown or missing values become `unknown`.
+    pub(crate) fn render(&self, suite: &SuiteResult) -> String {
+        let millis: Option<u64> = suite.started_at.parse().ok();
+        let mut out = String::new();
+        let mut rest = self.file_name.as_str();
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            let name = &rest[start + 1..start + len];
+            let value = match name {
+                "suite" => Some(suite.suite.clone()),
+                "timestamp" => Some(suite.started_at.clone()),
+                "date" => millis.map(|ms| format_date(ms / 1000)),
+                "time" => millis.map(|ms| format_time(ms / 1000)),
+                "sha" => suite
+                    .git_sha
+                    .as_ref()
+                    .map(|sha| sha.chars().take(7).collect()),
+                "git_sha" => suite.git_sha.clone(),
+                "branch" => suite.git_branch.clone(),
+                // Left as written; rejected by `check_file_name`
+                _ => Some(rest[start..=start + len].to_string()),
+            };
+            out.push_str(&sanitize(value.as_deref().unwrap_or("unknown")));
+            rest = &rest[start + len + 1..];
+        }
+        out.push_str(rest);
+        // `latest.json` is the link or copy, never a run; dot files such as
+        // `.index` are not runs either
+        if out == "latest" || out.starts_with('.') {
+            out.insert(0, '_');
+        }
+        out
+    }
+}
+
+/// Check a file name template.
+///
+/// Placeholders are `{suite}`, `{timestamp}` (unix milliseconds), `{date}`
+/// (`YYYY-MM-DD`, UTC), `{time}` (`HHMMSS`, UTC), `{sha}` (7 characters),
+/// `{git_sha}` and `{branch}`. `latest` is reserved for `latest.json`, and
+/// names starting with `.` for files such as the retention `.index`.
+pub fn check_file_name(template: &str) -> Result<(), String> {
+    if template.is_empty() {
+        return Err("file name template is empty".to_string());
+    }
+    if template == "latest" {
+        return Err("file name 'latest' is reserved for latest.json".to_string());
+    }
+    if template.starts_with('.') {
+        return Err(format!(
+            "file name template '{}' must not start with '.' (reserved for {})",
+            template, INDEX_FILE
+        ));
+    }
+    if template.contains(['/', '\\']) {
+        return Err(format!(
+            "file name template '{}' must not contain path separators",
+            template
+        ));
+    }
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            return Err(format!(
+                "unclosed '{{' in file name template '{}'",
+                template
+            ));
+        };
+        let name = &rest[start + 1..start + len];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "unknown placeholder '{{{}}}' in file name template (expected {})",
+                name,
+                PLACEHOLDERS.map(|p| format!("{{{}}}", p)).join(", ")
+            ));
+        }
+        rest = &rest[start + len + 1..];
+    }
+    Ok(())
+}
+
+/// Which runs [`JsonReporter`](crate::JsonReporter) keeps per suite.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Retention {
+    /// Keep every run
+    #[default]
+    All,
+    /// Keep the newest N runs
+    Runs(usize),
+    /// Keep runs from the last N days
+    Days(u64),
+}
+
+impl std::str::FromStr for Retention {
+    type Err = String;
+
+    /// `all`, a run count such as `20`, or days such as `30d`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        if s == "all" {
+            return Ok(Retention::All);
+        }
+        let invalid = || {
+            format!(
+                "invalid retention '{}' (expected all, a run count such as 20, or days such as 30d)",
+                s
+            )
+        };
+        let retention = match s.strip_suffix('d') {
+            Some(days) => Retention::Days(days.parse().map_err(|_| invalid())?),
+            None => Retention::Runs(s.parse().map_err(|_| invalid())?),
+        };
+        if matches!(retention, Retention::Runs(0) | Retention::Days(0)) {
+            return Err(format!("retention '{}' would remove every run", s));
+        }
+        Ok(retention)
+    }
+}
+
+/// How `latest.json` and `latest.txt` refer to the newest run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LatestMode {
+    /// Write a copy
+    #[default]
+    Copy,
+    /// Symlink to the run's files (Unix; falls back to a copy elsewhere and
+    /// for compressed runs, since `latest.json` is always plain JSON)
+    Symlink,
+}
+
+impl std::str::FromStr for LatestMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "copy" => Ok(LatestMode::Copy),
+            "symlink" => Ok(LatestMode::Symlink),
+            other => Err(format!(
+                "unknown latest mode '{}' (expected copy or symlink)",
+                other
+            )),
+        }
+    }
+}
+
+/// A run listed in a suite directory's index.
+struct IndexedRun {
+    /// When the run was recorded, in seconds since the unix epoch
+    written: u64,
+    /// Reporter that wrote it, e.g. `json` or `jsonl`
+    kind: String,
+    files: Vec<String>,
+}
+
+impl IndexedRun {
+    /// Parse a `{written}\t{kind}\t{file}...` line.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let written = fields.next()?.parse().ok()?;
+        let kind = fields.next()?.to_string();
+        let files: Vec<_> = fields.map(str::to_string).collect();
+        (!files.is_empty()).then_some(Self {
+            written,
+            kind,
+            files,
+        })
+    }
+
+    fn line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\n",
+            self.written,
+            self.kind,
+            self.files.join("\t")
+        )
+    }
+}
+
+/// Record that a run of `kind` wrote `files` to `suite_dir`, then remove the
+/// runs of that kind `retention` does not keep. Returns how many runs were
+/// removed.
+///
+/// Only runs recorded here are removed, so other files in the directory
+/// (such as a `baseline.json`) are left alone. Runs are ordered by when they
+/// were recorded, since templates need not sort.
+pub(crate) fn record_and_prune(
+    suite_dir: &Path,
+    kind: &str,
+    files: &[String],
+    retention: Retention,
+) -> std::io::Result<usize> {
+    let index = suite_dir.join(INDEX_FILE);
+    let mut runs: Vec<IndexedRun> = match std::fs::read_to_string(&index) {
+        Ok(content) => content.lines().filter_map(IndexedRun::parse).collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    // A template without a timestamp rewrites the same files
+    runs.retain(|run| !run.files.iter().any(|f| files.contains(f)));
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    runs.push(IndexedRun {
+        written: now,
+        kind: kind.to_string(),
+        files: files.to_vec(),
+    });
+
+    // Newest first, so the run just recorded is always kept
+    let mut kept = Vec::with_capacity(runs.len());
+    let mut newer = 0;
+    let mut removed = 0;
+    for run in runs.into_iter().rev() {
+        if run.kind != kind {
+            kept.push(run);
+            continue;
+        }
+        newer += 1;
+        let expired = match retention {
+            Retention::All => false,
+            Retention::Runs(keep) => newer > keep,
+            Retention::Days(days) => run.written < now.saturating_sub(days * 86_400),
+        };
+        if !expired {
+            kept.push(run);
+            continue;
+        }
+        for file in &run.files {
+            match std::fs::remove_file(suite_dir.join(file)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        removed += 1;
+    }
+    kept.reverse();
+
+    let content: String = kept.iter().map(IndexedRun::line).collect();
+    write_atomic(&index, content.as_bytes())?;
+    Ok(removed)
+}
+
+/// Point `link` at `target` (a file name in the same directory), replacing
+/// whatever `link` was in one rename.
+#[cfg(unix)]
+pub(crate) fn symlink_atomic(target: &str, link: &Path) -> std::io::Result<()> {
+    let file_name = link.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = link.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+    let _ = std::fs::remove_file(&tmp);
+    std::os::unix::fs::symlink(target, &tmp)?;
+    let result = std::fs::rename(&tmp, link);
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+#[cfg(not(unix))]
+pub(crate) fn symlink_atomic(_target: &str, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "symlinks are only used on Unix",
+    ))
+}
+
+/// Gzip `data`.
+#[cfg(feature = "gzip")]
+pub(crate) fn gzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+/// Decompress a gzipped UTF-8 file.
+#[cfg(feature = "gzip")]
+pub(crate) fn gunzip(data: &[u8]) -> std::io::Result<String> {
+    use std::io::Read;
+    let mut out = String::new();
+    flate2::read::GzDecoder::new(data).read_to_string(&mut out)?;
+    Ok(out)
+}
+
+/// Keep characters that are safe in a file name, replacing the rest with `_`.
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '+') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// `YYYY-MM-DD` (UTC) for seconds since the unix epoch.
+fn format_date(secs: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// `HHMMSS` (UTC) for seconds since the unix epoch.
+fn format_time(secs: u64) -> String {
+    let secs = secs % 86_400;
+    format!("{:02}{:02}{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suite() -> SuiteResult {
+        SuiteResult {
+            // 2026-03-01 13:45:09 UTC
+            started_at: "1772372709000".to_string(),
+            git_sha: Some("36d2a432aa".to_string()),
+            git_branch: Some("feature/faster-scan".to_string()),
+            ..SuiteResult::fixture("io", &[])
+        }
+    }
+
+    #[test]
+    fn should_render_placeholders_when_template_set() {
+        let layout = OutputLayout::new().file_name("{date}T{time}-{branch}-{sha}");
+        assert_eq!(
+            layout.render(&suite()),
+            "2026-03-01T134509-feature_faster-scan-36d2a43"
+        );
+        assert_eq!(OutputLayout::new().render(&suite()), "1772372709000");
+
+        let no_git = SuiteResult {
+            git_sha: None,
+            git_branch: None,
+            ..suite()
+        };
+        let layout = OutputLayout::new().file_name("{suite}-{branch}");
+        assert_eq!(layout.render(&no_git), "io-unknown");
+
+        let dot_branch = SuiteResult {
+            git_branch: Some(".index".to_string()),
+            ..suite()
+        };
+        let layout = OutputLayout::new().file_name("{branch}");
+        assert_eq!(layout.render(&dot_branch), "_.index");
+    }
+
+    #[test]
+    fn should_reject_bad_templates_and_retention() {
+        assert!(check_file_name("{date}-{sha}").is_ok());
+        assert!(check_file_name("{commit}")
+            .unwrap_err()
+            .contains("{commit}"));
+        assert!(check_file_name("runs/{sha}").is_err());
+        assert!(check_file_name("{sha").is_err());
+        assert!(check_file_name("latest").is_err());
+        assert!(check_file_name(".index").is_err());
+        assert!(check_file_name(".{sha}").is_err());
+        assert!(check_file_name("v1.{sha}").is_ok());
+
+        assert_eq!("all".parse(), Ok(Retention::All));
+        assert_eq!("20".parse(), Ok(Retention::Runs(20)));
+        assert_eq!("30d".parse(), Ok(Retention::Days(30)));
+        assert!("0".parse::<Retention>().is_err());
+        assert!("weekly".parse::<Retention>().is_err());
+    }
+
+    fn files(dir: &Path) -> Vec<String> {
+        let mut files: Vec<_> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn should_prune_only_recorded_runs_when_retention_set() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        // Recorded `days` ago: `a` is the oldest
+        let mut index = String::new();
+        for (stem, days) in [("a", 5), ("b", 4), ("c", 2)] {
+            for ext in ["json", "txt"] {
+                std::fs::write(dir.path().join(format!("{}.{}", stem, ext)), "{}").unwrap();
+            }
+            let written = now - days * 86_400;
+            index.push_str(&format!("{}\tjson\t{}.json\t{}.txt\n", written, stem, stem));
+        }
+        std::fs::write(dir.path().join("old.jsonl"), "").unwrap();
+        index.push_str(&format!("{}\tjsonl\told.jsonl\n", now - 9 * 86_400));
+        std::fs::write(dir.path().join(INDEX_FILE), index).unwrap();
+        // Written by the user, not a reporter
+        std::fs::write(dir.path().join("baseline.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("latest.json"), "{}").unwrap();
+
+        let d = ["d.json".to_string(), "d.txt".to_string()];
+        std::fs::write(dir.path().join("d.json"), "{}").unwrap();
+        assert_eq!(
+            record_and_prune(dir.path(), "json", &d, Retention::Runs(3)).unwrap(),
+            1
+        );
+        assert!(!dir.path().join("a.json").exists());
+        assert!(!dir.path().join("a.txt").exists());
+
+        // Rewriting `d` replaces its entry instead of adding a run
+        assert_eq!(
+            record_and_prune(dir.path(), "json", &d, Retention::Days(3)).unwrap(),
+            1
+        );
+        assert_eq!(
+            files(dir.path()),
+            [
+                ".index",
+                "baseline.json",
+                "c.json",
+                "c.txt",
+                "d.json",
+                "latest.json",
+                "old.jsonl"
+            ]
+        );
+        let index = std::fs::read_to_string(dir.path().join(INDEX_FILE)).unwrap();
+        assert_eq!(index.lines().count(), 3);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn should_load_gzipped_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("run.json.gz");
+        let json = serde_json::to_string(&suite()).unwrap();
+        std::fs::write(&path, gzip(json.as_bytes()).unwrap()).unwrap();
+
+        let loaded = SuiteResult::load(&path).unwrap();
+        assert_eq!(loaded.git_branch.as_deref(), Some("feature/faster-scan"));
+    }
+
+    #[test]
+    fn should_format_utc_date_and_time() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_time(86_399), "235959");
+    }
+}
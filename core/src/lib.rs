@@ -46,6 +46,8 @@
 //! cargo stress                          # Run all stress tests
 //! cargo stress --workload "database*"   # Run matching tests
 //! cargo stress --workload "*insert*"    # Glob patterns supported
+//! cargo stress --workload "db::**" --exclude "*slow*"
+//! cargo stress --regex --workload "^write_\d+k$"
 //! ```
 //!
 //! ## Manual Runner Style
@@ -75,28 +77,68 @@
 //! ## Features
 //!
 //! - **Single-shot measurements** — no statistical sampling overhead
-//! - **Glob filtering** — run subsets with `--workload "pattern*"`
+//! - **Glob filtering** — run subsets with `--workload "pattern*"` (or `--exact`, `--regex`)
 
 mod config;
+mod console;
 mod context;
+mod csv;
+mod environment;
+pub mod faults;
+mod filter;
 mod harness;
+mod html;
+mod isolate;
+mod jsonl;
+mod junit;
+mod layout;
+mod limits;
+mod markdown;
+mod progress;
+mod prometheus;
 mod report;
 mod result;
+mod rng;
 mod runner;
+mod sched;
+mod select;
+pub mod soak;
+pub mod workload;
 
-pub use config::BenchRunnerConfig;
+pub use config::{
+    AdaptiveConfig, BenchOrder, BenchOverrides, BenchRunnerConfig, BenchSettings, ProgressMode,
+    ReporterKind,
+};
+pub use console::{ByteUnits, ColorMode, ConsoleColumn, ConsoleOptions, ConsoleSort, TimeUnit};
 pub use context::StressContext;
+pub use filter::{FilterError, FilterMode, NameFilter};
+pub use limits::{AppliedLimits, ResourceLimits};
+pub use progress::ProgressHandle;
+pub use prometheus::PrometheusReporter;
+pub use rng::StressRng;
+pub use sched::{pin_current_thread, Priority, Scheduling};
 /// Backwards compatibility alias
 #[doc(hidden)]
 #[deprecated(since = "0.2.0", note = "Use StressContext instead")]
 pub type BenchContext = StressContext;
-pub use report::{ConsoleReporter, JsonReporter, MultiReporter, Reporter};
-pub use result::{BenchResult, SuiteResult};
-pub use runner::BenchRunner;
+pub use csv::{results_to_csv, CsvFormat, CsvReporter, BENCH_COLUMNS, RUN_COLUMNS};
+pub use environment::Environment;
+pub use html::HtmlReporter;
+pub use jsonl::{JsonLinesEvent, JsonLinesReporter};
+pub use junit::JunitReporter;
+pub use layout::{check_file_name, LatestMode, OutputLayout, Retention, DEFAULT_FILE_NAME};
+pub use markdown::{comparison_markdown, MarkdownReporter};
+pub use report::{ConsoleReporter, GitHubActionsReporter, JsonReporter, MultiReporter, Reporter};
+pub use result::{
+    AdaptiveOutcome, BenchFailure, BenchResult, FaultKind, InjectedFault, RunInfo, SkippedBench,
+    SuiteResult,
+};
+pub use runner::{BenchCase, BenchRunner};
 
 // Harness exports for auto-discovery
-pub use harness::{benchmark_count, list_benchmarks};
+pub use harness::{benchmark_count, format_benchmark_list, list_benchmarks};
 pub use harness::{run_registered_benchmarks, run_with_options, StressRunnerOptions};
+pub use harness::{BenchmarkInfo, BenchmarkList, BenchmarkParameters, ListFormat};
 
 // Entry point for stress binaries (called by stress_main! macro)
 pub use harness::stress_binary_main;
@@ -0,0 +1,384 @@
+//! Per-benchmark resource limits (Linux).
+//!
+//! Limits are applied to the isolated child process that runs a benchmark,
+//! so they never constrain the harness itself:
+//!
+//! - `memory` sets `RLIMIT_AS` (address space)
+//! - `open_files` sets `RLIMIT_NOFILE`
+//! - `cpus` restricts the CPU affinity of the benchmark thread, and of threads
+//!   it spawns afterwards, to that many cores (the `--pin` CPUs first)
+//!
+//! With cgroups enabled, `memory` and `cpus` are additionally enforced through
+//! a cgroup v2 child group (`memory.max`, `cpu.max`) when the current cgroup is
+//! writable. Whatever could not be applied is recorded as a warning in
+//! [`AppliedLimits`] rather than failing the benchmark.
+
+use serde::{Deserialize, Serialize};
+
+/// Resource limits requested for a benchmark.
+///
+/// ```rust,no_run
+/// use cntryl_stress::{BenchOverrides, ResourceLimits};
+///
+/// let limits = ResourceLimits::new().memory(512 << 20).cpus(2);
+/// let overrides = BenchOverrides::new().limits(limits);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// Address space limit in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<u64>,
+    /// Maximum number of open file descriptors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_files: Option<u64>,
+    /// Number of CPU cores the benchmark may run on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<usize>,
+}
+
+impl ResourceLimits {
+    /// No limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the address space to `bytes`.
+    pub fn memory(mut self, bytes: u64) -> Self {
+        self.memory = Some(bytes);
+        self
+    }
+
+    /// Limit open file descriptors.
+    pub fn open_files(mut self, n: u64) -> Self {
+        self.open_files = Some(n);
+        self
+    }
+
+    /// Restrict the benchmark to `n` CPU cores.
+    ///
+    /// Only the benchmark thread and threads it spawns afterwards are
+    /// restricted; threads that already exist keep their affinity.
+    pub fn cpus(mut self, n: usize) -> Self {
+        self.cpus = Some(n);
+        self
+    }
+
+    /// Whether no limit is set.
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_none() && self.open_files.is_none() && self.cpus.is_none()
+    }
+
+    /// Fill limits unset in `self` from `fallback`.
+    pub fn or(self, fallback: ResourceLimits) -> Self {
+        Self {
+            memory: self.memory.or(fallback.memory),
+            open_files: self.open_files.or(fallback.open_files),
+            cpus: self.cpus.or(fallback.cpus),
+        }
+    }
+}
+
+/// Limits that were actually in effect for a benchmark, recorded in
+/// [`BenchResult::limits`](crate::BenchResult::limits).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedLimits {
+    /// The limits that were requested
+    pub requested: ResourceLimits,
+    /// Address space limit set with `setrlimit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_space: Option<u64>,
+    /// File descriptor limit set with `setrlimit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_files: Option<u64>,
+    /// CPUs in the affinity mask
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu_affinity: Vec<usize>,
+    /// cgroup v2 directory holding `memory.max` / `cpu.max`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cgroup: Option<String>,
+    /// Limits that could not be applied, and why
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl AppliedLimits {
+    /// Record limits that were requested but not applied at all.
+    pub(crate) fn skipped(requested: ResourceLimits, reason: &str) -> Self {
+        Self {
+            requested,
+            warnings: vec![reason.to_string()],
+            ..Default::default()
+        }
+    }
+}
+
+/// Parse a byte size such as `512M`, `2G`, `1.5GiB` or `4096`.
+///
+/// Suffixes are binary (`K` = 1024) and case-insensitive; a trailing `B` or
+/// `iB` is accepted.
+pub(crate) fn parse_size(s: &str) -> Result<u64, String> {
+    let trimmed = s.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    let unit_start = lower
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(lower.len());
+    let (number, unit) = lower.split_at(unit_start);
+    let multiplier: u64 = match unit.trim() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        _ => {
+            return Err(format!(
+                "invalid size '{}' (expected e.g. 512M or 2G)",
+                trimmed
+            ))
+        }
+    };
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid size '{}' (expected e.g. 512M or 2G)", trimmed))?;
+    Ok((value * multiplier as f64) as u64)
+}
+
+/// Apply `limits` to the current process. `pin` is the `--pin` CPU list,
+/// preferred when restricting the CPU count.
+#[cfg(target_os = "linux")]
+pub(crate) fn apply(limits: ResourceLimits, cgroup: bool, pin: &[usize]) -> AppliedLimits {
+    let mut applied = AppliedLimits {
+        requested: limits,
+        ..Default::default()
+    };
+
+    if let Some(bytes) = limits.memory {
+        match linux::set_rlimit(linux::Rlimit::AddressSpace, bytes) {
+            Ok(set) => applied.address_space = Some(set),
+            Err(e) => applied.warnings.push(format!("RLIMIT_AS: {}", e)),
+        }
+    }
+    if let Some(n) = limits.open_files {
+        match linux::set_rlimit(linux::Rlimit::OpenFiles, n) {
+            Ok(set) => applied.open_files = Some(set),
+            Err(e) => applied.warnings.push(format!("RLIMIT_NOFILE: {}", e)),
+        }
+    }
+    if let Some(n) = limits.cpus {
+        match linux::restrict_affinity(n, pin) {
+            Ok(cpus) => applied.cpu_affinity = cpus,
+            Err(e) => applied.warnings.push(format!("CPU affinity: {}", e)),
+        }
+    }
+    if cgroup && (limits.memory.is_some() || limits.cpus.is_some()) {
+        match linux::join_limited_cgroup(limits) {
+            Ok(path) => applied.cgroup = Some(path),
+            Err(e) => applied.warnings.push(format!("cgroup: {}", e)),
+        }
+    }
+
+    applied
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn apply(limits: ResourceLimits, _cgroup: bool, _pin: &[usize]) -> AppliedLimits {
+    AppliedLimits::skipped(limits, "resource limits are only supported on Linux")
+}
+
+/// Remove the cgroup a finished child process created for itself.
+#[cfg(target_os = "linux")]
+pub(crate) fn remove_child_cgroup(pid: u32) {
+    if let Some(dir) = linux::cgroup_dir_for(pid) {
+        let _ = std::fs::remove_dir(dir);
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn remove_child_cgroup(_pid: u32) {}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::ResourceLimits;
+    use crate::sched;
+    use std::io;
+    use std::path::PathBuf;
+
+    /// cgroup v2 `cpu.max` period in microseconds.
+    const CPU_PERIOD_US: u64 = 100_000;
+
+    /// Resources limited with `setrlimit`.
+    #[derive(Clone, Copy)]
+    pub(super) enum Rlimit {
+        AddressSpace,
+        OpenFiles,
+    }
+
+    /// Lower the soft limit of `which` to `value` (capped at the hard
+    /// limit). Returns the limit that was set.
+    // `rlim_t` is 32 bits wide on some 32-bit targets
+    #[allow(clippy::unnecessary_cast)]
+    pub(super) fn set_rlimit(which: Rlimit, value: u64) -> io::Result<u64> {
+        let resource = match which {
+            Rlimit::AddressSpace => libc::RLIMIT_AS,
+            Rlimit::OpenFiles => libc::RLIMIT_NOFILE,
+        };
+        let mut current = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: `current` is a valid, writable rlimit
+        if unsafe { libc::getrlimit(resource, &mut current) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let cur = (value as libc::rlim_t).min(current.rlim_max);
+        let limit = libc::rlimit {
+            rlim_cur: cur,
+            rlim_max: current.rlim_max,
+        };
+        // SAFETY: `limit` is a valid rlimit
+        if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(cur as u64)
+    }
+
+    /// Restrict the calling thread's affinity mask to `n` CPUs: the `pin`
+    /// CPUs first, then the rest of the process affinity captured before
+    /// pinning. Threads spawned afterwards inherit the mask; other existing
+    /// threads are not affected.
+    pub(super) fn restrict_affinity(n: usize, pin: &[usize]) -> io::Result<Vec<usize>> {
+        let mut allowed = pin.to_vec();
+        for cpu in sched::process_cpus()? {
+            if !allowed.contains(&cpu) {
+                allowed.push(cpu);
+            }
+        }
+        if allowed.len() < n {
+            return Err(io::Error::other(format!(
+                "requested {} CPUs but only {} are available",
+                n,
+                allowed.len()
+            )));
+        }
+
+        let chosen = allowed[..n].to_vec();
+        sched::set_affinity(&chosen)?;
+        Ok(chosen)
+    }
+
+    /// Move this process into a new child of its cgroup with `memory.max` and
+    /// `cpu.max` set. Returns the cgroup directory.
+    pub(super) fn join_limited_cgroup(limits: ResourceLimits) -> io::Result<String> {
+        let dir = cgroup_dir_for(std::process::id())
+            .ok_or_else(|| io::Error::other("no cgroup v2 hierarchy found"))?;
+        std::fs::create_dir(&dir)?;
+
+        let result = (|| {
+            if let Some(bytes) = limits.memory {
+                std::fs::write(dir.join("memory.max"), bytes.to_string())?;
+            }
+            if let Some(n) = limits.cpus {
+                let quota = n as u64 * CPU_PERIOD_US;
+                std::fs::write(dir.join("cpu.max"), format!("{} {}", quota, CPU_PERIOD_US))?;
+            }
+            std::fs::write(dir.join("cgroup.procs"), std::process::id().to_string())
+        })();
+
+        match result {
+            Ok(()) => Ok(dir.display().to_string()),
+            Err(e) => {
+                let _ = std::fs::remove_dir(&dir);
+                Err(e)
+            }
+        }
+    }
+
+    /// Directory of the per-process cgroup for `pid`, a child of the cgroup
+    /// of the calling process (the child before it moves, or the parent).
+    pub(super) fn cgroup_dir_for(pid: u32) -> Option<PathBuf> {
+        // cgroup v2 has a single "0::<path>" line
+        let cgroups = std::fs::read_to_string("/proc/self/cgroup").ok()?;
+        let own = cgroups.lines().find_map(|line| line.strip_prefix("0::"))?;
+        // The cgroup2 mount is /sys/fs/cgroup on unified systems but e.g.
+        // /sys/fs/cgroup/unified on hybrid ones
+        let mounts = std::fs::read_to_string("/proc/self/mounts").ok()?;
+        let root = mounts.lines().find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let mount_point = fields.nth(1)?;
+            (fields.next()? == "cgroup2").then_some(mount_point)
+        })?;
+
+        let mut dir = PathBuf::from(root);
+        dir.push(own.trim_start_matches('/'));
+        if !dir.join("cgroup.controllers").exists() {
+            return None;
+        }
+        Some(dir.join(format!("cntryl-stress-{}", pid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_os = "linux")]
+    use crate::sched;
+
+    #[test]
+    fn should_parse_sizes_with_binary_suffixes() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("512M"), Ok(512 << 20));
+        assert_eq!(parse_size("2g"), Ok(2 << 30));
+        assert_eq!(parse_size("1.5GiB"), Ok(3 << 29));
+        assert_eq!(parse_size("64 KB"), Ok(64 << 10));
+        assert!(parse_size("lots").is_err());
+        assert!(parse_size("12X").is_err());
+    }
+
+    #[test]
+    fn should_fill_unset_limits_from_fallback() {
+        let attribute = ResourceLimits::new().memory(1 << 30);
+        let cli = ResourceLimits::new().memory(1 << 20).cpus(2);
+        assert_eq!(
+            attribute.or(cli),
+            ResourceLimits::new().memory(1 << 30).cpus(2)
+        );
+        assert!(ResourceLimits::new().is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[allow(clippy::unnecessary_cast)]
+    fn should_keep_open_file_limit_within_hard_limit() {
+        let mut current = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: `current` is a valid, writable rlimit
+        assert_eq!(
+            unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut current) },
+            0
+        );
+        // Re-applying the current soft limit is a no-op for the test process
+        let set = linux::set_rlimit(linux::Rlimit::OpenFiles, current.rlim_cur as u64).unwrap();
+        assert_eq!(set, current.rlim_cur as u64);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_restrict_affinity_beyond_pinned_cpu() {
+        let cpus = sched::process_cpus().unwrap();
+        if cpus.len() < 2 {
+            return;
+        }
+        let (first, pinned) = (cpus[0], cpus[1]);
+        let (chosen, allowed) = std::thread::spawn(move || {
+            sched::pin_current_thread(pinned).unwrap();
+            let chosen = linux::restrict_affinity(2, &[pinned]).unwrap();
+            (chosen, sched::allowed_cpus().unwrap())
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(chosen, [pinned, first]);
+        assert_eq!(allowed, [first, pinned]);
+    }
+}
@@ -0,0 +1,345 @@
+//! Markdown comparison of a suite against a baseline, for PR comments and
+//! the GitHub Actions job summary.
+//!
+//! ```text
+//! ### Benchmarks: storage
+//!
+//! Baseline `36d2a43` → current `9f1c0b2` · threshold ±5.0% · 1 regression, 1 improvement, 2 unchanged
+//!
+//! | | Benchmark | Baseline | Current | Change | Throughput |
+//! |:-:|---|---:|---:|---:|---:|
+//! | 🔴 | `compaction` | 1.20s | 1.45s | +20.8% | 72.31 MB/s |
+//! | 🟢 | `point_read` | 12.40us | 9.10us | -26.6% | |
+//!
+//! <details><summary>2 unchanged</summary>
+//! ...
+//! </details>
+//! ```
+//!
+//! Changed rows are sorted by the size of the change, largest first; rows
+//! within the threshold are collapsed.
+
+use crate::report::{
+    format_duration, load_baseline, short_name, suite_path_name, ConsoleReporter, Reporter,
+};
+use crate::result::{BenchResult, SuiteResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const HEADER: &str =
+    "| | Benchmark | Baseline | Current | Change | Throughput |\n|:-:|---|---:|---:|---:|---:|\n";
+
+/// How a benchmark compares with the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    New,
+    Removed,
+}
+
+impl Verdict {
+    fn emoji(self) -> &'static str {
+        match self {
+            Verdict::Regression => "🔴",
+            Verdict::Improvement => "🟢",
+            Verdict::Unchanged => "⚪",
+            Verdict::New => "🆕",
+            Verdict::Removed => "➖",
+        }
+    }
+}
+
+struct Row<'a> {
+    verdict: Verdict,
+    name: &'a str,
+    baseline: Option<&'a BenchResult>,
+    current: Option<&'a BenchResult>,
+    /// `current / baseline`, if both exist
+    ratio: Option<f64>,
+}
+
+impl Row<'_> {
+    fn format(&self) -> String {
+        let duration =
+            |r: Option<&BenchResult>| r.map_or("-".to_string(), |r| format_duration(r.duration));
+        let change = self
+            .ratio
+            .map(|ratio| format!("{:+.1}%", (ratio - 1.0) * 100.0))
+            .unwrap_or_default();
+        let throughput = self
+            .current
+            .map(ConsoleReporter::format_throughput)
+            .unwrap_or_default();
+        format!(
+            "| {} | `{}` | {} | {} | {} | {} |\n",
+            self.verdict.emoji(),
+            self.name.replace('|', "\\|"),
+            duration(self.baseline),
+            duration(self.current),
+            change,
+            throughput
+        )
+    }
+
+    /// Size of the change, for sorting.
+    fn magnitude(&self) -> f64 {
+        self.ratio.map_or(0.0, |ratio| (ratio - 1.0).abs())
+    }
+}
+
+/// Render `current` as Markdown, compared with `baseline` if given.
+///
+/// Benchmarks more than `threshold` slower are regressions, more than
+/// `threshold` faster are improvements. Failed and skipped benchmarks are
+/// listed after the table.
+pub fn comparison_markdown(
+    current: &SuiteResult,
+    baseline: Option<&SuiteResult>,
+    threshold: f64,
+) -> String {
+    let mut out = format!("### Benchmarks: {}\n\n", current.suite);
+    match baseline {
+        Some(baseline) => out.push_str(&comparison_table(current, baseline, threshold)),
+        None => {
+            out.push_str("| Benchmark | Duration | Throughput |\n|---|---:|---:|\n");
+            for r in &current.results {
+                out.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    short_name(&r.name).replace('|', "\\|"),
+                    format_duration(r.duration),
+                    ConsoleReporter::format_throughput(r)
+                ));
+            }
+        }
+    }
+
+    if !current.failures.is_empty() {
+        out.push_str("\n**Failed:**\n\n");
+        for f in &current.failures {
+            out.push_str(&format!("- `{}`: {}\n", short_name(&f.name), f.message));
+        }
+    }
+    if !current.skipped.is_empty() {
+        out.push_str("\n**Skipped:**\n\n");
+        for s in &current.skipped {
+            out.push_str(&format!("- `{}`: {}\n", short_name(&s.name), s.reason));
+        }
+    }
+    out
+}
+
+fn comparison_table(current: &SuiteResult, baseline: &SuiteResult, threshold: f64) -> String {
+    let previous: HashMap<_, _> = baseline.results.iter().map(|r| (&r.name, r)).collect();
+    let mut rows: Vec<Row> = current
+        .results
+        .iter()
+        .map(|r| {
+            let before = previous.get(&r.name).copied();
+            let ratio = before.map(|b| r.compare(b));
+            let verdict = match ratio {
+                None => Verdict::New,
+                Some(ratio) if ratio > 1.0 + threshold => Verdict::Regression,
+                Some(ratio) if ratio < 1.0 - threshold => Verdict::Improvement,
+                Some(_) => Verdict::Unchanged,
+            };
+            Row {
+                verdict,
+                name: short_name(&r.name),
+                baseline: before,
+                current: Some(r),
+                ratio,
+            }
+        })
+        .collect();
+    let current_names: std::collections::HashSet<_> =
+        current.results.iter().map(|r| &r.name).collect();
+    rows.extend(
+        baseline
+            .results
+            .iter()
+            .filter(|b| !current_names.contains(&b.name))
+            .map(|b| Row {
+                verdict: Verdict::Removed,
+                name: short_name(&b.name),
+                baseline: Some(b),
+                current: None,
+                ratio: None,
+            }),
+    );
+    // Largest changes first; new and removed benchmarks last
+    rows.sort_by(|a, b| {
+        let rank = |r: &Row| matches!(r.verdict, Verdict::New | Verdict::Removed);
+        rank(a)
+            .cmp(&rank(b))
+            .then(b.magnitude().total_cmp(&a.magnitude()))
+    });
+
+    let count = |verdict| rows.iter().filter(|r| r.verdict == verdict).count();
+    let plural = |n: usize, word: &str| match n {
+        1 => format!("1 {}", word),
+        n => format!("{} {}s", n, word),
+    };
+    let mut counts = vec![
+        plural(count(Verdict::Regression), "regression"),
+        plural(count(Verdict::Improvement), "improvement"),
+        format!("{} unchanged", count(Verdict::Unchanged)),
+    ];
+    if count(Verdict::New) > 0 {
+        counts.push(format!("{} new", count(Verdict::New)));
+    }
+    if count(Verdict::Removed) > 0 {
+        counts.push(format!("{} removed", count(Verdict::Removed)));
+    }
+
+    let sha = |suite: &SuiteResult| {
+        suite
+            .git_sha
+            .as_deref()
+            .map_or("unknown".to_string(), |sha| {
+                format!("`{}`", sha.chars().take(7).collect::<String>())
+            })
+    };
+    let mut out = format!(
+        "Baseline {} → current {} · threshold ±{:.1}% · {}\n\n",
+        sha(baseline),
+        sha(current),
+        threshold * 100.0,
+        counts.join(", ")
+    );
+
+    let (unchanged, changed): (Vec<_>, Vec<_>) =
+        rows.iter().partition(|r| r.verdict == Verdict::Unchanged);
+    if !changed.is_empty() {
+        out.push_str(HEADER);
+        for row in &changed {
+            out.push_str(&row.format());
+        }
+    }
+    if !unchanged.is_empty() {
+        if !changed.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "<details><summary>{} unchanged</summary>\n\n{}",
+            unchanged.len(),
+            HEADER
+        ));
+        for row in &unchanged {
+            out.push_str(&row.format());
+        }
+        out.push_str("\n</details>\n");
+    }
+    out
+}
+
+/// Reporter that writes the comparison to `{output_dir}/{suite}/comparison.md`.
+pub struct MarkdownReporter {
+    output_dir: PathBuf,
+    baseline: Option<SuiteResult>,
+    threshold: f64,
+}
+
+impl MarkdownReporter {
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            baseline: None,
+            threshold: 0.05,
+        }
+    }
+
+    /// Load baseline from a file for comparison.
+    pub fn with_baseline(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.baseline = load_baseline(path.as_ref());
+        self
+    }
+
+    /// Set the regression threshold (default: 0.05 = 5%).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn write(&self, suite: &SuiteResult) -> std::io::Result<PathBuf> {
+        let suite_dir = self.output_dir.join(suite_path_name(&suite.suite));
+        std::fs::create_dir_all(&suite_dir)?;
+        let path = suite_dir.join("comparison.md");
+        let markdown = comparison_markdown(suite, self.baseline.as_ref(), self.threshold);
+        std::fs::write(&path, markdown)?;
+        Ok(path)
+    }
+}
+
+impl Reporter for MarkdownReporter {
+    fn suite_end(&self, result: &SuiteResult) {
+        match self.write(result) {
+            Ok(path) => eprintln!("  Markdown comparison at: {}", path.display()),
+            Err(e) => eprintln!("Warning: failed to write Markdown comparison: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::BenchFailure;
+
+    fn suite(sha: &str, results: &[(&str, u64)]) -> SuiteResult {
+        SuiteResult {
+            git_sha: Some(sha.to_string()),
+            ..SuiteResult::fixture("io", results)
+        }
+    }
+
+    #[test]
+    fn should_sort_changes_and_collapse_unchanged_rows() {
+        let baseline = suite(
+            "36d2a432aa",
+            &[("write", 100), ("read", 100), ("scan", 100), ("old", 3)],
+        );
+        let mut current = suite(
+            "9f1c0b2bb",
+            &[("write", 110), ("read", 60), ("scan", 101), ("new", 5)],
+        );
+        current.results[0].bytes = Some(1_100_000);
+        current.failures.push(BenchFailure {
+            name: "io/compact".to_string(),
+            message: "panicked: boom".to_string(),
+        });
+
+        let markdown = comparison_markdown(&current, Some(&baseline), 0.05);
+        let lines: Vec<_> = markdown.lines().collect();
+        assert_eq!(lines[0], "### Benchmarks: io");
+        assert_eq!(
+            lines[2],
+            "Baseline `36d2a43` → current `9f1c0b2` · threshold ±5.0% · \
+             1 regression, 1 improvement, 1 unchanged, 1 new, 1 removed"
+        );
+        assert_eq!(
+            &lines[4..10],
+            [
+                "| | Benchmark | Baseline | Current | Change | Throughput |",
+                "|:-:|---|---:|---:|---:|---:|",
+                "| 🟢 | `read` | 100.00ms | 60.00ms | -40.0% |  |",
+                "| 🔴 | `write` | 100.00ms | 110.00ms | +10.0% | 10.00 MB/s |",
+                "| 🆕 | `new` | - | 5.00ms |  |  |",
+                "| ➖ | `old` | 3.00ms | - |  |  |",
+            ]
+        );
+        assert_eq!(lines[11], "<details><summary>1 unchanged</summary>");
+        assert!(markdown.contains("| ⚪ | `scan` | 100.00ms | 101.00ms | +1.0% |  |\n\n</details>"));
+        assert!(markdown.contains("**Failed:**\n\n- `compact`: panicked: boom\n"));
+    }
+
+    #[test]
+    fn should_list_results_without_baseline() {
+        let markdown = comparison_markdown(&suite("abc", &[("write", 2)]), None, 0.05);
+        assert_eq!(
+            markdown,
+            "### Benchmarks: io\n\n| Benchmark | Duration | Throughput |\n|---|---:|---:|\n\
+             | `write` | 2.00ms |  |\n"
+        );
+    }
+}
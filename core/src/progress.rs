@@ -0,0 +1,294 @@
+//! Heartbeat lines for long-running benchmarks.
+//!
+//! The console reporter prints a benchmark only once it finishes, so a
+//! 40-minute benchmark is silent for 40 minutes. When enabled (see
+//! [`ProgressMode`](crate::ProgressMode)), a background thread prints a line
+//! to stderr every interval while a benchmark runs:
+//!
+//! ```text
+//!   ... storage::full_compaction  run 3/10  elapsed 24m10s  ETA 56m20s  [inner 41%]
+//! ```
+//!
+//! The ETA comes from the wall time of the benchmark's finished runs, or from
+//! [`StressContext::progress`](crate::StressContext::progress) before the
+//! first run has finished.
+
+use crate::report::Reporter;
+use crate::result::SuiteResult;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Handle for reporting progress within a run, e.g. from inside `measure`
+/// or from worker threads. Obtained from
+/// [`StressContext::progress_handle`](crate::StressContext::progress_handle).
+#[derive(Debug, Clone, Default)]
+pub struct ProgressHandle {
+    progress: Option<Arc<Progress>>,
+}
+
+impl ProgressHandle {
+    pub(crate) fn new(progress: Option<Arc<Progress>>) -> Self {
+        Self { progress }
+    }
+
+    /// Report that `done` of `total` units of the current run are complete.
+    ///
+    /// Cheap enough for hot loops; does nothing if progress output is off.
+    pub fn update(&self, done: u64, total: u64) {
+        if let Some(progress) = &self.progress {
+            progress.inner_done.store(done, Ordering::Relaxed);
+            progress.inner_total.store(total, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Progress of the benchmarks in flight, shared by the runner, the
+/// heartbeat thread and benchmark contexts.
+#[derive(Debug, Default)]
+pub(crate) struct Progress {
+    state: Mutex<State>,
+    inner_done: AtomicU64,
+    inner_total: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    current: Option<Current>,
+    benches: HashMap<String, BenchStats>,
+}
+
+/// The run in progress.
+#[derive(Debug)]
+struct Current {
+    bench: String,
+    run: usize,
+    /// Total runs, if known up front (not in adaptive or soak mode)
+    runs: Option<usize>,
+    warmup: bool,
+    started: Instant,
+}
+
+#[derive(Debug)]
+struct BenchStats {
+    started: Instant,
+    finished: u32,
+    /// Wall time of finished measured runs, setup and teardown included
+    spent: Duration,
+}
+
+impl Progress {
+    /// A measured (or warmup) run of `bench` is starting.
+    pub(crate) fn run_started(&self, bench: &str, run: usize, runs: Option<usize>, warmup: bool) {
+        self.inner_done.store(0, Ordering::Relaxed);
+        self.inner_total.store(0, Ordering::Relaxed);
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .benches
+            .entry(bench.to_string())
+            .or_insert_with(|| BenchStats {
+                started: now,
+                finished: 0,
+                spent: Duration::ZERO,
+            });
+        state.current = Some(Current {
+            bench: bench.to_string(),
+            run,
+            runs,
+            warmup,
+            started: now,
+        });
+    }
+
+    /// The current run has finished.
+    pub(crate) fn run_finished(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let State { current, benches } = &mut *state;
+        let Some(current) = current.take() else {
+            return;
+        };
+        if let Some(stats) = benches.get_mut(&current.bench).filter(|_| !current.warmup) {
+            stats.finished += 1;
+            stats.spent += current.started.elapsed();
+        }
+    }
+
+    /// Forget `bench` once it has finished.
+    pub(crate) fn bench_finished(&self, bench: &str) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.benches.remove(bench);
+        if state.current.as_ref().is_some_and(|c| c.bench == bench) {
+            state.current = None;
+        }
+    }
+
+    /// Heartbeat line for the run in progress, if any.
+    fn line(&self, now: Instant) -> Option<String> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let current = state.current.as_ref()?;
+        let stats = state.benches.get(&current.bench)?;
+        let run_elapsed = now.saturating_duration_since(current.started);
+        let done = self.inner_done.load(Ordering::Relaxed);
+        let total = self.inner_total.load(Ordering::Relaxed);
+
+        let kind = if current.warmup { "warmup" } else { "run" };
+        let mut line = match current.runs {
+            Some(runs) => format!(
+                "  ... {}  {} {}/{}",
+                current.bench,
+                kind,
+                current.run + 1,
+                runs
+            ),
+            None => format!("  ... {}  {} {}", current.bench, kind, current.run + 1),
+        };
+        line.push_str(&format!(
+            "  elapsed {}",
+            format_clock(now.saturating_duration_since(stats.started))
+        ));
+
+        // Remaining time of this run: from inner progress if reported,
+        // otherwise from the average finished run
+        let per_run = (stats.finished > 0).then(|| stats.spent / stats.finished);
+        let inner = (done > 0 && total >= done)
+            .then(|| run_elapsed.as_secs_f64() * (total - done) as f64 / done as f64)
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+        let this_run = inner.or_else(|| per_run.map(|p| p.saturating_sub(run_elapsed)));
+        if let (false, Some(runs), Some(this_run)) = (current.warmup, current.runs, this_run) {
+            let later_runs = runs.saturating_sub(current.run + 1) as u32;
+            // Without finished runs, assume later runs take as long as this one
+            let per_run = per_run.unwrap_or(run_elapsed + this_run);
+            line.push_str(&format!(
+                "  ETA {}",
+                format_clock(this_run + per_run * later_runs)
+            ));
+        }
+
+        if total > 0 {
+            let pct = done.min(total) as f64 / total as f64 * 100.0;
+            line.push_str(&format!("  [inner {:.0}%]", pct));
+        }
+        Some(line)
+    }
+}
+
+/// Format a duration for humans watching a clock: `45s`, `12m05s`, `2h03m`.
+fn format_clock(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Reporter that prints a heartbeat line to stderr every `interval` while
+/// a benchmark runs.
+pub(crate) struct ProgressReporter {
+    stop: Mutex<Option<Sender<()>>>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ProgressReporter {
+    pub(crate) fn new(progress: Arc<Progress>, interval: Duration) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                if let Some(line) = progress.line(Instant::now()) {
+                    let _ = writeln!(std::io::stderr().lock(), "{}", line);
+                }
+            }
+        });
+        Self {
+            stop: Mutex::new(Some(stop)),
+            thread: Mutex::new(Some(thread)),
+        }
+    }
+
+    fn stop(&self) {
+        // Dropping the sender wakes the heartbeat thread up
+        drop(self.stop.lock().unwrap_or_else(|e| e.into_inner()).take());
+        if let Some(thread) = self.thread.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Reporter for ProgressReporter {
+    fn suite_end(&self, _result: &SuiteResult) {
+        self.stop();
+    }
+}
+
+impl Drop for ProgressReporter {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_clock_durations() {
+        assert_eq!(format_clock(Duration::from_secs(45)), "45s");
+        assert_eq!(format_clock(Duration::from_secs(725)), "12m05s");
+        assert_eq!(format_clock(Duration::from_secs(7380)), "2h03m");
+    }
+
+    #[test]
+    fn should_estimate_eta_from_finished_runs() {
+        let progress = Progress::default();
+        progress.run_started("compaction", 0, Some(3), false);
+        std::thread::sleep(Duration::from_millis(20));
+        progress.run_finished();
+        progress.run_started("compaction", 1, Some(3), false);
+
+        let line = progress.line(Instant::now()).unwrap();
+        assert!(line.starts_with("  ... compaction  run 2/3  elapsed 0s  ETA "));
+        assert!(!line.contains("inner"));
+    }
+
+    #[test]
+    fn should_use_inner_progress_before_first_run_finishes() {
+        let progress = Arc::new(Progress::default());
+        progress.run_started("scan", 0, None, false);
+        ProgressHandle::new(Some(Arc::clone(&progress))).update(1, 4);
+        let line = progress.line(Instant::now()).unwrap();
+        assert_eq!(line, "  ... scan  run 1  elapsed 0s  [inner 25%]");
+
+        progress.run_started("scan", 0, Some(2), false);
+        ProgressHandle::new(Some(Arc::clone(&progress))).update(1, 4);
+        let line = progress
+            .line(Instant::now() + Duration::from_secs(10))
+            .unwrap();
+        // 10s for a quarter: 30s left in this run, 40s for the next
+        assert_eq!(
+            line,
+            "  ... scan  run 1/2  elapsed 10s  ETA 1m10s  [inner 25%]"
+        );
+    }
+
+    #[test]
+    fn should_stay_quiet_between_benchmarks() {
+        let progress = Progress::default();
+        assert_eq!(progress.line(Instant::now()), None);
+        progress.run_started("warm", 0, Some(2), true);
+        assert!(progress
+            .line(Instant::now())
+            .unwrap()
+            .contains("warmup 1/2"));
+        progress.run_finished();
+        assert_eq!(progress.line(Instant::now()), None);
+        progress.bench_finished("warm");
+        assert!(progress.state.lock().unwrap().benches.is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+//! OpenMetrics text output for the Prometheus node-exporter textfile
+//! collector.
+//!
+//! Each suite is written to `{output_dir}/{suite}.prom`:
+//!
+//! ```text
+//! # TYPE cntryl_stress_duration_seconds gauge
+//! # UNIT cntryl_stress_duration_seconds seconds
+//! # HELP cntryl_stress_duration_seconds Median duration of a measured run.
+//! cntryl_stress_duration_seconds{suite="storage",benchmark="scan",git_sha="36d2a43",tag_disk="nvme"} 0.0042
+//! ...
+//! # EOF
+//! ```
+//!
+//! Files are written to a temporary file and renamed into place, so the
+//! collector never reads a partial file.
+
+use crate::report::{suite_path_name, write_atomic, Reporter};
+use crate::result::{BenchResult, SuiteResult};
+use std::path::PathBuf;
+
+const QUANTILES: [f64; 4] = [0.5, 0.9, 0.95, 0.99];
+
+/// A metric family: its samples must be contiguous in the output.
+struct Family {
+    name: &'static str,
+    unit: Option<&'static str>,
+    help: &'static str,
+    samples: Vec<(Vec<(String, String)>, f64)>,
+}
+
+impl Family {
+    fn new(name: &'static str, unit: Option<&'static str>, help: &'static str) -> Self {
+        Self {
+            name,
+            unit,
+            help,
+            samples: Vec::new(),
+        }
+    }
+
+    fn format(&self, out: &mut String) {
+        if self.samples.is_empty() {
+            return;
+        }
+        out.push_str(&format!("# TYPE {} gauge\n", self.name));
+        if let Some(unit) = self.unit {
+            out.push_str(&format!("# UNIT {} {}\n", self.name, unit));
+        }
+        out.push_str(&format!("# HELP {} {}\n", self.name, self.help));
+        for (labels, value) in &self.samples {
+            let labels: Vec<_> = labels
+                .iter()
+                .map(|(name, value)| format!("{}=\"{}\"", name, escape(value)))
+                .collect();
+            out.push_str(&format!(
+                "{}{{{}}} {}\n",
+                self.name,
+                labels.join(","),
+                format_value(*value)
+            ));
+        }
+    }
+}
+
+/// Reporter that writes an OpenMetrics text file per suite.
+pub struct PrometheusReporter {
+    output_dir: PathBuf,
+}
+
+impl PrometheusReporter {
+    /// Write `{output_dir}/{suite}.prom`; point the textfile collector's
+    /// `--collector.textfile.directory` at `output_dir`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+        }
+    }
+
+    fn format_suite(suite: &SuiteResult) -> String {
+        let mut duration = Family::new(
+            "cntryl_stress_duration_seconds",
+            Some("seconds"),
+            "Median duration of a measured run.",
+        );
+        let mut quantiles = Family::new(
+            "cntryl_stress_duration_quantile_seconds",
+            Some("seconds"),
+            "Quantiles of the measured run durations.",
+        );
+        let mut bytes = Family::new(
+            "cntryl_stress_throughput_bytes_per_second",
+            None,
+            "Bytes processed per second at the median duration.",
+        );
+        let mut elements = Family::new(
+            "cntryl_stress_throughput_elements_per_second",
+            None,
+            "Elements processed per second at the median duration.",
+        );
+        let mut runs = Family::new("cntryl_stress_runs", None, "Number of measured runs.");
+        let mut metrics = Family::new(
+            "cntryl_stress_metric",
+            None,
+            "Custom metric reported with ctx.metric() in the last measured run.",
+        );
+
+        for result in &suite.results {
+            let labels = Self::labels(suite, result);
+            duration
+                .samples
+                .push((labels.clone(), result.duration.as_secs_f64()));
+            for q in QUANTILES {
+                let mut labels = labels.clone();
+                labels.push(("quantile".to_string(), q.to_string()));
+                quantiles
+                    .samples
+                    .push((labels, result.percentile(q * 100.0).as_secs_f64()));
+            }
+            if let Some(bps) = result.bytes_per_sec() {
+                bytes.samples.push((labels.clone(), bps));
+            }
+            if let Some(eps) = result.elements_per_sec() {
+                elements.samples.push((labels.clone(), eps));
+            }
+            runs.samples.push((labels.clone(), result.runs as f64));
+            for (name, value) in &result.metrics {
+                let mut labels = labels.clone();
+                labels.push(("metric".to_string(), name.clone()));
+                metrics.samples.push((labels, *value));
+            }
+        }
+
+        let mut out = String::new();
+        for family in [duration, quantiles, bytes, elements, runs, metrics] {
+            family.format(&mut out);
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+
+    /// `suite`, `benchmark`, `group`, `git_sha` and one `tag_{key}` per tag.
+    fn labels(suite: &SuiteResult, result: &BenchResult) -> Vec<(String, String)> {
+        let mut labels = vec![
+            ("suite".to_string(), suite.suite.clone()),
+            (
+                "benchmark".to_string(),
+                result
+                    .name
+                    .split_once('/')
+                    .map_or(result.name.as_str(), |(_, bench)| bench)
+                    .to_string(),
+            ),
+        ];
+        if let Some(group) = &result.group {
+            labels.push(("group".to_string(), group.clone()));
+        }
+        if let Some(sha) = &suite.git_sha {
+            labels.push(("git_sha".to_string(), sha.clone()));
+        }
+        // Sorted so identical results produce identical files
+        let mut tags: Vec<_> = result.tags.iter().collect();
+        tags.sort();
+        for (key, value) in tags {
+            labels.push((format!("tag_{}", label_name(key)), value.clone()));
+        }
+        labels
+    }
+
+    fn write(&self, suite: &SuiteResult) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(&self.output_dir)?;
+        let path = self
+            .output_dir
+            .join(format!("{}.prom", suite_path_name(&suite.suite)));
+        write_atomic(&path, Self::format_suite(suite).as_bytes())?;
+        Ok(path)
+    }
+}
+
+impl Reporter for PrometheusReporter {
+    fn suite_end(&self, result: &SuiteResult) {
+        match self.write(result) {
+            Ok(path) => eprintln!("  OpenMetrics at: {}", path.display()),
+            Err(e) => eprintln!("Warning: failed to write OpenMetrics file: {}", e),
+        }
+    }
+}
+
+/// Tag key as a valid label name: `[a-zA-Z0-9_]`, other characters become `_`.
+fn label_name(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Escape a label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suite() -> SuiteResult {
+        let mut scan = BenchResult::fixture("storage/scan", 20)
+            .measured(&[10, 20, 30])
+            .bytes(2_000_000)
+            .tag("disk-kind", "nvme");
+        scan.metrics.insert("cache_hits".to_string(), 42.0);
+        SuiteResult {
+            suite: "storage".to_string(),
+            results: vec![scan],
+            git_sha: Some("36d2a432".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_write_gauges_labeled_by_suite_benchmark_tags_and_sha() {
+        let text = PrometheusReporter::format_suite(&suite());
+        let labels =
+            "suite=\"storage\",benchmark=\"scan\",git_sha=\"36d2a432\",tag_disk_kind=\"nvme\"";
+        assert!(text.starts_with(
+            "# TYPE cntryl_stress_duration_seconds gauge\n\
+             # UNIT cntryl_stress_duration_seconds seconds\n"
+        ));
+        assert!(text.contains(&format!(
+            "cntryl_stress_duration_seconds{{{}}} 0.02\n",
+            labels
+        )));
+        assert!(text.contains(&format!(
+            "cntryl_stress_duration_quantile_seconds{{{},quantile=\"0.9\"}} 0.028\n",
+            labels
+        )));
+        assert!(text.contains(&format!(
+            "cntryl_stress_throughput_bytes_per_second{{{}}} 100000000\n",
+            labels
+        )));
+        assert!(text.contains(&format!(
+            "cntryl_stress_metric{{{},metric=\"cache_hits\"}} 42\n",
+            labels
+        )));
+        // No elements were reported, so the family is left out
+        assert!(!text.contains("elements_per_second"));
+        assert!(text.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn should_escape_label_values() {
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+        assert_eq!(label_name("disk.kind-2"), "disk_kind_2");
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+    }
+
+    #[test]
+    fn should_replace_file_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let reporter = PrometheusReporter::new(dir.path());
+        std::fs::write(dir.path().join("storage.prom"), "stale").unwrap();
+
+        let path = reporter.write(&suite()).unwrap();
+        assert_eq!(path, dir.path().join("storage.prom"));
+        assert!(std::fs::read_to_string(&path).unwrap().ends_with("# EOF\n"));
+        // Only the final file remains
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
@@ -5,8 +5,16 @@
 //! - Atomic: output is written in complete lines to avoid interleaving
 //! - Deterministic: identical inputs produce identical outputs
 
-use crate::config::BenchRunnerConfig;
-use crate::result::{BenchResult, SuiteResult};
+use crate::config::{BenchRunnerConfig, BenchSettings};
+use crate::console::{
+    terminal_width, truncate_middle, ByteUnits, ConsoleOptions, ConsoleSort, Style,
+};
+use crate::layout::{record_and_prune, symlink_atomic, LatestMode, OutputLayout};
+use crate::markdown::comparison_markdown;
+use crate::result::{
+    AdaptiveOutcome, BenchFailure, BenchResult, RunInfo, SkippedBench, SuiteResult,
+};
+use crate::soak::SoakSummary;
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Mutex;
@@ -16,38 +24,117 @@ pub trait Reporter: Send + Sync {
     /// Called when a suite starts.
     fn suite_start(&self, _suite: &str, _config: &BenchRunnerConfig) {}
 
-    /// Called when a benchmark starts.
+    /// Called when a benchmark starts, with the settings it runs with.
     /// Note: Reporters should NOT print partial output here to ensure atomicity.
-    fn bench_start(&self, _name: &str) {}
+    fn bench_start(&self, _name: &str, _settings: &BenchSettings) {}
+
+    /// Called after each warmup and measured run of a benchmark.
+    fn run_end(&self, _name: &str, _run: &RunInfo) {}
 
     /// Called when a benchmark completes.
     fn bench_end(&self, _result: &BenchResult) {}
 
+    /// Called instead of `bench_end` when a benchmark panics or its
+    /// isolated child dies without a result.
+    fn bench_failed(&self, _failure: &BenchFailure) {}
+
+    /// Called for a selected benchmark that is not run, e.g. an ignored one.
+    fn bench_skipped(&self, _skipped: &SkippedBench) {}
+
     /// Called when a suite completes.
     fn suite_end(&self, _result: &SuiteResult) {}
 }
 
-/// Fixed width for benchmark name column in console output.
+/// Width of the benchmark name column when the line width is unknown.
 const NAME_WIDTH: usize = 40;
+/// Bounds of the name column when fitting lines into the line width.
+const MIN_NAME_WIDTH: usize = 20;
+const MAX_NAME_WIDTH: usize = 60;
 /// Fixed width for duration column in console output.
 const DURATION_WIDTH: usize = 14;
+/// Width of each extra column.
+const COLUMN_WIDTH: usize = 11;
+/// Room left for the throughput, e.g. `  (123.45 MiB/s)`.
+const THROUGHPUT_WIDTH: usize = 17;
+/// Width of the change against the baseline, e.g. `+123.4%`.
+const CHANGE_WIDTH: usize = 8;
+/// Width of the baseline marker, e.g. `▲ regressed`.
+const MARKER_WIDTH: usize = 11;
+
+/// How a median compares with the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Regressed,
+    Improved,
+    Unchanged,
+    /// Not in the baseline
+    New,
+}
+
+impl Change {
+    fn marker(self) -> &'static str {
+        match self {
+            Change::Regressed => "▲ regressed",
+            Change::Improved => "▼ improved",
+            Change::Unchanged => "= unchanged",
+            Change::New => "+ new",
+        }
+    }
+
+    fn style(self) -> Option<Style> {
+        match self {
+            Change::Regressed => Some(Style::Red),
+            Change::Improved => Some(Style::Green),
+            Change::Unchanged | Change::New => None,
+        }
+    }
+}
 
 /// Console reporter that prints results to stdout.
 ///
 /// Output is atomic: each benchmark is printed as a single complete line
 /// in `bench_end`, ensuring logs cannot interleave even if a benchmark panics.
+/// When sorting, lines are held back and printed in order at `suite_end`.
 pub struct ConsoleReporter {
     show_all_runs: bool,
+    options: ConsoleOptions,
+    /// Whether to emit ANSI colors, resolved from `options.color`
+    color: bool,
+    /// Line width, from `options.width` or the terminal
+    width: Option<usize>,
+    name_width: usize,
+    /// Shorten names longer than `name_width` (only when the width is known)
+    truncate: bool,
+    baseline: Option<SuiteResult>,
+    threshold: f64,
+    /// Events held back until `suite_end` when sorting.
+    pending: Mutex<Pending>,
     /// Mutex ensures atomic writes across threads.
     output_lock: Mutex<()>,
 }
 
+#[derive(Default)]
+struct Pending {
+    results: Vec<BenchResult>,
+    failures: Vec<BenchFailure>,
+    skipped: Vec<SkippedBench>,
+}
+
 impl ConsoleReporter {
     pub fn new() -> Self {
         Self {
             show_all_runs: false,
+            options: ConsoleOptions::default(),
+            color: false,
+            width: None,
+            name_width: NAME_WIDTH,
+            truncate: false,
+            baseline: None,
+            threshold: 0.05,
+            pending: Mutex::new(Pending::default()),
             output_lock: Mutex::new(()),
         }
+        .options(ConsoleOptions::default())
     }
 
     /// Show individual run times (not just median) on a separate indented line.
@@ -56,6 +143,47 @@ impl ConsoleReporter {
         self
     }
 
+    /// Set color, width, units, columns and sorting.
+    pub fn options(mut self, options: ConsoleOptions) -> Self {
+        self.color = options.color.enabled();
+        self.width = options.width.or_else(terminal_width);
+        self.options = options;
+        self.fit();
+        self
+    }
+
+    /// Load baseline from a file; each line shows the baseline median, the
+    /// change and whether it regressed, improved or is unchanged.
+    pub fn with_baseline(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.baseline = load_baseline(path.as_ref());
+        self.fit();
+        self
+    }
+
+    /// Size the name column so lines fit into the known width.
+    fn fit(&mut self) {
+        let Some(width) = self.width else {
+            self.name_width = NAME_WIDTH;
+            self.truncate = false;
+            return;
+        };
+        let mut fixed =
+            3 + DURATION_WIDTH + self.options.columns.len() * (1 + COLUMN_WIDTH) + THROUGHPUT_WIDTH;
+        if self.baseline.is_some() {
+            fixed += 1 + DURATION_WIDTH + 1 + CHANGE_WIDTH + 2 + MARKER_WIDTH;
+        }
+        self.name_width = width
+            .saturating_sub(fixed)
+            .clamp(MIN_NAME_WIDTH, MAX_NAME_WIDTH);
+        self.truncate = true;
+    }
+
+    /// Set the regression threshold (default: 0.05 = 5%).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
     /// Format a duration with consistent units: ns, µs, ms, or s.
     /// Always uses 2 decimal places, no scientific notation.
     fn format_duration(d: std::time::Duration) -> String {
@@ -64,18 +192,15 @@ impl ConsoleReporter {
 
     /// Format throughput string, only if bytes or elements are set.
     /// Returns empty string if neither is set.
-    fn format_throughput(result: &BenchResult) -> String {
+    pub(crate) fn format_throughput(result: &BenchResult) -> String {
+        Self::format_throughput_in(result, ByteUnits::Si)
+    }
+
+    /// Format throughput with bytes in the given units.
+    fn format_throughput_in(result: &BenchResult, units: ByteUnits) -> String {
         // Bytes take precedence over elements for throughput display
         if let Some(bps) = result.bytes_per_sec() {
-            if bps >= 1_000_000_000.0 {
-                format!("{:.2} GB/s", bps / 1_000_000_000.0)
-            } else if bps >= 1_000_000.0 {
-                format!("{:.2} MB/s", bps / 1_000_000.0)
-            } else if bps >= 1_000.0 {
-                format!("{:.2} KB/s", bps / 1_000.0)
-            } else {
-                format!("{:.2} B/s", bps)
-            }
+            units.format_rate(bps)
         } else if let Some(eps) = result.elements_per_sec() {
             if eps >= 1_000_000.0 {
                 format!("{:.2}M ops/s", eps / 1_000_000.0)
@@ -89,6 +214,248 @@ impl ConsoleReporter {
         }
     }
 
+    /// Format the adaptive sampling outcome: run count and achieved CI.
+    /// A target that was not met is shown next to the achieved value.
+    fn format_adaptive(adaptive: &AdaptiveOutcome) -> String {
+        match adaptive.relative_ci {
+            Some(ci) if adaptive.target_met => {
+                format!("  [{} runs, ±{:.1}%]", adaptive.runs, ci * 100.0)
+            }
+            Some(ci) => format!(
+                "  [{} runs, ±{:.1}% > ±{:.1}%]",
+                adaptive.runs,
+                ci * 100.0,
+                adaptive.target_ci * 100.0
+            ),
+            None => format!("  [{} runs, CI n/a]", adaptive.runs),
+        }
+    }
+
+    /// Benchmark name without the suite prefix; groups are kept.
+    /// Name padded (and, if the width is known, shortened) to the name column.
+    fn name_cell(&self, name: &str) -> String {
+        let name = short_name(name);
+        let name = if self.truncate {
+            truncate_middle(name, self.name_width)
+        } else {
+            name.to_string()
+        };
+        format!("{:<width$}", name, width = self.name_width)
+    }
+
+    /// Format soak runs and drift, e.g. `[soak: 5120 runs, duration +1.2%, RSS +0.4%]`.
+    fn format_soak(soak: &SoakSummary) -> String {
+        let mut parts = vec![format!("soak: {} runs", soak.iterations)];
+        if let Some(d) = soak.duration_drift {
+            parts.push(format!("duration {:+.1}%", d * 100.0));
+        }
+        if let Some(d) = soak.rss_drift {
+            parts.push(format!("RSS {:+.1}%", d * 100.0));
+        }
+        format!("  [{}]", parts.join(", "))
+    }
+
+    /// The baseline result and how the median changed, if a baseline is loaded.
+    fn compare(&self, result: &BenchResult) -> Option<(Option<&BenchResult>, Change)> {
+        let baseline = self.baseline.as_ref()?;
+        let Some(before) = baseline.results.iter().find(|b| b.name == result.name) else {
+            return Some((None, Change::New));
+        };
+        let ratio = result.compare(before);
+        let change = if ratio > 1.0 + self.threshold {
+            Change::Regressed
+        } else if ratio < 1.0 - self.threshold {
+            Change::Improved
+        } else {
+            Change::Unchanged
+        };
+        Some((Some(before), change))
+    }
+
+    fn format_result(&self, result: &BenchResult) -> String {
+        let unit = self.options.time_unit;
+        let mut line = format!("  {} ", self.name_cell(&result.name));
+
+        // Median, colored by its change against the baseline
+        let comparison = self.compare(result);
+        let style = comparison.and_then(|(_, change)| change.style());
+        let paint = |text: &str| match style {
+            Some(style) => style.paint(text, self.color),
+            None => text.to_string(),
+        };
+        line.push_str(&paint(&format!(
+            "{:>width$}",
+            unit.format(result.duration),
+            width = DURATION_WIDTH
+        )));
+
+        for column in &self.options.columns {
+            line.push_str(&format!(
+                " {:>width$}",
+                column.value(result, unit),
+                width = COLUMN_WIDTH
+            ));
+        }
+
+        let throughput = Self::format_throughput_in(result, self.options.byte_units);
+
+        // Baseline median, change and marker
+        if let Some((before, change)) = comparison {
+            let (baseline, percent) = match before {
+                Some(before) => (
+                    unit.format(before.duration),
+                    format!("{:+.1}%", (result.compare(before) - 1.0) * 100.0),
+                ),
+                None => ("-".to_string(), String::new()),
+            };
+            line.push_str(&format!(" {:>width$} ", baseline, width = DURATION_WIDTH));
+            line.push_str(&paint(&format!(
+                "{:>width$}",
+                percent,
+                width = CHANGE_WIDTH
+            )));
+            // Padded only when the throughput follows, to keep it aligned
+            let marker = if throughput.is_empty() {
+                change.marker().to_string()
+            } else {
+                format!("{:<width$}", change.marker(), width = MARKER_WIDTH)
+            };
+            line.push_str(&format!("  {}", paint(&marker)));
+        }
+
+        // Throughput only if bytes or elements are set
+        if !throughput.is_empty() {
+            line.push_str(&format!("  ({})", throughput));
+        }
+
+        if let Some(adaptive) = &result.adaptive {
+            let text = Self::format_adaptive(adaptive);
+            if adaptive.target_met {
+                line.push_str(&text);
+            } else {
+                line.push_str(&Style::Yellow.paint(&text, self.color));
+            }
+        }
+
+        if result.timed_out {
+            line.push_str(&Style::Red.paint("  [timed out]", self.color));
+        }
+
+        match result.faults.len() {
+            0 => {}
+            1 => line.push_str("  [1 fault injected]"),
+            n => line.push_str(&format!("  [{} faults injected]", n)),
+        }
+
+        if let Some(soak) = &result.soak {
+            line.push_str(&Self::format_soak(soak));
+            if soak.drift_exceeded {
+                line.push_str(&Style::Red.paint("  [drift exceeded]", self.color));
+            }
+        }
+
+        // Optionally append individual runs on a separate indented line
+        if self.show_all_runs && result.all_runs.len() > 1 {
+            let runs_formatted: Vec<_> = result.all_runs.iter().map(|d| unit.format(*d)).collect();
+            line.push_str(&format!("\n      runs: [{}]", runs_formatted.join(", ")));
+        }
+
+        line
+    }
+
+    fn format_failure(&self, failure: &BenchFailure) -> String {
+        format!(
+            "  {} {}  {}",
+            self.name_cell(&failure.name),
+            Style::Red.paint(
+                &format!("{:>width$}", "FAILED", width = DURATION_WIDTH),
+                self.color
+            ),
+            failure.message
+        )
+    }
+
+    fn format_skipped(&self, skipped: &SkippedBench) -> String {
+        let line = format!(
+            "  {} {:>dur_width$}  ({})",
+            self.name_cell(&skipped.name),
+            "skipped",
+            skipped.reason,
+            dur_width = DURATION_WIDTH
+        );
+        Style::Dim.paint(&line, self.color)
+    }
+
+    /// Counts of regressed, improved, unchanged and new benchmarks, e.g.
+    /// `Baseline 36d2a43 (±5.0%): 1 regressed, 0 improved, 4 unchanged`.
+    /// Empty without a baseline.
+    fn format_baseline_summary(&self, result: &SuiteResult) -> String {
+        let Some(baseline) = &self.baseline else {
+            return String::new();
+        };
+        let changes: Vec<_> = result
+            .results
+            .iter()
+            .filter_map(|r| self.compare(r).map(|(_, change)| change))
+            .collect();
+        let count = |change| changes.iter().filter(|c| **c == change).count();
+        let part = |change: Change, word: &str| {
+            let text = format!("{} {}", count(change), word);
+            match change.style() {
+                Some(style) if count(change) > 0 => style.paint(&text, self.color),
+                _ => text,
+            }
+        };
+        let mut parts = vec![
+            part(Change::Regressed, "regressed"),
+            part(Change::Improved, "improved"),
+            part(Change::Unchanged, "unchanged"),
+        ];
+        if count(Change::New) > 0 {
+            parts.push(part(Change::New, "new"));
+        }
+        let sha = baseline
+            .git_sha
+            .as_deref()
+            .map(|sha| format!(" {}", sha.chars().take(7).collect::<String>()))
+            .unwrap_or_default();
+        format!(
+            "Baseline{} (±{:.1}%): {}\n",
+            sha,
+            self.threshold * 100.0,
+            parts.join(", ")
+        )
+    }
+
+    fn sorting(&self) -> bool {
+        self.options.sort != ConsoleSort::None
+    }
+
+    /// Lines held back while sorting, in order: results, then failures,
+    /// then skipped benchmarks.
+    fn sorted_lines(&self) -> Vec<String> {
+        let mut pending =
+            std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()));
+        match self.options.sort {
+            ConsoleSort::Name => pending
+                .results
+                .sort_by(|a, b| short_name(&a.name).cmp(short_name(&b.name))),
+            // Slowest first
+            ConsoleSort::Duration => pending
+                .results
+                .sort_by_key(|r| std::cmp::Reverse(r.duration)),
+            ConsoleSort::None => {}
+        }
+        let mut lines: Vec<_> = pending
+            .results
+            .iter()
+            .map(|r| self.format_result(r))
+            .collect();
+        lines.extend(pending.failures.iter().map(|f| self.format_failure(f)));
+        lines.extend(pending.skipped.iter().map(|s| self.format_skipped(s)));
+        lines
+    }
+
     /// Atomically write a complete message to stdout.
     /// Never panics; logs warning on error.
     fn write_stdout(&self, message: &str) {
@@ -114,76 +481,109 @@ impl Default for ConsoleReporter {
 impl Reporter for ConsoleReporter {
     fn suite_start(&self, suite: &str, config: &BenchRunnerConfig) {
         // Build complete header atomically
-        let header = format!(
+        let seed = config
+            .seed
+            .map(|seed| format!(", Seed: {}", seed))
+            .unwrap_or_default();
+        let pin = match config.pin.as_slice() {
+            [] => String::new(),
+            cpus => {
+                let cpus: Vec<_> = cpus.iter().map(|c| c.to_string()).collect();
+                format!(", Pinned: {}", cpus.join(","))
+            }
+        };
+        let mut header = format!(
             "---------------------------------------------------------------\n\
              Benchmark Suite: {}\n\
-             Runs: {}, Warmup: {}\n\
+             Runs: {}, Warmup: {}{}{}\n\
              ---------------------------------------------------------------\n",
-            suite, config.runs, config.warmup_runs
+            suite, config.runs, config.warmup_runs, seed, pin
         );
+        // Extra columns need headings to be told apart
+        if !self.options.columns.is_empty() || self.baseline.is_some() {
+            header.push_str(&format!(
+                "  {:<name_width$} {:>dur_width$}",
+                "Benchmark",
+                "Median",
+                name_width = self.name_width,
+                dur_width = DURATION_WIDTH
+            ));
+            for column in &self.options.columns {
+                header.push_str(&format!(
+                    " {:>width$}",
+                    column.title(),
+                    width = COLUMN_WIDTH
+                ));
+            }
+            if self.baseline.is_some() {
+                header.push_str(&format!(
+                    " {:>dur_width$} {:>change_width$}",
+                    "Baseline",
+                    "Change",
+                    dur_width = DURATION_WIDTH,
+                    change_width = CHANGE_WIDTH
+                ));
+            }
+            header.push('\n');
+        }
         self.write_stdout(&header);
     }
 
-    fn bench_start(&self, _name: &str) {
+    fn bench_start(&self, _name: &str, _settings: &BenchSettings) {
         // Intentionally empty: we print the complete line in bench_end
         // to ensure atomic output that cannot interleave.
     }
 
     fn bench_end(&self, result: &BenchResult) {
-        // Extract just the benchmark name (remove suite prefix)
-        let name_parts: Vec<&str> = result.name.split('/').collect();
-        let bench_name = if name_parts.len() >= 2 {
-            name_parts[name_parts.len() - 1]
-        } else {
-            &result.name
-        };
-
-        // Format duration (median from BenchResult)
-        let duration_str = Self::format_duration(result.duration);
-
-        // Format throughput only if bytes or elements are set
-        let throughput_str = Self::format_throughput(result);
-
-        // Build the main result line with fixed column alignment
-        let mut line = if throughput_str.is_empty() {
-            format!(
-                "  {:<width$} {:>dur_width$}",
-                bench_name,
-                duration_str,
-                width = NAME_WIDTH,
-                dur_width = DURATION_WIDTH
-            )
-        } else {
-            format!(
-                "  {:<width$} {:>dur_width$}  ({})",
-                bench_name,
-                duration_str,
-                throughput_str,
-                width = NAME_WIDTH,
-                dur_width = DURATION_WIDTH
-            )
-        };
+        if self.sorting() {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.results.push(result.clone());
+            return;
+        }
+        self.write_stdout(&self.format_result(result));
+    }
 
-        // Optionally append individual runs on a separate indented line
-        if self.show_all_runs && result.all_runs.len() > 1 {
-            let runs_formatted: Vec<_> = result
-                .all_runs
-                .iter()
-                .map(|d| Self::format_duration(*d))
-                .collect();
-            line.push_str(&format!("\n      runs: [{}]", runs_formatted.join(", ")));
+    fn bench_failed(&self, failure: &BenchFailure) {
+        if self.sorting() {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.failures.push(failure.clone());
+            return;
         }
+        self.write_stdout(&self.format_failure(failure));
+    }
 
-        self.write_stdout(&line);
+    fn bench_skipped(&self, skipped: &SkippedBench) {
+        if self.sorting() {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.skipped.push(skipped.clone());
+            return;
+        }
+        self.write_stdout(&self.format_skipped(skipped));
     }
 
     fn suite_end(&self, result: &SuiteResult) {
+        if self.sorting() {
+            let lines = self.sorted_lines();
+            if !lines.is_empty() {
+                self.write_stdout(&lines.join("\n"));
+            }
+        }
+
+        let mut counts = String::new();
+        if !result.failures.is_empty() {
+            counts.push_str(&format!(", {} failed", result.failures.len()));
+        }
+        if !result.skipped.is_empty() {
+            counts.push_str(&format!(", {} skipped", result.skipped.len()));
+        }
         let footer = format!(
             "---------------------------------------------------------------\n\
-             Completed {} benchmarks in {}\n\
+             Completed {} benchmarks in {}{}\n{}\
              ---------------------------------------------------------------\n",
             result.results.len(),
-            Self::format_duration(result.total_duration)
+            Self::format_duration(result.total_duration),
+            counts,
+            self.format_baseline_summary(result)
         );
         self.write_stdout(&footer);
     }
@@ -191,21 +591,33 @@ impl Reporter for ConsoleReporter {
 
 /// JSON reporter that writes results to a file.
 ///
-/// Writes timestamped results files organized by suite:
-/// - `{suite}/{timestamp}.json` - Machine-readable results
-/// - `{suite}/{timestamp}.txt` - Human-readable summary
+/// Writes results files organized by suite:
+/// - `{suite}/{name}.json` - Machine-readable results (`.json.gz` if compressed)
+/// - `{suite}/{name}.txt` - Human-readable summary
 /// - `{suite}/latest.json` and `latest.txt` - Most recent results
+///
+/// `{name}` comes from the [`OutputLayout`] file name template (the start
+/// time in unix milliseconds by default). `latest.*` is replaced atomically,
+/// and runs the layout's retention does not keep are removed.
 pub struct JsonReporter {
     output_dir: PathBuf,
+    layout: OutputLayout,
 }
 
 impl JsonReporter {
     pub fn new(output_dir: impl Into<PathBuf>) -> Self {
         Self {
             output_dir: output_dir.into(),
+            layout: OutputLayout::default(),
         }
     }
 
+    /// Set the file names, compression, retention and `latest` files.
+    pub fn layout(mut self, layout: OutputLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
     /// Write JSON results to the output directory.
     /// Creates both timestamped JSON and text summary files organized by suite name.
     /// Never panics; logs warnings to stderr on failure.
@@ -216,42 +628,87 @@ impl JsonReporter {
     }
 
     fn write_results_inner(&self, result: &SuiteResult) -> std::io::Result<()> {
-        // Sanitize suite name for directory (replace path separators)
-        let sanitized_name = result.suite.replace(['/', '\\'], "_");
-
         // Create suite-specific subdirectory
-        let suite_dir = self.output_dir.join(&sanitized_name);
+        let suite_dir = self.output_dir.join(suite_path_name(&result.suite));
         std::fs::create_dir_all(&suite_dir)?;
 
-        // Create timestamped filename to make each run unique
-        let timestamp_str = &result.started_at;
-        let json_filename = format!("{}.json", timestamp_str);
-        let txt_filename = format!("{}.txt", timestamp_str);
+        // File name from the layout's template, unique per run by default
+        let stem = self.layout.render(result);
+        let compress = self.layout.compress && cfg!(feature = "gzip");
+        if self.layout.compress && !compress {
+            eprintln!(
+                "Warning: writing uncompressed results; compression needs the `gzip` feature"
+            );
+        }
+        let json_filename = if compress {
+            format!("{}.json.gz", stem)
+        } else {
+            format!("{}.json", stem)
+        };
+        let txt_filename = format!("{}.txt", stem);
 
-        let timestamped_json_path = suite_dir.join(&json_filename);
-        let timestamped_txt_path = suite_dir.join(&txt_filename);
+        let json_path = suite_dir.join(&json_filename);
+        let txt_path = suite_dir.join(&txt_filename);
 
         // Serialize to JSON
         let json = serde_json::to_string_pretty(result).map_err(std::io::Error::other)?;
 
-        // Write timestamped JSON file
-        std::fs::write(&timestamped_json_path, &json)?;
-        eprintln!("  Results written to: {}", timestamped_json_path.display());
+        // Write the run's JSON file
+        std::fs::write(&json_path, Self::encode(&json, compress)?)?;
+        eprintln!("  Results written to: {}", json_path.display());
 
         // Generate and write text summary
         let summary = self.format_summary(result);
-        std::fs::write(&timestamped_txt_path, &summary)?;
+        std::fs::write(&txt_path, &summary)?;
 
-        // Write latest files for convenient access to most recent results
+        // Latest files for convenient access to the most recent results;
+        // `latest.json` is plain JSON, so a compressed run is copied
         let latest_json_path = suite_dir.join("latest.json");
         let latest_txt_path = suite_dir.join("latest.txt");
-        std::fs::write(&latest_json_path, &json)?;
-        std::fs::write(&latest_txt_path, &summary)?;
+        let linked = self.layout.latest == LatestMode::Symlink
+            && !compress
+            && match symlink_atomic(&json_filename, &latest_json_path)
+                .and_then(|()| symlink_atomic(&txt_filename, &latest_txt_path))
+            {
+                Ok(()) => true,
+                Err(e) => {
+                    eprintln!("Warning: copying latest results instead of linking: {}", e);
+                    false
+                }
+            };
+        if !linked {
+            write_atomic(&latest_json_path, json.as_bytes())?;
+            write_atomic(&latest_txt_path, summary.as_bytes())?;
+        }
         eprintln!("  Latest results at: {}", latest_json_path.display());
 
+        let files = [json_filename, txt_filename];
+        let removed = record_and_prune(&suite_dir, "json", &files, self.layout.retention)?;
+        if removed > 0 {
+            eprintln!(
+                "  Removed {} old result(s) from: {}",
+                removed,
+                suite_dir.display()
+            );
+        }
+
         Ok(())
     }
 
+    #[cfg(feature = "gzip")]
+    fn encode(json: &str, compress: bool) -> std::io::Result<Vec<u8>> {
+        if compress {
+            crate::layout::gzip(json.as_bytes())
+        } else {
+            Ok(json.as_bytes().to_vec())
+        }
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    fn encode(json: &str, _compress: bool) -> std::io::Result<Vec<u8>> {
+        Ok(json.as_bytes().to_vec())
+    }
+
     fn format_summary(&self, result: &SuiteResult) -> String {
         let mut output = String::new();
         output.push_str("===============================================================\n");
@@ -327,7 +784,72 @@ impl JsonReporter {
     }
 }
 
-fn format_duration(nanos: std::time::Duration) -> String {
+/// Load a baseline for comparison.
+/// Never fails; logs a warning to stderr since the baseline is optional.
+pub(crate) fn load_baseline(path: &std::path::Path) -> Option<SuiteResult> {
+    match SuiteResult::load(path) {
+        Ok(baseline) => Some(baseline),
+        Err(e) => {
+            eprintln!(
+                "Warning: failed to load baseline from '{}': {}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Write `contents` to a temporary file next to `path` and rename it into
+/// place, so readers see either the old file or the new one.
+pub(crate) fn write_atomic(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    // Hidden and with another extension, so readers that glob for the real
+    // files (such as the textfile collector's `*.prom`) skip it
+    let tmp = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+    let result = (|| {
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Benchmark name without its `suite/` prefix.
+pub(crate) fn short_name(name: &str) -> &str {
+    name.split_once('/').map_or(name, |(_, bench)| bench)
+}
+
+/// Suite name with path separators replaced, for file and directory names.
+pub(crate) fn suite_path_name(suite: &str) -> String {
+    suite.replace(['/', '\\'], "_")
+}
+
+/// Escape text for XML or HTML content and attributes, dropping characters
+/// XML 1.0 forbids.
+pub(crate) fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            '\t' | '\r' => out.push_str(&format!("&#{};", c as u32)),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+pub(crate) fn format_duration(nanos: std::time::Duration) -> String {
     let secs = nanos.as_secs_f64();
     if secs >= 1.0 {
         format!("{:.2}s", secs)
@@ -346,18 +868,24 @@ impl Reporter for JsonReporter {
     }
 }
 
-/// GitHub Actions reporter that emits annotations.
+/// GitHub Actions reporter that emits workflow commands.
+///
+/// Prints an `::error` annotation for each failed benchmark and for each
+/// regression against the baseline (`::warning` unless regressions fail the
+/// build), a collapsible group of results, and appends a Markdown comparison
+/// table to the job summary (`$GITHUB_STEP_SUMMARY`).
 ///
-/// Only produces output when running in GitHub Actions environment.
-/// Emits warnings for performance regressions that exceed the threshold.
+/// [`BenchRunner`](crate::BenchRunner) installs it when `GITHUB_ACTIONS` is
+/// set or with `--reporter github`, using the run's baseline and threshold.
 /// Output goes to stdout (as required by GitHub Actions annotation format).
-#[allow(dead_code)]
 pub struct GitHubActionsReporter {
     threshold: f64,
     baseline: Option<SuiteResult>,
+    fail_on_regression: bool,
+    summary_path: Option<PathBuf>,
+    output_lock: Mutex<()>,
 }
 
-#[allow(dead_code)]
 impl GitHubActionsReporter {
     /// Create a new GitHub Actions reporter.
     ///
@@ -366,69 +894,127 @@ impl GitHubActionsReporter {
         Self {
             threshold,
             baseline: None,
+            fail_on_regression: false,
+            summary_path: std::env::var_os("GITHUB_STEP_SUMMARY").map(PathBuf::from),
+            output_lock: Mutex::new(()),
         }
     }
 
     /// Load baseline from a file for comparison.
     pub fn with_baseline(mut self, path: impl AsRef<std::path::Path>) -> Self {
-        match SuiteResult::load(path.as_ref()) {
-            Ok(baseline) => self.baseline = Some(baseline),
-            Err(e) => {
-                // Log to stderr, don't fail - baseline is optional
-                eprintln!(
-                    "Warning: failed to load baseline from '{}': {}",
-                    path.as_ref().display(),
-                    e
-                );
-            }
-        }
+        self.baseline = load_baseline(path.as_ref());
         self
     }
 
-    /// Check if we're running in GitHub Actions environment.
-    fn is_github_actions() -> bool {
-        std::env::var("GITHUB_ACTIONS").is_ok()
+    /// Annotate regressions as errors rather than warnings, for runs where
+    /// a regression fails the build.
+    pub fn fail_on_regression(mut self, fail: bool) -> Self {
+        self.fail_on_regression = fail;
+        self
     }
 
-    /// Format duration consistently for GitHub Actions output.
-    fn format_duration(d: std::time::Duration) -> String {
-        let secs = d.as_secs_f64();
-        if secs >= 1.0 {
-            format!("{:.2}s", secs)
-        } else {
-            format!("{:.2}ms", secs * 1000.0)
+    /// Write the job summary to `path` instead of `$GITHUB_STEP_SUMMARY`.
+    pub fn step_summary(mut self, path: impl Into<PathBuf>) -> Self {
+        self.summary_path = Some(path.into());
+        self
+    }
+
+    /// Whether we're running in GitHub Actions.
+    pub(crate) fn is_github_actions() -> bool {
+        std::env::var("GITHUB_ACTIONS").is_ok_and(|v| v == "true")
+    }
+
+    /// Escape the message of a workflow command.
+    fn escape_data(s: &str) -> String {
+        s.replace('%', "%25")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    /// Escape a workflow command property such as `title`.
+    fn escape_property(s: &str) -> String {
+        Self::escape_data(s).replace(':', "%3A").replace(',', "%2C")
+    }
+
+    /// Print complete workflow command lines without interleaving.
+    fn write_stdout(&self, lines: &str) {
+        let _guard = self.output_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{}", lines);
+        let _ = stdout.flush();
+    }
+
+    /// Markdown job summary; see [`comparison_markdown`].
+    fn format_summary(&self, result: &SuiteResult) -> String {
+        comparison_markdown(result, self.baseline.as_ref(), self.threshold)
+    }
+
+    /// Append the job summary, if there is a summary file.
+    fn write_summary(&self, result: &SuiteResult) {
+        let Some(path) = &self.summary_path else {
+            return;
+        };
+        let written = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", self.format_summary(result)));
+        if let Err(e) = written {
+            eprintln!(
+                "Warning: failed to write job summary to '{}': {}",
+                path.display(),
+                e
+            );
         }
     }
 }
 
 impl Reporter for GitHubActionsReporter {
+    fn bench_failed(&self, failure: &BenchFailure) {
+        self.write_stdout(&format!(
+            "::error title={}::{}",
+            Self::escape_property(&format!("Benchmark failed: {}", failure.name)),
+            Self::escape_data(&failure.message)
+        ));
+    }
+
     fn suite_end(&self, result: &SuiteResult) {
-        // Only emit when running in GitHub Actions
-        if !Self::is_github_actions() {
-            return;
-        }
+        let mut lines = Vec::new();
 
-        // Emit regression warnings if baseline is available
+        // Annotate regressions if baseline is available
         if let Some(baseline) = &self.baseline {
-            let regressions = result.find_regressions(baseline, self.threshold);
-            for (r, ratio) in regressions {
+            let level = if self.fail_on_regression {
+                "error"
+            } else {
+                "warning"
+            };
+            for (r, ratio) in result.find_regressions(baseline, self.threshold) {
                 let pct = (ratio - 1.0) * 100.0;
-                // Include suite name and benchmark name in annotation
-                // Format: ::warning title=<title>::<message>
-                println!(
-                    "::warning title=Performance Regression in {}::Benchmark '{}' is {:.1}% slower than baseline",
-                    result.suite, r.name, pct
-                );
+                // Format: ::<level> title=<title>::<message>
+                lines.push(format!(
+                    "::{} title={}::{}",
+                    level,
+                    Self::escape_property(&format!("Performance Regression in {}", result.suite)),
+                    Self::escape_data(&format!(
+                        "Benchmark '{}' is {:.1}% slower than baseline",
+                        r.name, pct
+                    ))
+                ));
             }
         }
 
         // Output summary in a collapsible group
-        println!("::group::Benchmark Results - {}", result.suite);
+        lines.push(format!("::group::Benchmark Results - {}", result.suite));
         for r in &result.results {
-            let duration = Self::format_duration(r.duration);
-            println!("  {}: {}", r.name, duration);
+            lines.push(format!("  {}: {}", r.name, format_duration(r.duration)));
+        }
+        for f in &result.failures {
+            lines.push(format!("  {}: FAILED", f.name));
         }
-        println!("::endgroup::");
+        lines.push("::endgroup::".to_string());
+        self.write_stdout(&lines.join("\n"));
+
+        self.write_summary(result);
     }
 }
 
@@ -456,10 +1042,18 @@ impl Reporter for MultiReporter {
         }
     }
 
-    fn bench_start(&self, name: &str) {
+    fn bench_start(&self, name: &str, settings: &BenchSettings) {
         for r in &self.reporters {
             let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                r.bench_start(name);
+                r.bench_start(name, settings);
+            }));
+        }
+    }
+
+    fn run_end(&self, name: &str, run: &RunInfo) {
+        for r in &self.reporters {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                r.run_end(name, run);
             }));
         }
     }
@@ -472,6 +1066,22 @@ impl Reporter for MultiReporter {
         }
     }
 
+    fn bench_failed(&self, failure: &BenchFailure) {
+        for r in &self.reporters {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                r.bench_failed(failure);
+            }));
+        }
+    }
+
+    fn bench_skipped(&self, skipped: &SkippedBench) {
+        for r in &self.reporters {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                r.bench_skipped(skipped);
+            }));
+        }
+    }
+
     fn suite_end(&self, result: &SuiteResult) {
         for r in &self.reporters {
             let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -484,7 +1094,7 @@ impl Reporter for MultiReporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
+    use crate::console::{ColorMode, ConsoleColumn, TimeUnit};
     use std::time::Duration;
 
     #[test]
@@ -523,7 +1133,7 @@ mod tests {
             bytes: Some(1_000_000_000),
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let throughput = ConsoleReporter::format_throughput(&result);
         assert!(throughput.contains("GB/s"));
@@ -537,7 +1147,7 @@ mod tests {
             bytes: None,
             elements: Some(1_000_000),
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let throughput = ConsoleReporter::format_throughput(&result);
         assert!(throughput.contains("ops/s"));
@@ -551,12 +1161,33 @@ mod tests {
             bytes: None,
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let throughput = ConsoleReporter::format_throughput(&result);
         assert!(throughput.is_empty());
     }
 
+    #[test]
+    fn should_format_adaptive_outcome() {
+        let met = AdaptiveOutcome {
+            runs: 12,
+            target_ci: 0.02,
+            relative_ci: Some(0.015),
+            target_met: true,
+        };
+        assert_eq!(ConsoleReporter::format_adaptive(&met), "  [12 runs, ±1.5%]");
+
+        let missed = AdaptiveOutcome {
+            relative_ci: Some(0.031),
+            target_met: false,
+            ..met
+        };
+        assert_eq!(
+            ConsoleReporter::format_adaptive(&missed),
+            "  [12 runs, ±3.1% > ±2.0%]"
+        );
+    }
+
     #[test]
     fn should_prefer_bytes_over_elements_for_throughput() {
         let result = BenchResult {
@@ -565,11 +1196,199 @@ mod tests {
             bytes: Some(1_000_000),
             elements: Some(500),
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let throughput = ConsoleReporter::format_throughput(&result);
         // Should show bytes throughput, not elements
         assert!(throughput.contains("MB/s") || throughput.contains("KB/s"));
         assert!(!throughput.contains("ops/s"));
     }
+
+    fn console(options: ConsoleOptions) -> ConsoleReporter {
+        ConsoleReporter::new().options(options.color(ColorMode::Never))
+    }
+
+    #[test]
+    fn should_fit_names_into_width_and_keep_groups() {
+        let reporter = console(ConsoleOptions::new().width(60));
+        let result = BenchResult::fixture("io/compaction/level0_to_level1_under_heavy_load", 5);
+        // 60 - 3 - 14 - 17 leaves 26 characters for the name
+        assert_eq!(
+            reporter.format_result(&result),
+            "  compaction/le…r_heavy_load         5.00ms"
+        );
+    }
+
+    #[test]
+    fn should_show_extra_columns_in_fixed_units() {
+        let reporter = console(
+            ConsoleOptions::new()
+                .width(120)
+                .time_unit(TimeUnit::Us)
+                .byte_units(ByteUnits::Iec)
+                .columns(vec![ConsoleColumn::Min, ConsoleColumn::Runs]),
+        );
+        let result = BenchResult::fixture("io/scan", 2)
+            .measured(&[1, 2])
+            .bytes(2 * 1024 * 1024);
+        let line = reporter.format_result(&result);
+        assert!(line.ends_with("2000.00us   1000.00us           2  (1000.00 MiB/s)"));
+    }
+
+    #[test]
+    fn should_color_changes_against_baseline() {
+        let mut reporter = ConsoleReporter::new()
+            .options(ConsoleOptions::new().color(ColorMode::Always).width(80));
+        reporter.baseline = Some(SuiteResult::fixture(
+            "io",
+            &[("write", 100), ("read", 100), ("scan", 100)],
+        ));
+        let line = |ms| reporter.format_result(&BenchResult::fixture("io/write", ms));
+        assert!(line(120).contains("\x1b[31m      120.00ms\x1b[0m"));
+        assert!(line(80).contains("\x1b[32m       80.00ms\x1b[0m"));
+        assert!(!line(102).contains('\x1b'));
+    }
+
+    #[test]
+    fn should_show_baseline_change_and_marker_when_baseline_loaded() {
+        let mut reporter = console(ConsoleOptions::new().width(80));
+        let mut baseline =
+            SuiteResult::fixture("io", &[("write", 100), ("read", 100), ("scan", 100)]);
+        baseline.git_sha = Some("36d2a432aa".to_string());
+        reporter.baseline = Some(baseline);
+        reporter.fit();
+
+        let current = SuiteResult::fixture(
+            "io",
+            &[("write", 120), ("read", 80), ("scan", 101), ("new", 5)],
+        );
+        let lines: Vec<_> = current
+            .results
+            .iter()
+            .map(|r| reporter.format_result(r))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "  write                      120.00ms       100.00ms   +20.0%  ▲ regressed",
+                "  read                        80.00ms       100.00ms   -20.0%  ▼ improved",
+                "  scan                       101.00ms       100.00ms    +1.0%  = unchanged",
+                "  new                          5.00ms              -           + new",
+            ]
+        );
+        assert_eq!(
+            reporter.format_baseline_summary(&current),
+            "Baseline 36d2a43 (±5.0%): 1 regressed, 1 improved, 1 unchanged, 1 new\n"
+        );
+        assert_eq!(
+            console(ConsoleOptions::new()).format_baseline_summary(&current),
+            ""
+        );
+    }
+
+    #[test]
+    fn should_hold_back_lines_and_sort_slowest_first() {
+        let reporter = console(ConsoleOptions::new().width(80).sort(ConsoleSort::Duration));
+        for (name, ms) in [("io/a", 1), ("io/b", 30), ("io/c", 2)] {
+            reporter.bench_end(&BenchResult::fixture(name, ms));
+        }
+        reporter.bench_failed(&BenchFailure {
+            name: "io/d".to_string(),
+            message: "panicked: boom".to_string(),
+        });
+        let names: Vec<_> = reporter
+            .sorted_lines()
+            .iter()
+            .map(|l| l.split_whitespace().next().unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["b", "c", "a", "d"]);
+        assert!(reporter.sorted_lines().is_empty());
+    }
+
+    #[test]
+    fn should_compare_against_baseline_in_job_summary() {
+        let mut reporter = GitHubActionsReporter::new(0.05);
+        reporter.baseline = Some(SuiteResult::fixture("io", &[("write", 100), ("read", 100)]));
+        let mut current = SuiteResult::fixture("io", &[("write", 120), ("read", 80), ("scan", 5)]);
+        current.failures.push(BenchFailure {
+            name: "io/compact".to_string(),
+            message: "panicked: boom".to_string(),
+        });
+
+        let summary = reporter.format_summary(&current);
+        assert!(summary.starts_with("### Benchmarks: io\n"));
+        assert!(summary.contains("| 🔴 | `write` | 100.00ms | 120.00ms | +20.0% |  |"));
+        assert!(summary.contains("| 🟢 | `read` | 100.00ms | 80.00ms | -20.0% |  |"));
+        assert!(summary.contains("| 🆕 | `scan` | - | 5.00ms |  |  |"));
+        assert!(summary.contains("- `compact`: panicked: boom"));
+        assert!(summary.contains("threshold ±5.0%"));
+    }
+
+    #[test]
+    fn should_append_job_summary_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("summary.md");
+        std::fs::write(&path, "earlier step\n").unwrap();
+        let reporter = GitHubActionsReporter::new(0.05).step_summary(&path);
+        reporter.write_summary(&SuiteResult::fixture("io", &[("write", 2)]));
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("earlier step\n### Benchmarks: io"));
+        assert!(written.contains("| `write` | 2.00ms |  |"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_link_latest_and_keep_newest_runs_when_layout_set() {
+        let dir = tempfile::tempdir().unwrap();
+        let reporter = JsonReporter::new(dir.path()).layout(
+            OutputLayout::new()
+                .file_name("{branch}-{timestamp}")
+                .retention(crate::Retention::Runs(2))
+                .latest(LatestMode::Symlink),
+        );
+        for started_at in ["1000", "2000", "3000"] {
+            let mut suite = SuiteResult::fixture("io", &[("write", 2)]);
+            suite.started_at = started_at.to_string();
+            suite.git_branch = Some("feature/x".to_string());
+            reporter.write_results_inner(&suite).unwrap();
+        }
+
+        let suite_dir = dir.path().join("io");
+        let mut files: Vec<_> = std::fs::read_dir(&suite_dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                ".index",
+                "feature_x-2000.json",
+                "feature_x-2000.txt",
+                "feature_x-3000.json",
+                "feature_x-3000.txt",
+                "latest.json",
+                "latest.txt",
+            ]
+        );
+        assert_eq!(
+            std::fs::read_link(suite_dir.join("latest.json")).unwrap(),
+            PathBuf::from("feature_x-3000.json")
+        );
+        let latest = SuiteResult::load(suite_dir.join("latest.json")).unwrap();
+        assert_eq!(latest.started_at, "3000");
+    }
+
+    #[test]
+    fn should_escape_workflow_command_values() {
+        assert_eq!(
+            GitHubActionsReporter::escape_data("100% done\nnext"),
+            "100%25 done%0Anext"
+        );
+        assert_eq!(
+            GitHubActionsReporter::escape_property("a::b, c"),
+            "a%3A%3Ab%2C c"
+        );
+    }
 }
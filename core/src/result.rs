@@ -1,11 +1,16 @@
 //! Benchmark result types.
 
+use crate::config::BenchOrder;
+use crate::environment::Environment;
+use crate::limits::AppliedLimits;
+use crate::sched::Scheduling;
+use crate::soak::SoakSummary;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::time::Duration;
 
 /// Result of a single benchmark measurement.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BenchResult {
     /// Full name including suite: "suite/benchmark"
     pub name: String,
@@ -24,6 +29,145 @@ pub struct BenchResult {
     /// Custom tags
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub tags: HashMap<String, String>,
+    /// Tags declared on the benchmark (`#[stress_test(tags(...))]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub declared_tags: Vec<String>,
+    /// Group declared on the benchmark
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Effective number of measurement runs for this benchmark
+    #[serde(default)]
+    pub runs: usize,
+    /// Effective number of warmup runs for this benchmark
+    #[serde(default)]
+    pub warmup_runs: usize,
+    /// Effective per-run timeout for this benchmark
+    #[serde(
+        default,
+        with = "option_duration_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout: Option<Duration>,
+    /// Whether a measured run exceeded `timeout` (remaining runs are skipped)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// Outcome of adaptive sampling (only set in adaptive mode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptiveOutcome>,
+    /// Seed of this benchmark's `ctx.rng()`, derived from the suite seed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Faults injected during measured runs, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub faults: Vec<InjectedFault>,
+    /// Resource limits in effect while the benchmark ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<AppliedLimits>,
+    /// Metrics reported with `ctx.metric()` in the last measured run
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, f64>,
+    /// Soak outcome (only set in soak mode, where `all_runs` holds at most
+    /// 1024 evenly spaced bucket averages)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soak: Option<SoakSummary>,
+}
+
+/// How an adaptive benchmark converged.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveOutcome {
+    /// Measurement runs that were needed
+    pub runs: usize,
+    /// Target relative half-width of the median's 95% confidence interval
+    pub target_ci: f64,
+    /// Achieved relative half-width (None if too few runs to compute it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative_ci: Option<f64>,
+    /// Whether `relative_ci` reached `target_ci`
+    pub target_met: bool,
+}
+
+/// A fault that was injected while a benchmark was measured.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InjectedFault {
+    pub kind: FaultKind,
+    /// Failpoint that triggered the fault, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failpoint: Option<String>,
+    /// Offset from the start of the measured region
+    #[serde(with = "duration_serde")]
+    pub at: Duration,
+    /// Index of the measured run the fault hit
+    pub run: usize,
+    /// Error message, delay length or restart details
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Kind of an [`InjectedFault`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultKind {
+    Delay,
+    Error,
+    DiskFull,
+    Kill,
+}
+
+/// A finished run, passed to [`Reporter::run_end`](crate::Reporter::run_end).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunInfo {
+    /// Run index, counted separately for warmup and measured runs
+    pub run: usize,
+    /// Whether this was a warmup run (not part of the result)
+    pub warmup: bool,
+    /// Measured duration of the run
+    #[serde(with = "duration_serde")]
+    pub duration: Duration,
+}
+
+/// A benchmark that panicked or whose isolated child produced no result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchFailure {
+    /// Full name including suite: "suite/benchmark"
+    pub name: String,
+    /// Panic message or reason
+    pub message: String,
+}
+
+/// A benchmark that was selected but not run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedBench {
+    /// Full name including suite: "suite/benchmark"
+    pub name: String,
+    /// Why it was skipped, e.g. the `ignore` reason
+    pub reason: String,
+}
+
+/// 95% confidence interval of the median from sorted samples.
+///
+/// Uses the distribution-free order-statistic method, so no assumption is made
+/// about the shape of the timing distribution. Returns `None` when there are
+/// too few samples (fewer than about ten) for the interval to exist.
+pub(crate) fn median_ci(sorted: &[Duration]) -> Option<(Duration, Duration)> {
+    let n = sorted.len() as f64;
+    let spread = 1.96 * n.sqrt();
+    // 1-based ranks of the lower and upper bounds
+    let lower = ((n - spread) / 2.0).floor() as usize;
+    let upper = (1.0 + (n + spread) / 2.0).ceil() as usize;
+    if lower < 1 || upper > sorted.len() {
+        return None;
+    }
+    Some((sorted[lower - 1], sorted[upper - 1]))
+}
+
+/// Relative half-width of the median's confidence interval.
+pub(crate) fn relative_median_ci(sorted: &[Duration]) -> Option<f64> {
+    let (lo, hi) = median_ci(sorted)?;
+    let median = sorted[sorted.len() / 2].as_secs_f64();
+    if median == 0.0 {
+        return Some(0.0);
+    }
+    Some((hi.as_secs_f64() - lo.as_secs_f64()) / (2.0 * median))
 }
 
 impl BenchResult {
@@ -67,6 +211,37 @@ impl BenchResult {
         Some(Duration::from_secs_f64(variance.sqrt()))
     }
 
+    /// Percentile `p` (0 to 100) of `all_runs`, interpolated between the
+    /// closest ranks. Falls back to `duration` if there are no runs.
+    pub fn percentile(&self, p: f64) -> Duration {
+        let mut sorted = self.all_runs.clone();
+        sorted.sort();
+        let Some(last) = sorted.len().checked_sub(1) else {
+            return self.duration;
+        };
+        let rank = p.clamp(0.0, 100.0) / 100.0 * last as f64;
+        let low = sorted[rank.floor() as usize].as_nanos() as f64;
+        let high = sorted[rank.ceil() as usize].as_nanos() as f64;
+        Duration::from_nanos((low + (high - low) * rank.fract()).round() as u64)
+    }
+
+    /// Arithmetic mean of `all_runs` (`duration` if there are no runs).
+    pub fn mean(&self) -> Duration {
+        match self.all_runs.len() {
+            0 => self.duration,
+            n => self.all_runs.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    /// 95% confidence interval of the median across `all_runs`.
+    ///
+    /// Returns `None` if there are too few runs (fewer than about ten).
+    pub fn median_ci(&self) -> Option<(Duration, Duration)> {
+        let mut sorted = self.all_runs.clone();
+        sorted.sort();
+        median_ci(&sorted)
+    }
+
     /// Compare against a baseline result.
     ///
     /// Returns the ratio: `self.duration / baseline.duration`.
@@ -86,7 +261,7 @@ impl BenchResult {
 }
 
 /// Results for an entire benchmark suite.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SuiteResult {
     /// Suite name
     pub suite: String,
@@ -104,19 +279,80 @@ pub struct SuiteResult {
     /// Git commit hash (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_sha: Option<String>,
+    /// Git branch (if available)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_branch: Option<String>,
     /// Custom metadata
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, String>,
+    /// Order benchmarks were run in; `results` lists them in that order
+    #[serde(default)]
+    pub order: BenchOrder,
+    /// Suite seed for random ordering and per-benchmark RNGs; pass it to
+    /// `--seed` to reproduce a run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Whether measurement runs were interleaved across benchmarks
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub interleaved: bool,
+    /// CPU pinning and priority of the benchmark thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduling: Option<Scheduling>,
+    /// Benchmarks that failed to produce a result
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<BenchFailure>,
+    /// Benchmarks that were selected but not run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedBench>,
+    /// Machine the suite ran on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<Environment>,
 }
 
 impl SuiteResult {
-    /// Load a suite result from JSON file.
+    /// Load a suite result from JSON file, a gzipped one (`.json.gz`, with
+    /// the `gzip` feature), or from a JSON Lines file (`.jsonl`) with
+    /// [`load_jsonl`](Self::load_jsonl).
     pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
-        let content = std::fs::read_to_string(path)?;
+        let path = path.as_ref();
+        if path.extension().is_some_and(|ext| ext == "jsonl") {
+            return Self::load_jsonl(path);
+        }
+        let content = if path.extension().is_some_and(|ext| ext == "gz") {
+            Self::read_gzip(path)?
+        } else {
+            std::fs::read_to_string(path)?
+        };
         serde_json::from_str(&content)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
     }
 
+    #[cfg(feature = "gzip")]
+    fn read_gzip(path: &std::path::Path) -> std::io::Result<String> {
+        crate::layout::gunzip(&std::fs::read(path)?)
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    fn read_gzip(path: &std::path::Path) -> std::io::Result<String> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!(
+                "{} is compressed; enable the `gzip` feature to read it",
+                path.display()
+            ),
+        ))
+    }
+
+    /// Rebuild a suite from a file written by
+    /// [`JsonLinesReporter`](crate::JsonLinesReporter).
+    ///
+    /// A file cut off mid-suite yields the benchmarks that finished; one that
+    /// was running is listed in `failures`.
+    pub fn load_jsonl(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        crate::jsonl::replay(&content)
+    }
+
     /// Compare this suite against a baseline.
     ///
     /// Returns a map of benchmark name to ratio (self/baseline).
@@ -159,7 +395,7 @@ impl SuiteResult {
     }
 }
 
-mod duration_serde {
+pub(crate) mod duration_serde {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::time::Duration;
 
@@ -167,9 +403,25 @@ mod duration_serde {
         d.as_nanos().serialize(s)
     }
 
+    // Read back as u64, which serde can also deserialize from buffered
+    // content (e.g. inside internally tagged enums); 2^64ns is 584 years
     pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
-        let nanos = u128::deserialize(d)?;
-        Ok(Duration::from_nanos(nanos as u64))
+        let nanos = u64::deserialize(d)?;
+        Ok(Duration::from_nanos(nanos))
+    }
+}
+
+mod option_duration_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        d.map(|d| d.as_nanos()).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        let nanos = Option::<u64>::deserialize(d)?;
+        Ok(nanos.map(Duration::from_nanos))
     }
 }
 
@@ -185,11 +437,55 @@ mod duration_vec_serde {
     }
 
     pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Duration>, D::Error> {
-        let nanos: Vec<u128> = Vec::deserialize(d)?;
-        Ok(nanos
-            .into_iter()
-            .map(|n| Duration::from_nanos(n as u64))
-            .collect())
+        let nanos: Vec<u64> = Vec::deserialize(d)?;
+        Ok(nanos.into_iter().map(Duration::from_nanos).collect())
+    }
+}
+
+/// Fixtures for reporter tests.
+#[cfg(test)]
+impl BenchResult {
+    /// A result named `name` with a median of `ms` milliseconds.
+    pub(crate) fn fixture(name: impl Into<String>, ms: u64) -> Self {
+        Self {
+            name: name.into(),
+            duration: Duration::from_millis(ms),
+            ..Default::default()
+        }
+    }
+
+    /// Set the measured runs in milliseconds.
+    pub(crate) fn measured(mut self, runs: &[u64]) -> Self {
+        self.all_runs = runs.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        self.runs = runs.len();
+        self
+    }
+
+    /// Set the bytes processed per run.
+    pub(crate) fn bytes(mut self, bytes: u64) -> Self {
+        self.bytes = Some(bytes);
+        self
+    }
+
+    /// Add a custom tag.
+    pub(crate) fn tag(mut self, key: &str, value: &str) -> Self {
+        self.tags.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+impl SuiteResult {
+    /// A suite with a `{suite}/{bench}` result for each `(bench, ms)` pair.
+    pub(crate) fn fixture(suite: &str, results: &[(&str, u64)]) -> Self {
+        Self {
+            suite: suite.to_string(),
+            results: results
+                .iter()
+                .map(|(bench, ms)| BenchResult::fixture(format!("{}/{}", suite, bench), *ms))
+                .collect(),
+            ..Default::default()
+        }
     }
 }
 
@@ -197,6 +493,26 @@ mod duration_vec_serde {
 mod tests {
     use super::*;
 
+    #[test]
+    fn should_interpolate_percentiles_between_runs() {
+        let result = BenchResult {
+            duration: Duration::from_millis(25),
+            all_runs: [40, 10, 20, 30].map(Duration::from_millis).to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(result.percentile(0.0), Duration::from_millis(10));
+        assert_eq!(result.percentile(50.0), Duration::from_millis(25));
+        assert_eq!(result.percentile(90.0), Duration::from_millis(37));
+        assert_eq!(result.percentile(100.0), Duration::from_millis(40));
+        assert_eq!(result.mean(), Duration::from_millis(25));
+
+        let single = BenchResult {
+            duration: Duration::from_millis(7),
+            ..Default::default()
+        };
+        assert_eq!(single.percentile(99.0), Duration::from_millis(7));
+    }
+
     #[test]
     fn should_calculate_throughput_when_bytes_set() {
         let result = BenchResult {
@@ -205,7 +521,7 @@ mod tests {
             bytes: Some(1_000_000),
             elements: None,
             all_runs: vec![Duration::from_secs(1)],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         assert_eq!(result.bytes_per_sec(), Some(1_000_000.0));
     }
@@ -218,7 +534,7 @@ mod tests {
             bytes: None,
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let current = BenchResult {
             name: "test".to_string(),
@@ -226,7 +542,7 @@ mod tests {
             bytes: None,
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         assert!(current.is_regression(&baseline, 0.05)); // 20% slower > 5% threshold
     }
@@ -239,7 +555,7 @@ mod tests {
             bytes: None,
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         let current = BenchResult {
             name: "test".to_string(),
@@ -247,8 +563,52 @@ mod tests {
             bytes: None,
             elements: None,
             all_runs: vec![],
-            tags: HashMap::new(),
+            ..Default::default()
         };
         assert!(!current.is_regression(&baseline, 0.05)); // 3% slower < 5% threshold
     }
+
+    #[test]
+    fn should_not_compute_median_ci_for_few_runs() {
+        let runs: Vec<_> = (1..=5).map(Duration::from_millis).collect();
+        assert!(median_ci(&runs).is_none());
+    }
+
+    #[test]
+    fn should_bracket_median_with_ci() {
+        let runs: Vec<_> = (1..=30).map(Duration::from_millis).collect();
+        let (lo, hi) = median_ci(&runs).unwrap();
+        let median = runs[runs.len() / 2];
+        assert!(lo < median && median < hi);
+        assert!(hi - lo < Duration::from_millis(30));
+    }
+
+    #[test]
+    fn should_report_zero_relative_ci_for_identical_runs() {
+        let runs = vec![Duration::from_millis(10); 20];
+        assert_eq!(relative_median_ci(&runs), Some(0.0));
+    }
+
+    #[test]
+    fn should_load_results_without_effective_settings() {
+        let json = r#"{"name":"old","duration":100,"all_runs":[100]}"#;
+        let result: BenchResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.runs, 0);
+        assert_eq!(result.timeout, None);
+        assert!(!result.timed_out);
+    }
+
+    #[test]
+    fn should_round_trip_timeout() {
+        let result = BenchResult {
+            name: "test".to_string(),
+            timeout: Some(Duration::from_secs(3)),
+            timed_out: true,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        let back: BenchResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.timeout, Some(Duration::from_secs(3)));
+        assert!(back.timed_out);
+    }
 }
@@ -0,0 +1,168 @@
+//! Seedable PRNG for reproducible benchmark ordering and workload data.
+//!
+//! Implements SplitMix64, which is fast, has a full 2^64 period and is
+//! stable across platforms and releases, so a recorded seed always
+//! reproduces the same sequence.
+
+use std::ops::Range;
+
+/// Deterministic random number generator handed out by
+/// [`StressContext::rng`](crate::StressContext::rng).
+///
+/// Every run of a benchmark starts from the same per-benchmark seed, so
+/// generated keys, values and access patterns are identical across runs and
+/// across invocations with the same `--seed`.
+#[derive(Debug, Clone)]
+pub struct StressRng {
+    state: u64,
+}
+
+impl StressRng {
+    /// Create a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Next 32 random bits.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Uniform float in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        // 53 random mantissa bits
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform integer in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "gen_range called with empty range");
+        range.start + self.below(range.end - range.start)
+    }
+
+    /// Fill `buf` with random bytes.
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Pick a random element, or `None` if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len() as u64) as usize])
+        }
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Uniform integer in `0..n`. `n` must be non-zero.
+    fn below(&mut self, n: u64) -> u64 {
+        // Rejection sampling avoids modulo bias
+        let zone = u64::MAX - (u64::MAX % n);
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+}
+
+/// A seed that differs between invocations, for when none was given.
+pub(crate) fn random_seed() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+    StressRng::new(nanos ^ (u64::from(std::process::id()) << 32)).next_u64()
+}
+
+/// Derive a benchmark's seed from the suite seed and its name.
+///
+/// Uses FNV-1a rather than `std`'s hasher, whose output may change between
+/// Rust releases, so recorded seeds stay reproducible.
+pub(crate) fn bench_seed(suite_seed: u64, name: &str) -> u64 {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+    for byte in name.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    StressRng::new(suite_seed ^ hash).next_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_produce_same_sequence_for_same_seed() {
+        let mut a = StressRng::new(42);
+        let mut b = StressRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(StressRng::new(1).next_u64(), StressRng::new(2).next_u64());
+    }
+
+    #[test]
+    fn should_match_reference_splitmix64_output() {
+        // Known outputs of the reference C implementation for seed 1234567
+        let mut rng = StressRng::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+    }
+
+    #[test]
+    fn should_shuffle_into_permutation() {
+        let mut items: Vec<u32> = (0..20).collect();
+        StressRng::new(7).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_stay_within_requested_range() {
+        let mut rng = StressRng::new(3);
+        assert!((0..1000).all(|_| (10..17).contains(&rng.gen_range(10..17))));
+        assert!((0..1000).all(|_| (0.0..1.0).contains(&rng.next_f64())));
+        assert_eq!(rng.choose::<u8>(&[]), None);
+    }
+
+    #[test]
+    fn should_fill_partial_chunks() {
+        let mut buf = [0u8; 13];
+        StressRng::new(5).fill_bytes(&mut buf);
+        assert!(buf[8..].iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn should_derive_distinct_stable_bench_seeds() {
+        assert_eq!(bench_seed(1, "insert"), bench_seed(1, "insert"));
+        assert_ne!(bench_seed(1, "insert"), bench_seed(1, "scan"));
+        assert_ne!(bench_seed(1, "insert"), bench_seed(2, "insert"));
+    }
+}
@@ -1,10 +1,31 @@
 //! The main benchmark runner.
 
-use crate::config::BenchRunnerConfig;
+use crate::config::{BenchOrder, BenchOverrides, BenchRunnerConfig, BenchSettings, ReporterKind};
 use crate::context::StressContext;
-use crate::report::{ConsoleReporter, JsonReporter, Reporter};
-use crate::result::{BenchResult, SuiteResult};
-use std::collections::HashMap;
+use crate::csv::{CsvFormat, CsvReporter};
+use crate::environment::Environment;
+use crate::filter::NameFilter;
+use crate::html::HtmlReporter;
+use crate::isolate::{self, ChildReporter};
+use crate::jsonl::JsonLinesReporter;
+use crate::junit::JunitReporter;
+use crate::limits::{self, AppliedLimits};
+use crate::markdown::MarkdownReporter;
+use crate::progress::{Progress, ProgressReporter};
+use crate::prometheus::PrometheusReporter;
+use crate::report::{
+    suite_path_name, ConsoleReporter, GitHubActionsReporter, JsonReporter, Reporter,
+};
+use crate::result::{
+    relative_median_ci, AdaptiveOutcome, BenchFailure, BenchResult, InjectedFault, RunInfo,
+    SkippedBench, SuiteResult,
+};
+use crate::rng::{bench_seed, random_seed, StressRng};
+use crate::sched::{self, Scheduling};
+use crate::soak::{SoakConfig, SoakLog, SoakSample, SoakTracker};
+use std::collections::{BTreeMap, HashMap};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// Lightweight benchmark runner for single-shot measurements.
@@ -30,9 +51,20 @@ pub struct BenchRunner {
     suite: String,
     config: BenchRunnerConfig,
     results: Vec<BenchResult>,
+    failures: Vec<BenchFailure>,
+    skipped: Vec<SkippedBench>,
     suite_start: Instant,
     reporters: Vec<Box<dyn Reporter>>,
     metadata: HashMap<String, String>,
+    filter: Option<NameFilter>,
+    /// Ordering actually applied, recorded in the suite result
+    order: BenchOrder,
+    interleaved: bool,
+    scheduling: Option<Scheduling>,
+    /// Shared with the heartbeat thread when progress output is enabled
+    progress: Option<Arc<Progress>>,
+    /// Re-raise benchmark panics from `finish` once results are reported
+    propagate_failures: bool,
 }
 
 impl BenchRunner {
@@ -42,22 +74,84 @@ impl BenchRunner {
     }
 
     /// Create a new runner with explicit config.
-    pub fn with_config(suite: &str, config: BenchRunnerConfig) -> Self {
+    ///
+    /// If `config.filter` is not a valid pattern for `config.filter_mode`, a
+    /// warning is printed and every benchmark runs.
+    ///
+    /// If `config.seed` is unset a random suite seed is chosen; it is shown
+    /// by the console reporter and recorded in the suite result.
+    ///
+    /// `config.pin` and `config.priority` are applied to the calling thread,
+    /// which should be the thread that runs the benchmarks.
+    pub fn with_config(suite: &str, mut config: BenchRunnerConfig) -> Self {
         let suite_start = Instant::now();
 
-        // Default reporters: console (always) + JSON
-        let reporters: Vec<Box<dyn Reporter>> = vec![
-            Box::new(ConsoleReporter::new()),
-            Box::new(JsonReporter::new(config.output_dir.clone())),
-        ];
+        // Resolve the suite seed up front so reporters can log it
+        config.seed.get_or_insert_with(random_seed);
+
+        let scheduling = if config.pin.is_empty() && config.priority.is_none() {
+            None
+        } else {
+            let scheduling = sched::apply(&config.pin, config.priority);
+            for warning in &scheduling.warnings {
+                eprintln!("Warning: {}", warning);
+            }
+            Some(scheduling)
+        };
+
+        let filter = config.filter.as_deref().and_then(|spec| {
+            NameFilter::new(spec, config.filter_mode)
+                .map_err(|e| eprintln!("Warning: ignoring benchmark filter: {}", e))
+                .ok()
+        });
+
+        // Default reporters: console (always) + JSON. A child in isolated
+        // mode only hands its results back to the parent.
+        let mut reporters: Vec<Box<dyn Reporter>> = if isolate::is_child() {
+            vec![Box::new(ChildReporter)]
+        } else {
+            vec![
+                Box::new(console_reporter(&config)),
+                Box::new(
+                    JsonReporter::new(config.output_dir.clone()).layout(config.layout.clone()),
+                ),
+            ]
+        };
+
+        if !isolate::is_child() {
+            let github = GitHubActionsReporter::is_github_actions()
+                && !config.reporters.contains(&ReporterKind::Github);
+            let kinds = config.reporters.iter().copied();
+            for kind in kinds.chain(github.then_some(ReporterKind::Github)) {
+                reporters.push(extra_reporter(kind, &config));
+            }
+        }
+
+        // Heartbeats go to stderr, so children print them too
+        let progress = config.progress.enabled(config.verbose).then(|| {
+            let progress = Arc::new(Progress::default());
+            reporters.push(Box::new(ProgressReporter::new(
+                Arc::clone(&progress),
+                config.progress_interval,
+            )));
+            progress
+        });
 
         let runner = Self {
             suite: suite.to_string(),
             config,
             results: Vec::new(),
+            failures: Vec::new(),
+            skipped: Vec::new(),
             suite_start,
             reporters,
             metadata: HashMap::new(),
+            filter,
+            order: BenchOrder::Declared,
+            interleaved: false,
+            scheduling,
+            progress,
+            propagate_failures: true,
         };
 
         // Notify reporters of suite start
@@ -87,84 +181,398 @@ impl BenchRunner {
     }
 
     fn should_run(&self, name: &str) -> bool {
-        match &self.config.filter {
-            Some(f) => name.contains(f.as_str()),
+        match &self.filter {
+            Some(f) => f.matches_any(&[name, &format!("{}/{}", self.suite, name)]),
             None => true,
         }
     }
 
+    /// Record a benchmark that is not run, e.g. because it is ignored.
+    ///
+    /// Reporters are notified and it is listed in [`SuiteResult::skipped`].
+    pub fn skip(&mut self, name: &str, reason: impl Into<String>) {
+        let skipped = SkippedBench {
+            name: format!("{}/{}", self.suite, name),
+            reason: reason.into(),
+        };
+        for r in &self.reporters {
+            r.bench_skipped(&skipped);
+        }
+        self.skipped.push(skipped);
+    }
+
+    /// Benchmarks that have failed so far.
+    pub fn failures(&self) -> &[BenchFailure] {
+        &self.failures
+    }
+
+    /// Keep failures out of `finish`, for the stress binary, which turns
+    /// them into its exit code.
+    pub(crate) fn contain_failures(&mut self) -> &mut Self {
+        self.propagate_failures = false;
+        self
+    }
+
     /// Run a benchmark case.
     ///
-    /// The closure must call `ctx.measure()` exactly once.
+    /// The closure must call `ctx.measure()` exactly once. If it panics, the
+    /// benchmark is recorded as failed and the suite carries on;
+    /// [`finish`](Self::finish) panics once the results are reported.
     pub fn run<F>(&mut self, name: &str, f: F)
+    where
+        F: Fn(&mut StressContext),
+    {
+        self.run_with(name, &BenchOverrides::default(), f);
+    }
+
+    /// Run a benchmark case with per-benchmark runs, warmup or timeout.
+    ///
+    /// Overrides take precedence over the runner config unless the config
+    /// forces its own values; the config's caps always apply.
+    ///
+    /// ```rust,no_run
+    /// use cntryl_stress::{BenchOverrides, BenchRunner};
+    /// use std::time::Duration;
+    ///
+    /// let mut runner = BenchRunner::new("storage");
+    /// let slow = BenchOverrides::new().runs(1).timeout(Duration::from_secs(1200));
+    /// runner.run_with("full_compaction", &slow, |ctx| {
+    ///     ctx.measure(|| { /* ... */ });
+    /// });
+    /// runner.finish();
+    /// ```
+    pub fn run_with<F>(&mut self, name: &str, overrides: &BenchOverrides, f: F)
     where
         F: Fn(&mut StressContext),
     {
         if !self.should_run(name) {
             return;
         }
+        self.run_case(&BenchCase::new(name, &f).overrides(overrides.clone()));
+    }
+
+    /// Run one case to completion.
+    fn run_case(&mut self, case: &BenchCase<'_>) {
+        let mut sampler = self.start_bench(case);
+        for round in 0..sampler.settings.warmup_runs {
+            if sampler.failure.is_some() {
+                break;
+            }
+            self.run_once(&mut sampler, case.func, Some(round));
+        }
+        while sampler.needs_more_runs() {
+            self.run_once(&mut sampler, case.func, None);
+        }
+        self.end_bench(sampler);
+    }
 
-        let full_name = format!("{}/{}", self.suite, name);
+    /// Run a set of benchmarks in the configured order.
+    ///
+    /// Cases are reordered according to `config.order` (a random order is
+    /// derived from the suite seed). With `config.interleave`, warmup and measurement runs
+    /// are round-robined across all cases instead of finishing one benchmark
+    /// before starting the next, so slow drift (thermal throttling, page
+    /// cache growth) is spread evenly rather than biasing later benchmarks.
+    ///
+    /// ```rust,no_run
+    /// use cntryl_stress::{BenchCase, BenchOrder, BenchRunner, BenchRunnerConfig};
+    ///
+    /// let config = BenchRunnerConfig::new().runs(10).order(BenchOrder::Random).interleave(true);
+    /// let mut runner = BenchRunner::with_config("storage", config);
+    /// let insert = |ctx: &mut cntryl_stress::StressContext| ctx.measure(|| { /* ... */ });
+    /// let scan = |ctx: &mut cntryl_stress::StressContext| ctx.measure(|| { /* ... */ });
+    /// runner.run_all(vec![BenchCase::new("insert", &insert), BenchCase::new("scan", &scan)]);
+    /// runner.finish();
+    /// ```
+    ///
+    /// With `config.soak`, runs are round-robined until the soak duration
+    /// has elapsed instead; see [`soak`](crate::soak).
+    pub fn run_all(&mut self, cases: Vec<BenchCase<'_>>) {
+        let cases = self.schedule(cases, |c| &c.name);
 
-        // Notify reporters
-        for r in &self.reporters {
-            r.bench_start(name);
+        if let Some(soak) = self.config.soak.clone() {
+            self.run_soak(&cases, &soak);
+            return;
         }
 
-        // Warmup runs
-        for _ in 0..self.config.warmup_runs {
-            let mut ctx = StressContext::new();
-            f(&mut ctx);
+        if !self.config.interleave {
+            for case in &cases {
+                self.run_case(case);
+            }
+            return;
         }
 
-        // Measurement runs
-        let mut durations = Vec::with_capacity(self.config.runs);
-        let mut bytes = None;
-        let mut elements = None;
-        let mut tags = HashMap::new();
+        self.interleaved = true;
+        let mut samplers: Vec<_> = cases.iter().map(|c| self.start_bench(c)).collect();
+        self.warm_up(&cases, &mut samplers);
 
-        for _ in 0..self.config.runs {
-            let mut ctx = StressContext::new();
-            f(&mut ctx);
+        while samplers.iter().any(Sampler::needs_more_runs) {
+            for (case, sampler) in cases.iter().zip(samplers.iter_mut()) {
+                if sampler.needs_more_runs() {
+                    self.run_once(sampler, case.func, None);
+                }
+            }
+        }
 
-            if let Some(d) = ctx.duration {
-                durations.push(d);
-            } else {
-                panic!(
-                    "Benchmark '{}' did not call ctx.measure(). \
-                     Every benchmark must measure exactly one operation.",
-                    name
+        for sampler in samplers {
+            self.end_bench(sampler);
+        }
+    }
+
+    /// Round-robin measured runs across `cases` until the soak duration has
+    /// elapsed, appending every run to the soak samples file.
+    fn run_soak(&mut self, cases: &[BenchCase<'_>], soak: &SoakConfig) {
+        let path = soak.output.clone().unwrap_or_else(|| {
+            self.config
+                .output_dir
+                .join(suite_path_name(&self.suite))
+                .join("soak.jsonl")
+        });
+        let mut log = match SoakLog::open(&path) {
+            Ok(log) => Some(log),
+            Err(e) => {
+                eprintln!(
+                    "Warning: cannot write soak samples to {}: {}",
+                    path.display(),
+                    e
                 );
+                None
             }
+        };
+
+        let mut samplers: Vec<_> = cases.iter().map(|c| self.start_bench(c)).collect();
+        self.warm_up(cases, &mut samplers);
+
+        let session = chrono_timestamp();
+        let start = Instant::now();
+        let samples = log.as_ref().map(|log| log.path().to_path_buf());
+        for sampler in &mut samplers {
+            let bench = format!("{}/{}", self.suite, sampler.name);
+            sampler.soak = Some(SoakTracker::new(
+                &session,
+                bench,
+                start,
+                soak,
+                samples.clone(),
+            ));
+        }
 
-            bytes = ctx.bytes.or(bytes);
-            elements = ctx.elements.or(elements);
-            for (k, v) in ctx.tags {
-                tags.insert(k, v);
+        loop {
+            for (case, sampler) in cases.iter().zip(samplers.iter_mut()) {
+                if sampler.stopped() {
+                    continue;
+                }
+                let Some(sample) = self.run_once(sampler, case.func, None) else {
+                    continue;
+                };
+                if let Some(Err(e)) = log.as_mut().map(|log| log.append(&sample)) {
+                    eprintln!("Warning: stopped writing soak samples: {}", e);
+                    log = None;
+                }
+            }
+            if start.elapsed() >= soak.duration || samplers.iter().all(Sampler::stopped) {
+                break;
             }
         }
 
-        // Report median
-        durations.sort();
-        let median = durations[durations.len() / 2];
+        for sampler in samplers {
+            self.end_bench(sampler);
+        }
+    }
 
-        let result = BenchResult {
-            name: full_name,
-            duration: median,
-            bytes,
-            elements,
-            all_runs: durations,
-            tags,
+    /// Run warmups round-robin, up to each sampler's own warmup count.
+    fn warm_up(&self, cases: &[BenchCase<'_>], samplers: &mut [Sampler]) {
+        let max_warmup = samplers
+            .iter()
+            .map(|s| s.settings.warmup_runs)
+            .max()
+            .unwrap_or(0);
+        for round in 0..max_warmup {
+            for (case, sampler) in cases.iter().zip(samplers.iter_mut()) {
+                if round < sampler.settings.warmup_runs && sampler.failure.is_none() {
+                    self.run_once(sampler, case.func, Some(round));
+                }
+            }
+        }
+    }
+
+    /// Make one warmup run (`Some(round)`) or measured run and notify
+    /// reporters. A panic marks the benchmark failed.
+    fn run_once(
+        &self,
+        sampler: &mut Sampler,
+        f: &dyn Fn(&mut StressContext),
+        warmup: Option<usize>,
+    ) -> Option<SoakSample> {
+        let mut ctx = match warmup {
+            Some(round) => sampler.warmup_context(round),
+            None => sampler.context(),
         };
+        let outcome = catch_unwind(AssertUnwindSafe(|| f(&mut ctx)));
+        if let Some(progress) = &self.progress {
+            progress.run_finished();
+        }
+        if let Err(payload) = outcome {
+            sampler.failure = Some(panic_message(payload.as_ref()));
+            return None;
+        }
 
-        // Notify reporters
+        let run = RunInfo {
+            run: warmup.unwrap_or(ctx.run),
+            warmup: warmup.is_some(),
+            duration: ctx.duration.unwrap_or_default(),
+        };
+        let sample = match warmup {
+            Some(_) => None,
+            None => sampler.record(ctx),
+        };
+        for r in &self.reporters {
+            r.run_end(&sampler.name, &run);
+        }
+        sample
+    }
+
+    /// Drop filtered-out items and apply the configured order.
+    pub(crate) fn schedule<T>(&mut self, items: Vec<T>, name: impl Fn(&T) -> &str) -> Vec<T> {
+        let mut items: Vec<_> = items
+            .into_iter()
+            .filter(|item| self.should_run(name(item)))
+            .collect();
+
+        self.order = self.config.order;
+        match self.config.order {
+            BenchOrder::Declared => {}
+            BenchOrder::Name => items.sort_by(|a, b| name(a).cmp(name(b))),
+            BenchOrder::Random => {
+                StressRng::new(self.suite_seed()).shuffle(&mut items);
+            }
+        }
+        items
+    }
+
+    /// Announce a benchmark measured elsewhere (an isolated-mode child).
+    pub(crate) fn announce(&self, name: &str, overrides: &BenchOverrides) {
+        let settings = self.config.resolve(overrides);
+        for r in &self.reporters {
+            r.bench_start(name, &settings);
+        }
+    }
+
+    /// Forward a run reported by an isolated-mode child.
+    pub(crate) fn record_run(&self, name: &str, run: &RunInfo) {
+        for r in &self.reporters {
+            r.run_end(name, run);
+        }
+    }
+
+    /// Record a result measured elsewhere (an isolated-mode child).
+    pub(crate) fn record_result(&mut self, result: BenchResult) {
         for r in &self.reporters {
             r.bench_end(&result);
         }
+        self.results.push(result);
+    }
+
+    /// Record a benchmark that produced no result.
+    pub(crate) fn record_failure(&mut self, name: &str, message: String) {
+        let failure = BenchFailure {
+            name: format!("{}/{}", self.suite, name),
+            message,
+        };
+        for r in &self.reporters {
+            r.bench_failed(&failure);
+        }
+        self.failures.push(failure);
+    }
+
+    pub(crate) fn suite_seed(&self) -> u64 {
+        // Always set by `with_config`
+        self.config.seed.unwrap_or_default()
+    }
+
+    fn start_bench(&self, case: &BenchCase<'_>) -> Sampler {
+        let name = case.name.as_str();
+        let settings = self.config.resolve(&case.overrides);
+        for r in &self.reporters {
+            r.bench_start(name, &settings);
+        }
+        let mut sampler = Sampler::new(name, settings, bench_seed(self.suite_seed(), name));
+        sampler.declared_tags = case.tags.clone();
+        sampler.group = case.group.clone();
+        sampler.worker_cpus = self.config.pin.clone();
+        sampler.progress = self.progress.clone();
 
+        // Limits are process-wide, so only an isolated child may apply them
+        if !settings.limits.is_empty() {
+            sampler.limits = Some(if isolate::is_child() {
+                limits::apply(settings.limits, self.config.cgroup, &self.config.pin)
+            } else {
+                AppliedLimits::skipped(
+                    settings.limits,
+                    "resource limits are only applied in --isolated mode",
+                )
+            });
+        }
+        sampler
+    }
+
+    /// Settings a benchmark with `overrides` would run with.
+    pub(crate) fn settings(&self, overrides: &BenchOverrides) -> BenchSettings {
+        self.config.resolve(overrides)
+    }
+
+    fn end_bench(&mut self, sampler: Sampler) {
+        if let Some(progress) = &self.progress {
+            progress.bench_finished(&sampler.name);
+        }
+        if let Some(message) = sampler.failure {
+            self.record_failure(&sampler.name, message);
+            return;
+        }
+        let result = sampler.into_result(&self.suite);
+        for r in &self.reporters {
+            r.bench_end(&result);
+        }
         self.results.push(result);
     }
 
+    /// Decide whether another measurement run is needed.
+    ///
+    /// Fixed mode stops at `settings.runs`. Adaptive mode always makes one
+    /// run, then stops when the time budget is spent, `max_runs` is reached, or
+    /// (after `min_runs`) the median's confidence interval meets the target.
+    fn needs_more_runs(
+        settings: &BenchSettings,
+        durations: &[Duration],
+        measured: Duration,
+    ) -> bool {
+        let adaptive = match settings.adaptive {
+            Some(adaptive) => adaptive,
+            None => return durations.len() < settings.runs,
+        };
+
+        let n = durations.len();
+        if n == 0 {
+            return true;
+        }
+        if adaptive
+            .time_budget
+            .is_some_and(|budget| measured >= budget)
+        {
+            return false;
+        }
+        if n < adaptive.min_runs {
+            return true;
+        }
+        if n >= adaptive.max_runs {
+            return false;
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        !relative_median_ci(&sorted).is_some_and(|ci| ci <= adaptive.target_ci)
+    }
+
     /// Run multiple related benchmarks as a group.
     ///
     /// Groups are just for organization/reporting.
@@ -182,8 +590,18 @@ impl BenchRunner {
     /// Finish the suite and return results.
     ///
     /// This writes JSON output and prints summary.
+    ///
+    /// # Panics
+    ///
+    /// Panics after reporting if any benchmark failed, so a failing suite
+    /// run from a test does not pass.
     pub fn finish(self) -> Vec<BenchResult> {
         let total_duration = self.suite_start.elapsed();
+        let propagate_failures = self.propagate_failures;
+        let mut environment = Environment::capture();
+        if self.config.record_hostname {
+            environment = environment.record_hostname();
+        }
 
         let suite_result = SuiteResult {
             suite: self.suite.clone(),
@@ -193,7 +611,15 @@ impl BenchRunner {
             runs: self.config.runs,
             warmup_runs: self.config.warmup_runs,
             git_sha: self.config.git_sha.clone(),
+            git_branch: self.config.git_branch.clone(),
             metadata: self.metadata,
+            order: self.order,
+            seed: self.config.seed,
+            interleaved: self.interleaved,
+            scheduling: self.scheduling,
+            failures: self.failures,
+            skipped: self.skipped,
+            environment: Some(environment),
         };
 
         // Notify reporters
@@ -201,6 +627,19 @@ impl BenchRunner {
             r.suite_end(&suite_result);
         }
 
+        if propagate_failures && !suite_result.failures.is_empty() {
+            let failures: Vec<_> = suite_result
+                .failures
+                .iter()
+                .map(|f| format!("{}: {}", f.name, f.message))
+                .collect();
+            panic!(
+                "{} benchmark(s) failed: {}",
+                failures.len(),
+                failures.join("; ")
+            );
+        }
+
         self.results
     }
 
@@ -217,14 +656,8 @@ impl BenchRunner {
         let regressions = match SuiteResult::load(&baseline_path) {
             Ok(baseline) => {
                 let current = SuiteResult {
-                    suite: String::new(),
                     results: results.clone(),
-                    total_duration: Duration::ZERO,
-                    started_at: String::new(),
-                    runs: 0,
-                    warmup_runs: 0,
-                    git_sha: None,
-                    metadata: HashMap::new(),
+                    ..Default::default()
                 };
                 current
                     .find_regressions(&baseline, threshold)
@@ -239,6 +672,225 @@ impl BenchRunner {
     }
 }
 
+/// A benchmark queued for [`BenchRunner::run_all`].
+pub struct BenchCase<'a> {
+    name: String,
+    overrides: BenchOverrides,
+    tags: Vec<String>,
+    group: Option<String>,
+    func: &'a dyn Fn(&mut StressContext),
+}
+
+impl<'a> BenchCase<'a> {
+    /// Create a case that runs `func` with the runner's settings.
+    pub fn new(name: impl Into<String>, func: &'a dyn Fn(&mut StressContext)) -> Self {
+        Self {
+            name: name.into(),
+            overrides: BenchOverrides::default(),
+            tags: Vec::new(),
+            group: None,
+            func,
+        }
+    }
+
+    /// Benchmark name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn overrides_ref(&self) -> &BenchOverrides {
+        &self.overrides
+    }
+
+    /// Set per-benchmark overrides.
+    pub fn overrides(mut self, overrides: BenchOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Add a declared tag, recorded in the result.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set the declared group, recorded in the result.
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+}
+
+/// Measurements collected for one benchmark.
+struct Sampler {
+    name: String,
+    declared_tags: Vec<String>,
+    group: Option<String>,
+    settings: BenchSettings,
+    seed: u64,
+    durations: Vec<Duration>,
+    bytes: Option<u64>,
+    elements: Option<u64>,
+    tags: HashMap<String, String>,
+    metrics: BTreeMap<String, f64>,
+    timed_out: bool,
+    measured: Duration,
+    faults: Vec<InjectedFault>,
+    limits: Option<AppliedLimits>,
+    worker_cpus: Vec<usize>,
+    /// Set in soak mode, which keeps trends instead of every duration
+    soak: Option<SoakTracker>,
+    progress: Option<Arc<Progress>>,
+    /// Panic message of a failed run; no further runs are made
+    failure: Option<String>,
+}
+
+impl Sampler {
+    fn new(name: &str, settings: BenchSettings, seed: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            declared_tags: Vec::new(),
+            group: None,
+            settings,
+            seed,
+            durations: Vec::with_capacity(settings.runs),
+            bytes: None,
+            elements: None,
+            tags: HashMap::new(),
+            metrics: BTreeMap::new(),
+            timed_out: false,
+            measured: Duration::ZERO,
+            faults: Vec::new(),
+            limits: None,
+            worker_cpus: Vec::new(),
+            soak: None,
+            progress: None,
+            failure: None,
+        }
+    }
+
+    /// Context for the next measured run.
+    fn context(&self) -> StressContext {
+        let mut ctx = StressContext::with_seed(self.seed);
+        ctx.run = match &self.soak {
+            Some(soak) => soak.iterations() as usize,
+            None => self.durations.len(),
+        };
+        ctx.pin = self.worker_cpus.clone();
+        if let Some(progress) = &self.progress {
+            // Adaptive and soak runs have no fixed count to show or estimate from
+            let runs = (self.soak.is_none() && self.settings.adaptive.is_none())
+                .then_some(self.settings.runs);
+            progress.run_started(&self.name, ctx.run, runs, false);
+            ctx.progress = Some(Arc::clone(progress));
+        }
+        ctx
+    }
+
+    fn warmup_context(&self, round: usize) -> StressContext {
+        let mut ctx = StressContext::with_seed(self.seed);
+        ctx.warmup = true;
+        ctx.pin = self.worker_cpus.clone();
+        if let Some(progress) = &self.progress {
+            let warmups = Some(self.settings.warmup_runs);
+            progress.run_started(&self.name, round, warmups, true);
+            ctx.progress = Some(Arc::clone(progress));
+        }
+        ctx
+    }
+
+    /// Whether the benchmark failed or timed out.
+    fn stopped(&self) -> bool {
+        self.timed_out || self.failure.is_some()
+    }
+
+    fn needs_more_runs(&self) -> bool {
+        // A run that blows the timeout makes further runs pointless
+        !self.stopped()
+            && BenchRunner::needs_more_runs(&self.settings, &self.durations, self.measured)
+    }
+
+    /// Record a measured run; in soak mode, returns its sample.
+    fn record(&mut self, ctx: StressContext) -> Option<SoakSample> {
+        let d = match ctx.duration {
+            Some(d) => d,
+            None => panic!(
+                "Benchmark '{}' did not call ctx.measure(). \
+                 Every benchmark must measure exactly one operation.",
+                self.name
+            ),
+        };
+        let sample = match &mut self.soak {
+            Some(soak) => Some(soak.record(d, &ctx.metrics)),
+            None => {
+                self.durations.push(d);
+                None
+            }
+        };
+        self.measured += d;
+
+        self.bytes = ctx.bytes.or(self.bytes);
+        self.elements = ctx.elements.or(self.elements);
+        self.tags.extend(ctx.tags);
+        self.metrics.extend(ctx.metrics);
+        self.faults.extend(ctx.faults);
+
+        if self.settings.timeout.is_some_and(|limit| d > limit) {
+            self.timed_out = true;
+        }
+        sample
+    }
+
+    fn into_result(self, suite: &str) -> BenchResult {
+        let soak = self.soak.as_ref().map(SoakTracker::summary);
+        let mut durations = match &self.soak {
+            Some(soak) => soak.durations(),
+            None => self.durations,
+        };
+
+        // Report median
+        durations.sort();
+        let median = durations[durations.len() / 2];
+
+        let adaptive = self.settings.adaptive.map(|adaptive| {
+            let relative_ci = relative_median_ci(&durations);
+            AdaptiveOutcome {
+                runs: durations.len(),
+                target_ci: adaptive.target_ci,
+                relative_ci,
+                target_met: relative_ci.is_some_and(|ci| ci <= adaptive.target_ci),
+            }
+        });
+
+        BenchResult {
+            name: format!("{}/{}", suite, self.name),
+            duration: median,
+            bytes: self.bytes,
+            elements: self.elements,
+            runs: if let Some(soak) = &soak {
+                soak.iterations as usize
+            } else if adaptive.is_some() {
+                durations.len()
+            } else {
+                self.settings.runs
+            },
+            all_runs: durations,
+            tags: self.tags,
+            declared_tags: self.declared_tags,
+            group: self.group,
+            warmup_runs: self.settings.warmup_runs,
+            timeout: self.settings.timeout,
+            timed_out: self.timed_out,
+            adaptive,
+            seed: Some(self.seed),
+            faults: self.faults,
+            limits: self.limits,
+            metrics: self.metrics,
+            soak,
+        }
+    }
+}
+
 /// A benchmark group for organizing related benchmarks.
 pub struct BenchGroup<'a> {
     runner: &'a mut BenchRunner,
@@ -254,6 +906,86 @@ impl<'a> BenchGroup<'a> {
         let full_name = format!("{}/{}", self.prefix, name);
         self.runner.run(&full_name, f);
     }
+
+    /// Run a benchmark within this group with per-benchmark overrides.
+    pub fn run_with<F>(&mut self, name: &str, overrides: &BenchOverrides, f: F)
+    where
+        F: Fn(&mut StressContext),
+    {
+        let full_name = format!("{}/{}", self.prefix, name);
+        self.runner.run_with(&full_name, overrides, f);
+    }
+}
+
+/// Console reporter with the config's options, coloring changes against
+/// its baseline.
+fn console_reporter(config: &BenchRunnerConfig) -> ConsoleReporter {
+    let console = ConsoleReporter::new()
+        .options(config.console.clone())
+        .threshold(config.threshold);
+    match &config.baseline {
+        Some(baseline) => console.with_baseline(baseline),
+        None => console,
+    }
+}
+
+/// Build an extra reporter, comparing against the config's baseline.
+fn extra_reporter(kind: ReporterKind, config: &BenchRunnerConfig) -> Box<dyn Reporter> {
+    match kind {
+        ReporterKind::Github => {
+            let mut github = GitHubActionsReporter::new(config.threshold);
+            if let Some(baseline) = &config.baseline {
+                // The stress binary exits 1 on regressions against the baseline
+                github = github.with_baseline(baseline).fail_on_regression(true);
+            }
+            Box::new(github)
+        }
+        ReporterKind::Junit => {
+            let mut junit =
+                JunitReporter::new(config.output_dir.clone()).threshold(config.threshold);
+            if let Some(baseline) = &config.baseline {
+                junit = junit.with_baseline(baseline);
+            }
+            Box::new(junit)
+        }
+        ReporterKind::Csv => Box::new(CsvReporter::new(config.output_dir.clone())),
+        ReporterKind::Tsv => {
+            Box::new(CsvReporter::new(config.output_dir.clone()).format(CsvFormat::Tsv))
+        }
+        ReporterKind::Markdown => {
+            let mut markdown =
+                MarkdownReporter::new(config.output_dir.clone()).threshold(config.threshold);
+            if let Some(baseline) = &config.baseline {
+                markdown = markdown.with_baseline(baseline);
+            }
+            Box::new(markdown)
+        }
+        ReporterKind::Html => {
+            let mut html = HtmlReporter::new(config.output_dir.clone()).threshold(config.threshold);
+            if let Some(baseline) = &config.baseline {
+                html = html.with_baseline(baseline);
+            }
+            Box::new(html)
+        }
+        ReporterKind::Jsonl => Box::new(
+            JsonLinesReporter::new(config.output_dir.clone()).retention(config.layout.retention),
+        ),
+        ReporterKind::Prometheus => Box::new(PrometheusReporter::new(
+            config.output_dir.join("prometheus"),
+        )),
+    }
+}
+
+/// Message of a caught benchmark panic.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    };
+    format!("panicked: {}", message)
 }
 
 fn chrono_timestamp() -> String {
@@ -270,6 +1002,8 @@ fn chrono_timestamp() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{AdaptiveConfig, ProgressMode};
+    use crate::filter::FilterMode;
 
     #[test]
     fn should_run_benchmark_when_no_filter() {
@@ -304,6 +1038,141 @@ mod tests {
         assert!(results[0].name.contains("keep"));
     }
 
+    #[test]
+    fn should_apply_overrides_when_running_with() {
+        let config = BenchRunnerConfig::new().verbose(false).runs(5);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        runner.run_with("slow", &BenchOverrides::new().runs(2).warmup(1), |ctx| {
+            ctx.measure(|| {});
+        });
+        runner.run("fast", |ctx| {
+            ctx.measure(|| {});
+        });
+
+        let results = runner.finish();
+        assert_eq!(results[0].all_runs.len(), 2);
+        assert_eq!(results[0].runs, 2);
+        assert_eq!(results[0].warmup_runs, 1);
+        assert_eq!(results[1].all_runs.len(), 5);
+    }
+
+    #[test]
+    fn should_stop_after_run_exceeding_timeout() {
+        let config = BenchRunnerConfig::new().verbose(false).runs(3);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        let overrides = BenchOverrides::new().timeout(Duration::from_millis(1));
+        runner.run_with("sleepy", &overrides, |ctx| {
+            ctx.record_duration(Duration::from_millis(5));
+        });
+
+        let results = runner.finish();
+        assert!(results[0].timed_out);
+        assert_eq!(results[0].all_runs.len(), 1);
+        assert_eq!(results[0].timeout, Some(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn should_stop_adaptive_runs_when_ci_is_narrow() {
+        let adaptive = AdaptiveConfig::new()
+            .min_runs(10)
+            .max_runs(50)
+            .target_ci(0.05);
+        let config = BenchRunnerConfig::new().verbose(false).adaptive(adaptive);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        runner.run("stable", |ctx| {
+            ctx.record_duration(Duration::from_millis(10));
+        });
+
+        let results = runner.finish();
+        let outcome = results[0].adaptive.unwrap();
+        assert_eq!(results[0].all_runs.len(), 10);
+        assert_eq!(results[0].runs, 10);
+        assert_eq!(outcome.runs, 10);
+        assert!(outcome.target_met);
+    }
+
+    #[test]
+    fn should_stop_adaptive_runs_at_max_when_noisy() {
+        let adaptive = AdaptiveConfig::new()
+            .min_runs(10)
+            .max_runs(15)
+            .target_ci(0.0001);
+        let config = BenchRunnerConfig::new().verbose(false).adaptive(adaptive);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        let counter = std::cell::Cell::new(0u64);
+        runner.run("noisy", |ctx| {
+            counter.set(counter.get() + 1);
+            ctx.record_duration(Duration::from_millis(1 + (counter.get() * 7) % 13));
+        });
+
+        let results = runner.finish();
+        let outcome = results[0].adaptive.unwrap();
+        assert_eq!(outcome.runs, 15);
+        assert!(!outcome.target_met);
+    }
+
+    #[test]
+    fn should_stop_adaptive_runs_when_budget_spent() {
+        let adaptive = AdaptiveConfig::new()
+            .min_runs(10)
+            .time_budget(Duration::from_millis(30));
+        let config = BenchRunnerConfig::new().verbose(false).adaptive(adaptive);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        runner.run("budgeted", |ctx| {
+            ctx.record_duration(Duration::from_millis(10));
+        });
+
+        let results = runner.finish();
+        assert_eq!(results[0].all_runs.len(), 3);
+        assert!(!results[0].adaptive.unwrap().target_met);
+    }
+
+    #[test]
+    fn should_filter_with_glob_and_exact_modes() {
+        let config = BenchRunnerConfig::new().verbose(false).filter("db/*,scan");
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+        for name in ["db/insert", "db/wal/insert", "full_scan", "other"] {
+            runner.run(name, |ctx| ctx.measure(|| {}));
+        }
+        let names: Vec<_> = runner.finish().into_iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["test/db/insert", "test/full_scan"]);
+
+        let config = BenchRunnerConfig::new()
+            .verbose(false)
+            .filter("scan")
+            .filter_mode(FilterMode::Exact);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+        runner.run("scan", |ctx| ctx.measure(|| {}));
+        runner.run("full_scan", |ctx| ctx.measure(|| {}));
+        assert_eq!(runner.finish().len(), 1);
+    }
+
+    #[test]
+    fn should_ignore_filter_when_filter_invalid() {
+        let config = BenchRunnerConfig::new()
+            .verbose(false)
+            .runs(1)
+            .filter("(")
+            .filter_mode(FilterMode::Regex);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+        runner.run("a", |ctx| ctx.measure(|| {}));
+
+        assert_eq!(runner.finish().len(), 1);
+    }
+
     #[test]
     #[should_panic(expected = "did not call ctx.measure")]
     fn should_panic_when_measure_not_called() {
@@ -315,4 +1184,332 @@ mod tests {
             // Forgot to call measure!
         });
     }
+
+    /// Captures the suite result passed to `suite_end`.
+    struct SuiteCapture(std::sync::Arc<std::sync::Mutex<Option<SuiteResult>>>);
+
+    impl Reporter for SuiteCapture {
+        fn suite_end(&self, result: &SuiteResult) {
+            *self.0.lock().unwrap() = Some(result.clone());
+        }
+    }
+
+    fn run_cases(config: BenchRunnerConfig, names: &[&str]) -> SuiteResult {
+        let captured = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let mut runner = BenchRunner::with_config("test", config.verbose(false));
+        runner.reporters(vec![Box::new(SuiteCapture(captured.clone()))]);
+
+        let measure = |ctx: &mut StressContext| ctx.measure(|| {});
+        runner.run_all(
+            names
+                .iter()
+                .map(|name| BenchCase::new(*name, &measure))
+                .collect(),
+        );
+        runner.finish();
+
+        let suite = captured.lock().unwrap().take().unwrap();
+        suite
+    }
+
+    fn result_names(suite: &SuiteResult) -> Vec<&str> {
+        suite.results.iter().map(|r| r.name.as_str()).collect()
+    }
+
+    #[test]
+    fn should_run_cases_in_declared_or_name_order() {
+        let suite = run_cases(BenchRunnerConfig::new(), &["c", "a", "b"]);
+        assert_eq!(result_names(&suite), ["test/c", "test/a", "test/b"]);
+        assert_eq!(suite.order, BenchOrder::Declared);
+        assert!(suite.seed.is_some());
+
+        let suite = run_cases(
+            BenchRunnerConfig::new().order(BenchOrder::Name),
+            &["c", "a", "b"],
+        );
+        assert_eq!(result_names(&suite), ["test/a", "test/b", "test/c"]);
+    }
+
+    #[test]
+    fn should_reproduce_random_order_from_seed() {
+        let names: Vec<String> = (0..10).map(|i| format!("bench{}", i)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let config = BenchRunnerConfig::new().order(BenchOrder::Random).seed(99);
+
+        let first = run_cases(config.clone(), &names);
+        let second = run_cases(config, &names);
+        assert_eq!(result_names(&first), result_names(&second));
+        assert_eq!(first.seed, Some(99));
+        assert_eq!(first.order, BenchOrder::Random);
+
+        let unseeded = run_cases(BenchRunnerConfig::new().order(BenchOrder::Random), &names);
+        assert!(unseeded.seed.is_some());
+    }
+
+    #[test]
+    fn should_round_robin_runs_when_interleaved() {
+        let log = std::cell::RefCell::new(Vec::new());
+        let config = BenchRunnerConfig::new()
+            .verbose(false)
+            .runs(3)
+            .warmup(1)
+            .interleave(true);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        let a = |ctx: &mut StressContext| {
+            log.borrow_mut().push("a");
+            ctx.measure(|| {});
+        };
+        let b = |ctx: &mut StressContext| {
+            log.borrow_mut().push("b");
+            ctx.measure(|| {});
+        };
+        runner.run_all(vec![
+            BenchCase::new("a", &a),
+            BenchCase::new("b", &b).overrides(BenchOverrides::new().runs(1)),
+        ]);
+
+        let results = runner.finish();
+        assert_eq!(*log.borrow(), ["a", "b", "a", "b", "a", "a"]);
+        assert_eq!(results[0].all_runs.len(), 3);
+        assert_eq!(results[1].all_runs.len(), 1);
+    }
+
+    #[test]
+    fn should_record_declared_tags_and_group_when_set_on_case() {
+        let config = BenchRunnerConfig::new().verbose(false).runs(1);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        let f = |ctx: &mut StressContext| ctx.measure(|| {});
+        runner.run_all(vec![BenchCase::new("scan", &f)
+            .tag("disk")
+            .tag("slow")
+            .group("io")]);
+
+        let results = runner.finish();
+        assert_eq!(results[0].declared_tags, ["disk", "slow"]);
+        assert_eq!(results[0].group.as_deref(), Some("io"));
+    }
+
+    #[test]
+    fn should_give_each_benchmark_a_reproducible_seed() {
+        let draw = |seed: u64| {
+            let config = BenchRunnerConfig::new().verbose(false).runs(2).seed(seed);
+            let mut runner = BenchRunner::with_config("test", config);
+            runner.reporters(vec![]);
+            let draws = std::cell::RefCell::new(Vec::new());
+            for name in ["a", "b"] {
+                runner.run(name, |ctx| {
+                    draws.borrow_mut().push(ctx.rng().next_u64());
+                    ctx.measure(|| {});
+                });
+            }
+            let results = runner.finish();
+            (draws.into_inner(), results)
+        };
+
+        let (first, results) = draw(42);
+        let (second, _) = draw(42);
+        let (other, _) = draw(43);
+
+        // Same sequence on every run of a benchmark, distinct between benchmarks
+        assert_eq!(first[0], first[1]);
+        assert_ne!(first[0], first[2]);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(results[0].seed, Some(bench_seed(42, "a")));
+    }
+
+    #[test]
+    fn should_record_faults_from_measured_runs_only() {
+        let _serial = crate::faults::serial();
+        let config = BenchRunnerConfig::new().verbose(false).runs(3).warmup(2);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+        runner.run("flaky_io", |ctx| {
+            ctx.faults().error("runner.test.io", "EIO").times(1);
+            ctx.measure(|| crate::faults::failpoint("runner.test.io").is_err());
+        });
+        let results = runner.finish();
+
+        let faults = &results[0].faults;
+        assert_eq!(faults.len(), 3);
+        let runs: Vec<_> = faults.iter().map(|f| f.run).collect();
+        assert_eq!(runs, [0, 1, 2]);
+        assert!(faults.iter().all(|f| f.kind == crate::FaultKind::Error));
+    }
+
+    #[test]
+    fn should_soak_round_robin_and_append_samples() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("soak.jsonl");
+        let soak = SoakConfig::new(Duration::from_millis(30)).output(&path);
+        let config = BenchRunnerConfig::new().verbose(false).soak(soak);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![]);
+
+        let fast = |ctx: &mut StressContext| {
+            ctx.metric("depth", 2.0);
+            ctx.measure(|| std::thread::sleep(Duration::from_millis(1)));
+        };
+        let slow = |ctx: &mut StressContext| {
+            ctx.measure(|| std::thread::sleep(Duration::from_millis(2)));
+        };
+        runner.run_all(vec![
+            BenchCase::new("fast", &fast),
+            BenchCase::new("slow", &slow),
+        ]);
+        let results = runner.finish();
+
+        let fast = results[0].soak.as_ref().unwrap();
+        let slow = results[1].soak.as_ref().unwrap();
+        assert!(fast.iterations >= 2);
+        assert_eq!(fast.iterations, slow.iterations);
+        assert_eq!(results[0].runs, fast.iterations as usize);
+        assert_eq!(results[0].metrics["depth"], 2.0);
+        assert_eq!(fast.samples.as_deref(), Some(path.as_path()));
+
+        let lines = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(lines.lines().count() as u64, fast.iterations * 2);
+        let first: SoakSample = serde_json::from_str(lines.lines().next().unwrap()).unwrap();
+        assert_eq!(first.bench, "test/fast");
+    }
+
+    #[test]
+    fn should_share_progress_with_contexts_only_when_enabled() {
+        for (mode, expected) in [(ProgressMode::Always, true), (ProgressMode::Never, false)] {
+            let config = BenchRunnerConfig::new()
+                .verbose(false)
+                .runs(2)
+                .warmup(1)
+                .progress(mode)
+                .progress_interval(Duration::from_millis(1));
+            let mut runner = BenchRunner::with_config("test", config);
+            // Dropping the heartbeat reporter must release its handle
+            runner.reporters(vec![]);
+            let enabled = std::cell::Cell::new(Vec::new());
+            runner.run("load", |ctx| {
+                let mut seen = enabled.take();
+                seen.push(ctx.progress.is_some());
+                enabled.set(seen);
+                ctx.progress(1, 2);
+                ctx.measure(|| std::thread::sleep(Duration::from_millis(3)));
+            });
+            assert_eq!(enabled.take(), [expected; 3]);
+            let progress = runner.progress.clone();
+            runner.finish();
+            if let Some(progress) = progress {
+                assert_eq!(Arc::strong_count(&progress), 1);
+            }
+        }
+    }
+
+    /// Records lifecycle events as strings.
+    struct EventCapture(Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl Reporter for EventCapture {
+        fn bench_start(&self, name: &str, settings: &BenchSettings) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("start {} runs={}", name, settings.runs));
+        }
+
+        fn run_end(&self, name: &str, run: &RunInfo) {
+            let kind = if run.warmup { "warmup" } else { "run" };
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} {} {}", kind, name, run.run));
+        }
+
+        fn bench_end(&self, result: &BenchResult) {
+            self.0.lock().unwrap().push(format!("end {}", result.name));
+        }
+
+        fn bench_failed(&self, failure: &BenchFailure) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("failed {}: {}", failure.name, failure.message));
+        }
+
+        fn bench_skipped(&self, skipped: &SkippedBench) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("skipped {}: {}", skipped.name, skipped.reason));
+        }
+    }
+
+    #[test]
+    fn should_report_each_run_and_lifecycle_event() {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let config = BenchRunnerConfig::new().verbose(false).runs(2).warmup(1);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![Box::new(EventCapture(events.clone()))]);
+
+        runner.skip("raw_device", "needs /dev/nvme0");
+        runner.run("scan", |ctx| ctx.measure(|| {}));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "skipped test/raw_device: needs /dev/nvme0",
+                "start scan runs=2",
+                "warmup scan 0",
+                "run scan 0",
+                "run scan 1",
+                "end test/scan",
+            ]
+        );
+        assert_eq!(runner.skipped.len(), 1);
+    }
+
+    #[test]
+    fn should_record_panicking_benchmark_as_failed_and_continue() {
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let captured = Arc::new(std::sync::Mutex::new(None));
+        let config = BenchRunnerConfig::new().verbose(false).runs(3).warmup(0);
+        let mut runner = BenchRunner::with_config("test", config);
+        runner.reporters(vec![
+            Box::new(EventCapture(events.clone())),
+            Box::new(SuiteCapture(captured.clone())),
+        ]);
+
+        let calls = std::cell::Cell::new(0);
+        runner.run("broken", |ctx| {
+            calls.set(calls.get() + 1);
+            ctx.measure(|| {});
+            if calls.get() == 2 {
+                panic!("corrupt block");
+            }
+        });
+        runner.run("healthy", |ctx| ctx.measure(|| {}));
+
+        // No runs after the panic
+        assert_eq!(calls.get(), 2);
+        assert_eq!(runner.failures().len(), 1);
+        let payload = catch_unwind(AssertUnwindSafe(|| runner.finish())).unwrap_err();
+        assert_eq!(
+            panic_message(payload.as_ref()),
+            "panicked: 1 benchmark(s) failed: test/broken: panicked: corrupt block"
+        );
+
+        let suite = captured.lock().unwrap().take().unwrap();
+        assert_eq!(result_names(&suite), ["test/healthy"]);
+        assert_eq!(
+            suite.failures,
+            [BenchFailure {
+                name: "test/broken".to_string(),
+                message: "panicked: corrupt block".to_string(),
+            }]
+        );
+        assert!(events
+            .lock()
+            .unwrap()
+            .contains(&"failed test/broken: panicked: corrupt block".to_string()));
+    }
 }
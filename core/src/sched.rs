@@ -0,0 +1,271 @@
+//! CPU pinning and scheduling priority for benchmark threads (Linux).
+//!
+//! With a pin list, the thread running benchmarks is pinned to its first CPU
+//! and worker threads take the remaining CPUs round-robin (see
+//! [`StressContext::worker_cpu`](crate::StressContext::worker_cpu)). A
+//! priority is applied to the benchmark thread only when the process is
+//! permitted to raise it; failures are recorded as warnings in [`Scheduling`]
+//! instead of aborting the suite.
+
+use serde::{Deserialize, Serialize};
+#[cfg(target_os = "linux")]
+use std::sync::OnceLock;
+
+/// Hands the process affinity to isolated children, which otherwise inherit
+/// the parent's pinned mask.
+pub(crate) const PROCESS_CPUS_ENV: &str = "CNTRYL_STRESS_PROCESS_CPUS";
+
+/// Scheduling priority for the benchmark thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// `nice` value, -20 (highest) to 19 (lowest); negative values usually
+    /// need `CAP_SYS_NICE`
+    Nice(i32),
+    /// Real-time `SCHED_FIFO` priority, 1 to 99; needs `CAP_SYS_NICE`.
+    /// A benchmark that never blocks can starve the rest of the system.
+    Fifo(i32),
+}
+
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    /// Parse `nice:<N>` or `fifo:<N>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid priority '{}' (expected nice:<N> or fifo:<N>)", s);
+        let (kind, value) = s.split_once(':').ok_or_else(invalid)?;
+        let value: i32 = value.trim().parse().map_err(|_| invalid())?;
+        match kind.trim().to_ascii_lowercase().as_str() {
+            "nice" if (-20..=19).contains(&value) => Ok(Priority::Nice(value)),
+            "fifo" if (1..=99).contains(&value) => Ok(Priority::Fifo(value)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Pinning and priority that were applied, recorded in
+/// [`SuiteResult::scheduling`](crate::SuiteResult::scheduling).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scheduling {
+    /// CPUs requested with `--pin`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpus: Vec<usize>,
+    /// CPU the benchmark thread was pinned to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark_cpu: Option<usize>,
+    /// Priority applied to the benchmark thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    /// What could not be applied, and why
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Parse a CPU list such as `3`, `0-3` or `0,2,4-7`.
+pub(crate) fn parse_cpu_list(s: &str) -> Result<Vec<usize>, String> {
+    let invalid = || format!("invalid CPU list '{}' (expected e.g. 2 or 0-3,8)", s);
+    let mut cpus = Vec::new();
+    for part in s.split(',').map(str::trim) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start: usize = start.trim().parse().map_err(|_| invalid())?;
+                let end: usize = end.trim().parse().map_err(|_| invalid())?;
+                if start > end {
+                    return Err(invalid());
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(part.parse().map_err(|_| invalid())?),
+        }
+    }
+    Ok(cpus)
+}
+
+/// CPU for worker `index`: workers share the pin list after the benchmark
+/// thread's CPU, wrapping around when there are more workers than CPUs.
+pub(crate) fn worker_cpu(cpus: &[usize], index: usize) -> Option<usize> {
+    match cpus.len() {
+        0 => None,
+        1 => Some(cpus[0]),
+        n => Some(cpus[1 + index % (n - 1)]),
+    }
+}
+
+/// Pin the calling thread and apply `priority` to it.
+pub(crate) fn apply(cpus: &[usize], priority: Option<Priority>) -> Scheduling {
+    let mut scheduling = Scheduling {
+        cpus: cpus.to_vec(),
+        ..Default::default()
+    };
+
+    if let Some(&cpu) = cpus.first() {
+        // Capture the affinity before it is narrowed to one CPU
+        let _ = process_cpus();
+        match pin_current_thread(cpu) {
+            Ok(()) => scheduling.benchmark_cpu = Some(cpu),
+            Err(e) => scheduling
+                .warnings
+                .push(format!("pinning to CPU {}: {}", cpu, e)),
+        }
+    }
+    if let Some(priority) = priority {
+        match set_priority(priority) {
+            Ok(()) => scheduling.priority = Some(priority),
+            Err(e) => scheduling
+                .warnings
+                .push(format!("priority {:?}: {}", priority, e)),
+        }
+    }
+
+    scheduling
+}
+
+/// Pin the calling thread to a single CPU (Linux only).
+pub fn pin_current_thread(cpu: usize) -> std::io::Result<()> {
+    set_affinity(&[cpu])
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn set_affinity(cpus: &[usize]) -> std::io::Result<()> {
+    // SAFETY: an all-zero cpu_set_t is a valid empty set
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &cpu in cpus {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(std::io::Error::other(format!("CPU {} out of range", cpu)));
+        }
+        // SAFETY: `cpu` is below CPU_SETSIZE
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+    let size = std::mem::size_of::<libc::cpu_set_t>();
+    // SAFETY: `set` is valid for `size` bytes; pid 0 is the calling thread
+    if unsafe { libc::sched_setaffinity(0, size, &set) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// CPUs in the calling thread's affinity mask.
+#[cfg(target_os = "linux")]
+pub(crate) fn allowed_cpus() -> std::io::Result<Vec<usize>> {
+    // SAFETY: an all-zero cpu_set_t is a valid empty set
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::cpu_set_t>();
+    // SAFETY: `set` is valid for `size` bytes
+    if unsafe { libc::sched_getaffinity(0, size, &mut set) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok((0..libc::CPU_SETSIZE as usize)
+        // SAFETY: `cpu` is below CPU_SETSIZE
+        .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) })
+        .collect())
+}
+
+/// CPUs the process may run on, as captured before any pinning.
+///
+/// In an isolated child this is the parent's affinity rather than the
+/// inherited, possibly pinned, mask.
+#[cfg(target_os = "linux")]
+pub(crate) fn process_cpus() -> std::io::Result<Vec<usize>> {
+    static CPUS: OnceLock<Vec<usize>> = OnceLock::new();
+    if let Some(cpus) = CPUS.get() {
+        return Ok(cpus.clone());
+    }
+    let inherited = std::env::var(PROCESS_CPUS_ENV)
+        .ok()
+        .and_then(|v| parse_cpu_list(&v).ok());
+    let cpus = match inherited {
+        Some(cpus) => cpus,
+        None => allowed_cpus()?,
+    };
+    Ok(CPUS.get_or_init(|| cpus).clone())
+}
+
+#[cfg(target_os = "linux")]
+fn set_priority(priority: Priority) -> std::io::Result<()> {
+    let rc = match priority {
+        // On Linux the nice value is per thread; who = 0 is the calling thread
+        // SAFETY: plain syscall without pointers
+        Priority::Nice(nice) => unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) },
+        Priority::Fifo(prio) => {
+            let param = libc::sched_param {
+                sched_priority: prio,
+            };
+            // SAFETY: `param` is a valid sched_param
+            unsafe { libc::sched_setscheduler(0, libc::SCHED_FIFO, &param) }
+        }
+    };
+    if rc != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn set_affinity(_cpus: &[usize]) -> std::io::Result<()> {
+    Err(std::io::Error::other(
+        "CPU pinning is only supported on Linux",
+    ))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn process_cpus() -> std::io::Result<Vec<usize>> {
+    Err(std::io::Error::other(
+        "CPU affinity is only supported on Linux",
+    ))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_priority(_priority: Priority) -> std::io::Result<()> {
+    Err(std::io::Error::other(
+        "scheduling priority is only supported on Linux",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_cpu_lists_with_ranges() {
+        assert_eq!(parse_cpu_list("3"), Ok(vec![3]));
+        assert_eq!(parse_cpu_list("0-3,8"), Ok(vec![0, 1, 2, 3, 8]));
+        assert_eq!(parse_cpu_list(" 4 , 6-7 "), Ok(vec![4, 6, 7]));
+        assert!(parse_cpu_list("3-1").is_err());
+        assert!(parse_cpu_list("a").is_err());
+        assert!(parse_cpu_list("").is_err());
+    }
+
+    #[test]
+    fn should_parse_priority_within_range() {
+        assert_eq!("nice:-5".parse(), Ok(Priority::Nice(-5)));
+        assert_eq!("FIFO:10".parse(), Ok(Priority::Fifo(10)));
+        assert!("fifo:0".parse::<Priority>().is_err());
+        assert!("nice:20".parse::<Priority>().is_err());
+        assert!("rr:5".parse::<Priority>().is_err());
+    }
+
+    #[test]
+    fn should_spread_workers_over_remaining_cpus() {
+        let cpus = [4, 5, 6];
+        let assigned: Vec<_> = (0..4).map(|i| worker_cpu(&cpus, i)).collect();
+        assert_eq!(assigned, [Some(5), Some(6), Some(5), Some(6)]);
+        assert_eq!(worker_cpu(&[2], 7), Some(2));
+        assert_eq!(worker_cpu(&[], 0), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_pin_thread_to_allowed_cpu() {
+        let cpu = allowed_cpus().unwrap()[0];
+        let pinned = std::thread::spawn(move || {
+            let scheduling = apply(&[cpu], None);
+            (scheduling, allowed_cpus().unwrap())
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(pinned.0.benchmark_cpu, Some(cpu));
+        assert!(pinned.0.warnings.is_empty());
+        assert_eq!(pinned.1, [cpu]);
+    }
+}
@@ -0,0 +1,211 @@
+//! Boolean selection expressions over benchmark tags and groups.
+//!
+//! Grammar (lowest to highest precedence):
+//!
+//! ```text
+//! expr   := or
+//! or     := and ( "||" and )*
+//! and    := not ( "&&" not )*
+//! not    := "!" not | atom
+//! atom   := "(" expr ")" | "tag:" NAME | "group:" NAME | NAME
+//! ```
+//!
+//! A bare `NAME` matches a benchmark that has that tag or belongs to that
+//! group. Names are compared case-insensitively.
+
+use std::fmt;
+
+/// A parsed `--select` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Selector {
+    /// Matches a tag or the group
+    Name(String),
+    /// Matches a tag only
+    Tag(String),
+    /// Matches the group only
+    Group(String),
+    Not(Box<Selector>),
+    And(Box<Selector>, Box<Selector>),
+    Or(Box<Selector>, Box<Selector>),
+}
+
+/// Error produced when a selection expression cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SelectError {
+    /// Byte offset in the expression where parsing failed
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SelectError {}
+
+impl Selector {
+    /// Parse a selection expression such as `disk && !slow`.
+    pub fn parse(expr: &str) -> Result<Self, SelectError> {
+        let mut parser = Parser {
+            input: expr,
+            pos: 0,
+        };
+        let selector = parser.parse_or()?;
+        parser.skip_whitespace();
+        if parser.pos < expr.len() {
+            return Err(parser.error("unexpected input"));
+        }
+        Ok(selector)
+    }
+
+    /// Evaluate the expression against a benchmark's tags and group.
+    pub fn matches(&self, tags: &[&str], group: Option<&str>) -> bool {
+        let has_tag = |name: &str| tags.iter().any(|t| t.eq_ignore_ascii_case(name));
+        let in_group = |name: &str| group.is_some_and(|g| g.eq_ignore_ascii_case(name));
+
+        match self {
+            Selector::Name(name) => has_tag(name) || in_group(name),
+            Selector::Tag(name) => has_tag(name),
+            Selector::Group(name) => in_group(name),
+            Selector::Not(inner) => !inner.matches(tags, group),
+            Selector::And(a, b) => a.matches(tags, group) && b.matches(tags, group),
+            Selector::Or(a, b) => a.matches(tags, group) || b.matches(tags, group),
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, message: &str) -> SelectError {
+        SelectError {
+            position: self.pos,
+            message: message.to_string(),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Selector, SelectError> {
+        let mut lhs = self.parse_and()?;
+        while self.eat("||") {
+            let rhs = self.parse_and()?;
+            lhs = Selector::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Selector, SelectError> {
+        let mut lhs = self.parse_not()?;
+        while self.eat("&&") {
+            let rhs = self.parse_not()?;
+            lhs = Selector::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_not(&mut self) -> Result<Selector, SelectError> {
+        if self.eat("!") {
+            let inner = self.parse_not()?;
+            return Ok(Selector::Not(Box::new(inner)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<Selector, SelectError> {
+        if self.eat("(") {
+            let inner = self.parse_or()?;
+            if !self.eat(")") {
+                return Err(self.error("expected `)`"));
+            }
+            return Ok(inner);
+        }
+
+        self.skip_whitespace();
+        if self.eat("tag:") {
+            return Ok(Selector::Tag(self.parse_name()?));
+        }
+        if self.eat("group:") {
+            return Ok(Selector::Group(self.parse_name()?));
+        }
+        Ok(Selector::Name(self.parse_name()?))
+    }
+
+    fn parse_name(&mut self) -> Result<String, SelectError> {
+        let len = self
+            .rest()
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a tag or group name"));
+        }
+        let name = self.rest()[..len].to_string();
+        self.pos += len;
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sel(expr: &str) -> Selector {
+        Selector::parse(expr).unwrap()
+    }
+
+    #[test]
+    fn should_match_tag_or_group_by_bare_name() {
+        assert!(sel("disk").matches(&["disk"], None));
+        assert!(sel("io").matches(&[], Some("io")));
+        assert!(!sel("disk").matches(&["net"], Some("io")));
+    }
+
+    #[test]
+    fn should_respect_precedence_and_negation() {
+        let s = sel("disk && !slow || net");
+        assert!(s.matches(&["disk"], None));
+        assert!(!s.matches(&["disk", "slow"], None));
+        assert!(s.matches(&["net", "slow"], None));
+
+        let s = sel("disk && !(slow || net)");
+        assert!(!s.matches(&["disk", "net"], None));
+    }
+
+    #[test]
+    fn should_distinguish_tag_and_group_prefixes() {
+        assert!(sel("group:io").matches(&[], Some("IO")));
+        assert!(!sel("group:io").matches(&["io"], None));
+        assert!(sel("tag:io").matches(&["io"], None));
+        assert!(!sel("tag:io").matches(&[], Some("io")));
+    }
+
+    #[test]
+    fn should_report_position_of_parse_errors() {
+        assert_eq!(Selector::parse("disk &&").unwrap_err().position, 7);
+        assert_eq!(Selector::parse("(disk").unwrap_err().position, 5);
+        assert!(Selector::parse("disk slow").is_err());
+        assert!(Selector::parse("").is_err());
+    }
+}
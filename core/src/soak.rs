@@ -0,0 +1,474 @@
+//! Soak mode: run benchmarks for hours and watch for drift.
+//!
+//! With a soak duration, [`BenchRunner::run_all`](crate::BenchRunner::run_all)
+//! round-robins measured runs across the selected benchmarks until the
+//! duration has elapsed. Every run is appended to a JSONL file as one
+//! [`SoakSample`] (run duration, process RSS and the run's
+//! [`metric`](crate::StressContext::metric)s), so a soak that dies halfway
+//! still leaves its data behind.
+//!
+//! Drift is the growth of a Theil-Sen trend line over the soak, relative to
+//! the median: `0.1` means run duration (or RSS) crept up by 10% from start
+//! to end. Theil-Sen takes the median of pairwise slopes, so a few GC pauses
+//! or compactions do not register as a trend. A benchmark whose drift
+//! exceeds [`SoakConfig::max_drift`] fails the suite.
+
+use crate::result::duration_serde;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Trend points kept per series; older points are averaged pairwise beyond this.
+const MAX_TREND_POINTS: usize = 1024;
+
+/// Fewer points than this are too few to call a trend.
+const MIN_TREND_POINTS: usize = 10;
+
+/// How long to soak and how much drift to tolerate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoakConfig {
+    /// Wall-clock time to keep running benchmarks.
+    pub duration: Duration,
+    /// JSONL file samples are appended to
+    /// (default: `{output_dir}/{suite}/soak.jsonl`).
+    pub output: Option<PathBuf>,
+    /// Largest tolerated drift of run duration or RSS (default: 0.1, i.e. 10%).
+    pub max_drift: f64,
+}
+
+impl SoakConfig {
+    /// Soak for `duration` with the default drift limit.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            output: None,
+            max_drift: 0.1,
+        }
+    }
+
+    /// Append samples to `path` instead of the default file.
+    pub fn output(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = Some(path.into());
+        self
+    }
+
+    /// Set the largest tolerated drift (0.1 = 10% growth over the soak).
+    pub fn max_drift(mut self, max_drift: f64) -> Self {
+        self.max_drift = max_drift;
+        self
+    }
+}
+
+/// One measured run, as written to the soak JSONL file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoakSample {
+    /// Start of the soak (unix milliseconds), shared by all its samples
+    pub session: String,
+    /// Full benchmark name: "suite/benchmark"
+    pub bench: String,
+    /// Measured run index, starting at 0
+    pub iteration: u64,
+    /// Time since the soak started
+    #[serde(with = "duration_serde")]
+    pub elapsed: Duration,
+    /// Measured duration of this run
+    #[serde(with = "duration_serde")]
+    pub duration: Duration,
+    /// Resident set size of the process after the run, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss: Option<u64>,
+    /// Metrics reported with `ctx.metric()` during this run
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, f64>,
+}
+
+/// Outcome of soaking one benchmark, recorded in
+/// [`BenchResult::soak`](crate::BenchResult::soak).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoakSummary {
+    /// Time from the start of the soak to this benchmark's last run
+    #[serde(with = "duration_serde")]
+    pub elapsed: Duration,
+    /// Measured runs
+    pub iterations: u64,
+    /// Relative growth of run duration over the soak (None if too few runs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_drift: Option<f64>,
+    /// Relative growth of RSS over the soak (None if too few runs or unavailable)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss_drift: Option<f64>,
+    /// Drift limit the benchmark was held to
+    pub max_drift: f64,
+    /// Whether duration or RSS drifted by more than `max_drift`
+    pub drift_exceeded: bool,
+    /// JSONL file the samples were appended to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub samples: Option<PathBuf>,
+}
+
+/// Parse a duration such as `90`, `90s`, `30m`, `8h` or `1h30m`
+/// (a bare number is seconds).
+pub(crate) fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration '{}' (expected e.g. 90s, 30m or 8h)", s);
+    let s = s.trim();
+    let is_number = |c: char| c.is_ascii_digit() || c == '.';
+
+    let secs = if let Ok(secs) = s.parse::<f64>() {
+        secs
+    } else {
+        let mut total = 0.0;
+        let mut rest = s;
+        while !rest.is_empty() {
+            let unit_start = rest.find(|c| !is_number(c)).ok_or_else(invalid)?;
+            let value: f64 = rest[..unit_start].parse().map_err(|_| invalid())?;
+            rest = &rest[unit_start..];
+            let unit_end = rest.find(is_number).unwrap_or(rest.len());
+            let scale = match &rest[..unit_end] {
+                "ms" => 0.001,
+                "s" => 1.0,
+                "m" => 60.0,
+                "h" => 3600.0,
+                "d" => 86400.0,
+                _ => return Err(invalid()),
+            };
+            total += value * scale;
+            rest = &rest[unit_end..];
+        }
+        total
+    };
+
+    if !secs.is_finite() || secs <= 0.0 {
+        return Err(invalid());
+    }
+    Duration::try_from_secs_f64(secs).map_err(|_| invalid())
+}
+
+/// Theil-Sen estimate of the slope of `points`: the median of the slopes
+/// between all pairs. `None` without two distinct x values.
+pub(crate) fn theil_sen_slope(points: &[(f64, f64)]) -> Option<f64> {
+    let mut slopes = Vec::with_capacity(points.len() * points.len().saturating_sub(1) / 2);
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        for &(x2, y2) in &points[i + 1..] {
+            if x2 != x1 {
+                slopes.push((y2 - y1) / (x2 - x1));
+            }
+        }
+    }
+    median(&mut slopes)
+}
+
+/// Growth of the trend of `points` over their x span, relative to the median y.
+fn drift(points: &[(f64, f64)]) -> Option<f64> {
+    if points.len() < MIN_TREND_POINTS {
+        return None;
+    }
+    let slope = theil_sen_slope(points)?;
+    let span = points[points.len() - 1].0 - points[0].0;
+    let mut ys: Vec<f64> = points.iter().map(|&(_, y)| y).collect();
+    let level = median(&mut ys).filter(|&level| level > 0.0)?;
+    Some(slope * span / level)
+}
+
+// `usize::is_multiple_of` is newer than the supported Rust version
+#[allow(clippy::manual_is_multiple_of)]
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Time series with bounded memory: once full, adjacent points are averaged
+/// pairwise and later points are averaged over twice as many samples.
+#[derive(Debug, Default)]
+struct Trend {
+    points: Vec<(f64, f64)>,
+    /// Samples averaged into each new point
+    stride: usize,
+    /// Sum of x, sum of y and count of samples not yet in `points`
+    pending: (f64, f64, usize),
+}
+
+impl Trend {
+    fn new() -> Self {
+        Self {
+            stride: 1,
+            ..Default::default()
+        }
+    }
+
+    fn push(&mut self, x: f64, y: f64) {
+        self.pending = (self.pending.0 + x, self.pending.1 + y, self.pending.2 + 1);
+        let (sx, sy, n) = self.pending;
+        if n < self.stride {
+            return;
+        }
+        self.points.push((sx / n as f64, sy / n as f64));
+        self.pending = (0.0, 0.0, 0);
+
+        if self.points.len() >= MAX_TREND_POINTS {
+            self.points = self
+                .points
+                .chunks(2)
+                .map(|pair| {
+                    let n = pair.len() as f64;
+                    let x = pair.iter().map(|p| p.0).sum::<f64>() / n;
+                    let y = pair.iter().map(|p| p.1).sum::<f64>() / n;
+                    (x, y)
+                })
+                .collect();
+            self.stride *= 2;
+        }
+    }
+}
+
+/// Soak state of one benchmark.
+pub(crate) struct SoakTracker {
+    session: String,
+    bench: String,
+    start: Instant,
+    max_drift: f64,
+    samples: Option<PathBuf>,
+    iterations: u64,
+    elapsed: Duration,
+    durations: Trend,
+    rss: Trend,
+}
+
+impl SoakTracker {
+    pub(crate) fn new(
+        session: &str,
+        bench: String,
+        start: Instant,
+        soak: &SoakConfig,
+        samples: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            session: session.to_string(),
+            bench,
+            start,
+            max_drift: soak.max_drift,
+            samples,
+            iterations: 0,
+            elapsed: Duration::ZERO,
+            durations: Trend::new(),
+            rss: Trend::new(),
+        }
+    }
+
+    pub(crate) fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Record a measured run and return its sample.
+    pub(crate) fn record(
+        &mut self,
+        duration: Duration,
+        metrics: &BTreeMap<String, f64>,
+    ) -> SoakSample {
+        let elapsed = self.start.elapsed();
+        let rss = current_rss();
+        let x = elapsed.as_secs_f64();
+        self.durations.push(x, duration.as_nanos() as f64);
+        if let Some(rss) = rss {
+            self.rss.push(x, rss as f64);
+        }
+
+        let sample = SoakSample {
+            session: self.session.clone(),
+            bench: self.bench.clone(),
+            iteration: self.iterations,
+            elapsed,
+            duration,
+            rss,
+            metrics: metrics.clone(),
+        };
+        self.iterations += 1;
+        self.elapsed = elapsed;
+        sample
+    }
+
+    /// Run durations for the result: one per run, or bucket averages once
+    /// the soak outgrew the trend buffer.
+    pub(crate) fn durations(&self) -> Vec<Duration> {
+        self.durations
+            .points
+            .iter()
+            .map(|&(_, nanos)| Duration::from_nanos(nanos as u64))
+            .collect()
+    }
+
+    pub(crate) fn summary(&self) -> SoakSummary {
+        let duration_drift = drift(&self.durations.points);
+        let rss_drift = drift(&self.rss.points);
+        let drift_exceeded = [duration_drift, rss_drift]
+            .iter()
+            .flatten()
+            .any(|&d| d > self.max_drift);
+        SoakSummary {
+            elapsed: self.elapsed,
+            iterations: self.iterations,
+            duration_drift,
+            rss_drift,
+            max_drift: self.max_drift,
+            drift_exceeded,
+            samples: self.samples.clone(),
+        }
+    }
+}
+
+/// Append-only JSONL file of soak samples.
+pub(crate) struct SoakLog {
+    path: PathBuf,
+    file: BufWriter<File>,
+}
+
+impl SoakLog {
+    pub(crate) fn open(path: &Path) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: BufWriter::new(file),
+        })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write one sample line; flushed right away so a crash loses nothing.
+    pub(crate) fn append(&mut self, sample: &SoakSample) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.file, sample).map_err(std::io::Error::other)?;
+        self.file.write_all(b"\n")?;
+        self.file.flush()
+    }
+}
+
+/// Resident set size of this process in bytes (Linux only).
+#[cfg(target_os = "linux")]
+pub(crate) fn current_rss() -> Option<u64> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    // SAFETY: sysconf has no preconditions
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    u64::try_from(page_size).ok().map(|size| pages * size)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn current_rss() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_durations_with_units() {
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(1800)));
+        assert_eq!(parse_duration("8h"), Ok(Duration::from_secs(8 * 3600)));
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5h"), Ok(Duration::from_secs(5400)));
+        assert!(parse_duration("0").is_err());
+        assert!(parse_duration("8x").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("1e20").is_err());
+        assert!(parse_duration("99999999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn should_ignore_outliers_when_estimating_slope() {
+        let mut points: Vec<_> = (0..20).map(|x| (x as f64, 2.0 * x as f64 + 1.0)).collect();
+        points[5].1 = 1000.0;
+        points[15].1 = -1000.0;
+        assert_eq!(theil_sen_slope(&points), Some(2.0));
+        assert_eq!(theil_sen_slope(&[(1.0, 1.0), (1.0, 5.0)]), None);
+    }
+
+    #[test]
+    fn should_report_drift_relative_to_median() {
+        // 100 -> 120 over the soak: +20% of the median (110)
+        let growing: Vec<_> = (0..=20).map(|x| (x as f64, 100.0 + x as f64)).collect();
+        let drift = drift(&growing).unwrap();
+        assert!((drift - 20.0 / 110.0).abs() < 1e-9);
+
+        let flat: Vec<_> = (0..=20).map(|x| (x as f64, 100.0)).collect();
+        assert_eq!(super::drift(&flat), Some(0.0));
+        assert_eq!(super::drift(&flat[..5]), None);
+    }
+
+    #[test]
+    fn should_bound_trend_memory_and_keep_slope() {
+        let mut trend = Trend::new();
+        for i in 0..10_000 {
+            trend.push(i as f64, 3.0 * i as f64);
+        }
+        assert!(trend.points.len() < MAX_TREND_POINTS);
+        assert!(trend.stride > 1);
+        let slope = theil_sen_slope(&trend.points).unwrap();
+        assert!((slope - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_flag_drift_above_limit() {
+        let soak = SoakConfig::new(Duration::from_secs(1)).max_drift(0.05);
+        let mut tracker = SoakTracker::new("0", "s/b".into(), Instant::now(), &soak, None);
+        for i in 0..20 {
+            tracker.record(Duration::from_micros(100 + 10 * i), &BTreeMap::new());
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        let summary = tracker.summary();
+        assert_eq!(summary.iterations, 20);
+        assert!(summary.duration_drift.unwrap() > 0.05);
+        assert!(summary.drift_exceeded);
+        assert_eq!(tracker.durations().len(), 20);
+    }
+
+    #[test]
+    fn should_append_samples_as_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/soak.jsonl");
+        let soak = SoakConfig::new(Duration::from_secs(1));
+        let mut tracker = SoakTracker::new("42", "s/b".into(), Instant::now(), &soak, None);
+        let metrics = BTreeMap::from([("queue_depth".to_string(), 3.0)]);
+
+        for _ in 0..2 {
+            let mut log = SoakLog::open(&path).unwrap();
+            log.append(&tracker.record(Duration::from_millis(1), &metrics))
+                .unwrap();
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let samples: Vec<SoakSample> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].iteration, 1);
+        assert_eq!(samples[1].metrics["queue_depth"], 3.0);
+        assert_eq!(samples[0].session, "42");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_read_rss_of_current_process() {
+        assert!(current_rss().is_some_and(|rss| rss > 0));
+    }
+}
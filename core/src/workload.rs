@@ -0,0 +1,735 @@
+//! Workload data generators: key distributions, value payloads and operation mixes.
+//!
+//! All generators draw from a [`StressRng`], normally the benchmark's
+//! [`StressContext::rng`](crate::StressContext::rng), so a given suite seed
+//! always produces the same keys, values and operations.
+//!
+//! ```rust,no_run
+//! use cntryl_stress::workload::{Operation, Workload, YcsbPreset};
+//! use cntryl_stress::StressContext;
+//!
+//! fn ycsb_a(ctx: &mut StressContext) {
+//!     let mut workload = Workload::ycsb(YcsbPreset::A, 100_000);
+//!     let ops: Vec<_> = (0..10_000).map(|_| workload.next(ctx.rng())).collect();
+//!     ctx.set_elements(ops.len() as u64);
+//!     ctx.measure(|| {
+//!         for op in &ops {
+//!             match op.operation {
+//!                 Operation::Read => { /* db.get(op.key) */ }
+//!                 _ => { /* ... */ }
+//!             }
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! The distributions follow YCSB (Cooper et al., "Benchmarking Cloud Serving
+//! Systems with YCSB", SoCC 2010).
+
+use crate::rng::StressRng;
+
+/// YCSB's default Zipfian constant.
+pub const ZIPFIAN_CONSTANT: f64 = 0.99;
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// How keys are picked from `0..key_count`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyDistribution {
+    /// Every key is equally likely.
+    Uniform,
+    /// Popularity follows a Zipfian law with exponent `theta`; key 0 is the
+    /// most popular. Use [`KeyDistribution::ScrambledZipfian`] to spread the
+    /// hot keys across the key space.
+    Zipfian { theta: f64 },
+    /// Zipfian popularity with ranks hashed over the key space, so hot keys
+    /// are not clustered at the start.
+    ScrambledZipfian { theta: f64 },
+    /// Recently inserted (highest) keys are the most popular.
+    Latest { theta: f64 },
+    /// `hot_op_fraction` of accesses go to the first `hot_set_fraction` of
+    /// the key space; the rest are uniform over the cold keys.
+    Hotspot {
+        hot_set_fraction: f64,
+        hot_op_fraction: f64,
+    },
+    /// Keys in order, wrapping around at `key_count`.
+    Sequential,
+}
+
+/// Generates keys in `0..key_count` following a [`KeyDistribution`].
+#[derive(Debug, Clone)]
+pub struct KeyGenerator {
+    distribution: KeyDistribution,
+    key_count: u64,
+    next_sequential: u64,
+    zipf: Option<Zipf>,
+}
+
+impl KeyGenerator {
+    /// Create a generator over `key_count` keys.
+    ///
+    /// Zipfian-based distributions precompute a normalisation constant in
+    /// O(`key_count`) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_count` is zero or a distribution parameter is out of range.
+    pub fn new(distribution: KeyDistribution, key_count: u64) -> Self {
+        assert!(key_count > 0, "key_count must be greater than zero");
+        let zipf = match distribution {
+            KeyDistribution::Zipfian { theta }
+            | KeyDistribution::ScrambledZipfian { theta }
+            | KeyDistribution::Latest { theta } => Some(Zipf::new(key_count, theta)),
+            KeyDistribution::Hotspot {
+                hot_set_fraction,
+                hot_op_fraction,
+            } => {
+                assert!(
+                    (0.0..=1.0).contains(&hot_set_fraction)
+                        && (0.0..=1.0).contains(&hot_op_fraction),
+                    "hotspot fractions must be within 0.0..=1.0"
+                );
+                None
+            }
+            KeyDistribution::Uniform | KeyDistribution::Sequential => None,
+        };
+
+        Self {
+            distribution,
+            key_count,
+            next_sequential: 0,
+            zipf,
+        }
+    }
+
+    /// Uniformly distributed keys.
+    pub fn uniform(key_count: u64) -> Self {
+        Self::new(KeyDistribution::Uniform, key_count)
+    }
+
+    /// Zipfian keys with YCSB's default constant (0.99).
+    pub fn zipfian(key_count: u64) -> Self {
+        Self::new(
+            KeyDistribution::Zipfian {
+                theta: ZIPFIAN_CONSTANT,
+            },
+            key_count,
+        )
+    }
+
+    /// Zipfian keys with hot keys scattered over the key space.
+    pub fn scrambled_zipfian(key_count: u64) -> Self {
+        Self::new(
+            KeyDistribution::ScrambledZipfian {
+                theta: ZIPFIAN_CONSTANT,
+            },
+            key_count,
+        )
+    }
+
+    /// Keys skewed towards the most recently inserted.
+    pub fn latest(key_count: u64) -> Self {
+        Self::new(
+            KeyDistribution::Latest {
+                theta: ZIPFIAN_CONSTANT,
+            },
+            key_count,
+        )
+    }
+
+    /// `hot_op_fraction` of accesses hit the first `hot_set_fraction` of keys.
+    pub fn hotspot(key_count: u64, hot_set_fraction: f64, hot_op_fraction: f64) -> Self {
+        Self::new(
+            KeyDistribution::Hotspot {
+                hot_set_fraction,
+                hot_op_fraction,
+            },
+            key_count,
+        )
+    }
+
+    /// Keys `0, 1, 2, ...` wrapping at `key_count`.
+    pub fn sequential(key_count: u64) -> Self {
+        Self::new(KeyDistribution::Sequential, key_count)
+    }
+
+    /// The distribution keys are drawn from.
+    pub fn distribution(&self) -> KeyDistribution {
+        self.distribution
+    }
+
+    /// Number of keys in the key space.
+    pub fn key_count(&self) -> u64 {
+        self.key_count
+    }
+
+    /// Extend the key space, e.g. after inserts.
+    ///
+    /// Shrinking is ignored. Zipfian constants are updated incrementally.
+    pub fn set_key_count(&mut self, key_count: u64) {
+        if key_count <= self.key_count {
+            return;
+        }
+        self.key_count = key_count;
+        if let Some(zipf) = &mut self.zipf {
+            zipf.grow(key_count);
+        }
+    }
+
+    /// Draw the next key.
+    pub fn next(&mut self, rng: &mut StressRng) -> u64 {
+        let n = self.key_count;
+        match self.distribution {
+            KeyDistribution::Uniform => rng.gen_range(0..n),
+            KeyDistribution::Zipfian { .. } => self.zipf_rank(rng),
+            KeyDistribution::ScrambledZipfian { .. } => fnv1a_u64(self.zipf_rank(rng)) % n,
+            KeyDistribution::Latest { .. } => n - 1 - self.zipf_rank(rng),
+            KeyDistribution::Hotspot {
+                hot_set_fraction,
+                hot_op_fraction,
+            } => {
+                let hot = ((n as f64 * hot_set_fraction) as u64).min(n);
+                if hot > 0 && (hot == n || rng.next_f64() < hot_op_fraction) {
+                    rng.gen_range(0..hot)
+                } else {
+                    rng.gen_range(hot..n)
+                }
+            }
+            KeyDistribution::Sequential => {
+                let key = self.next_sequential % n;
+                self.next_sequential = key + 1;
+                key
+            }
+        }
+    }
+
+    fn zipf_rank(&self, rng: &mut StressRng) -> u64 {
+        match &self.zipf {
+            Some(zipf) => zipf.sample(rng),
+            None => 0,
+        }
+    }
+}
+
+/// Zipfian sampler over ranks `0..n` (Gray et al., "Quickly Generating
+/// Billion-Record Synthetic Databases", SIGMOD 1994), as used by YCSB.
+#[derive(Debug, Clone)]
+struct Zipf {
+    n: u64,
+    theta: f64,
+    alpha: f64,
+    zeta_n: f64,
+    zeta_2: f64,
+    eta: f64,
+}
+
+impl Zipf {
+    fn new(n: u64, theta: f64) -> Self {
+        assert!(
+            theta > 0.0 && theta < 1.0,
+            "zipfian theta must be within (0.0, 1.0)"
+        );
+        let zeta_2 = zeta(0, 2, theta, 0.0);
+        let mut zipf = Self {
+            n: 0,
+            theta,
+            alpha: 1.0 / (1.0 - theta),
+            zeta_n: 0.0,
+            zeta_2,
+            eta: 0.0,
+        };
+        zipf.grow(n);
+        zipf
+    }
+
+    fn grow(&mut self, n: u64) {
+        self.zeta_n = zeta(self.n, n, self.theta, self.zeta_n);
+        self.n = n;
+        self.eta =
+            (1.0 - (2.0 / n as f64).powf(1.0 - self.theta)) / (1.0 - self.zeta_2 / self.zeta_n);
+    }
+
+    fn sample(&self, rng: &mut StressRng) -> u64 {
+        let u = rng.next_f64();
+        let uz = u * self.zeta_n;
+        if uz < 1.0 {
+            return 0;
+        }
+        if uz < 1.0 + 0.5f64.powf(self.theta) {
+            return 1.min(self.n - 1);
+        }
+        let rank = (self.n as f64 * (self.eta * u - self.eta + 1.0).powf(self.alpha)) as u64;
+        rank.min(self.n - 1)
+    }
+}
+
+/// Sum of `1 / i^theta` for `i` in `from+1..=to`, added to `initial`.
+fn zeta(from: u64, to: u64, theta: f64, initial: f64) -> f64 {
+    (from..to).fold(initial, |sum, i| sum + 1.0 / ((i + 1) as f64).powf(theta))
+}
+
+/// FNV-1a over the little-endian bytes of `value`.
+fn fnv1a_u64(value: u64) -> u64 {
+    value
+        .to_le_bytes()
+        .iter()
+        .fold(0xCBF2_9CE4_8422_2325, |hash, &b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+}
+
+// ============================================================================
+// Values
+// ============================================================================
+
+/// Distribution of generated value sizes in bytes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SizeDistribution {
+    /// Always `size` bytes.
+    Fixed(usize),
+    /// Uniform in `min..=max`.
+    Uniform { min: usize, max: usize },
+    /// Normal with the given mean and standard deviation, clamped to `min..=max`.
+    Normal {
+        mean: f64,
+        std_dev: f64,
+        min: usize,
+        max: usize,
+    },
+}
+
+impl SizeDistribution {
+    fn sample(&self, rng: &mut StressRng) -> usize {
+        match *self {
+            SizeDistribution::Fixed(size) => size,
+            SizeDistribution::Uniform { min, max } => {
+                rng.gen_range(min as u64..max as u64 + 1) as usize
+            }
+            SizeDistribution::Normal {
+                mean,
+                std_dev,
+                min,
+                max,
+            } => {
+                // Box-Muller; 1 - u keeps the logarithm finite
+                let u1 = 1.0 - rng.next_f64();
+                let u2 = rng.next_f64();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                ((mean + z * std_dev).round().max(0.0) as usize).clamp(min, max)
+            }
+        }
+    }
+}
+
+/// Generates value payloads with a given size distribution and compressibility.
+///
+/// Payloads are built like `db_bench`'s: a random chunk of
+/// `size * compression_ratio` bytes repeated to fill the value, so a
+/// compressor shrinks them to roughly `compression_ratio` of their size.
+#[derive(Debug, Clone)]
+pub struct ValueGenerator {
+    size: SizeDistribution,
+    compression_ratio: f64,
+}
+
+impl ValueGenerator {
+    /// Incompressible values with sizes from `size`.
+    pub fn new(size: SizeDistribution) -> Self {
+        Self {
+            size,
+            compression_ratio: 1.0,
+        }
+    }
+
+    /// Incompressible values of exactly `size` bytes.
+    pub fn fixed(size: usize) -> Self {
+        Self::new(SizeDistribution::Fixed(size))
+    }
+
+    /// Target compressed size as a fraction of the original (0.5 = 2:1).
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0.0 < ratio <= 1.0`.
+    pub fn compression_ratio(mut self, ratio: f64) -> Self {
+        assert!(
+            ratio > 0.0 && ratio <= 1.0,
+            "compression_ratio must be within (0.0, 1.0]"
+        );
+        self.compression_ratio = ratio;
+        self
+    }
+
+    /// Generate the next value.
+    pub fn next(&self, rng: &mut StressRng) -> Vec<u8> {
+        let mut value = Vec::new();
+        self.fill(rng, &mut value);
+        value
+    }
+
+    /// Generate the next value into `buf`, reusing its allocation.
+    pub fn fill(&self, rng: &mut StressRng, buf: &mut Vec<u8>) {
+        let size = self.size.sample(rng);
+        buf.clear();
+        buf.resize(size, 0);
+        if size == 0 {
+            return;
+        }
+
+        let unique = ((size as f64 * self.compression_ratio).ceil() as usize).clamp(1, size);
+        rng.fill_bytes(&mut buf[..unique]);
+        for i in unique..size {
+            buf[i] = buf[i % unique];
+        }
+    }
+}
+
+// ============================================================================
+// Operation mixes
+// ============================================================================
+
+/// A single operation in a mixed workload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Read,
+    Update,
+    /// Insert a new key (always the next key past the current key space)
+    Insert,
+    /// Range scan of `len` records starting at the key
+    Scan {
+        len: usize,
+    },
+    ReadModifyWrite,
+}
+
+/// Relative weights of each operation kind.
+///
+/// Weights need not sum to one; they are normalised when sampling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OperationMix {
+    pub read: f64,
+    pub update: f64,
+    pub insert: f64,
+    pub scan: f64,
+    pub read_modify_write: f64,
+    /// Scan lengths are uniform in `1..=max_scan_len`
+    pub max_scan_len: usize,
+}
+
+impl Default for OperationMix {
+    fn default() -> Self {
+        Self {
+            read: 1.0,
+            update: 0.0,
+            insert: 0.0,
+            scan: 0.0,
+            read_modify_write: 0.0,
+            max_scan_len: 100,
+        }
+    }
+}
+
+impl OperationMix {
+    /// Read-only mix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn read(mut self, weight: f64) -> Self {
+        self.read = weight;
+        self
+    }
+
+    pub fn update(mut self, weight: f64) -> Self {
+        self.update = weight;
+        self
+    }
+
+    pub fn insert(mut self, weight: f64) -> Self {
+        self.insert = weight;
+        self
+    }
+
+    pub fn scan(mut self, weight: f64) -> Self {
+        self.scan = weight;
+        self
+    }
+
+    pub fn read_modify_write(mut self, weight: f64) -> Self {
+        self.read_modify_write = weight;
+        self
+    }
+
+    pub fn max_scan_len(mut self, len: usize) -> Self {
+        self.max_scan_len = len;
+        self
+    }
+
+    /// Draw the next operation.
+    pub fn next(&self, rng: &mut StressRng) -> Operation {
+        let total = self.read + self.update + self.insert + self.scan + self.read_modify_write;
+        let mut x = rng.next_f64() * total;
+
+        for (weight, op) in [
+            (self.read, Operation::Read),
+            (self.update, Operation::Update),
+            (self.insert, Operation::Insert),
+            (self.scan, Operation::Scan { len: 0 }),
+        ] {
+            if x < weight {
+                return match op {
+                    Operation::Scan { .. } => Operation::Scan {
+                        len: rng.gen_range(1..self.max_scan_len.max(1) as u64 + 1) as usize,
+                    },
+                    op => op,
+                };
+            }
+            x -= weight;
+        }
+        Operation::ReadModifyWrite
+    }
+}
+
+/// The standard YCSB core workloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YcsbPreset {
+    /// Update heavy: 50% read, 50% update, zipfian
+    A,
+    /// Read mostly: 95% read, 5% update, zipfian
+    B,
+    /// Read only: 100% read, zipfian
+    C,
+    /// Read latest: 95% read, 5% insert, latest
+    D,
+    /// Short ranges: 95% scan, 5% insert, zipfian
+    E,
+    /// Read-modify-write: 50% read, 50% read-modify-write, zipfian
+    F,
+}
+
+impl YcsbPreset {
+    /// Operation mix of this preset.
+    pub fn mix(self) -> OperationMix {
+        let mix = OperationMix::new().read(0.0);
+        match self {
+            YcsbPreset::A => mix.read(0.5).update(0.5),
+            YcsbPreset::B => mix.read(0.95).update(0.05),
+            YcsbPreset::C => mix.read(1.0),
+            YcsbPreset::D => mix.read(0.95).insert(0.05),
+            YcsbPreset::E => mix.scan(0.95).insert(0.05).max_scan_len(100),
+            YcsbPreset::F => mix.read(0.5).read_modify_write(0.5),
+        }
+    }
+
+    /// Request distribution of this preset over `record_count` keys.
+    pub fn keys(self, record_count: u64) -> KeyGenerator {
+        match self {
+            YcsbPreset::D => KeyGenerator::latest(record_count),
+            _ => KeyGenerator::scrambled_zipfian(record_count),
+        }
+    }
+}
+
+impl std::str::FromStr for YcsbPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim_start_matches("ycsb-") {
+            "a" => Ok(YcsbPreset::A),
+            "b" => Ok(YcsbPreset::B),
+            "c" => Ok(YcsbPreset::C),
+            "d" => Ok(YcsbPreset::D),
+            "e" => Ok(YcsbPreset::E),
+            "f" => Ok(YcsbPreset::F),
+            other => Err(format!("unknown YCSB workload '{}' (expected a-f)", other)),
+        }
+    }
+}
+
+/// An operation together with the key it targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Op {
+    pub operation: Operation,
+    pub key: u64,
+}
+
+/// An operation mix combined with a key distribution.
+///
+/// Inserts target the next unused key and grow the key space, so
+/// `Latest` distributions follow them.
+#[derive(Debug, Clone)]
+pub struct Workload {
+    keys: KeyGenerator,
+    mix: OperationMix,
+}
+
+impl Workload {
+    /// Combine a key generator with an operation mix.
+    pub fn new(keys: KeyGenerator, mix: OperationMix) -> Self {
+        Self { keys, mix }
+    }
+
+    /// A YCSB core workload over `record_count` preloaded records.
+    pub fn ycsb(preset: YcsbPreset, record_count: u64) -> Self {
+        Self::new(preset.keys(record_count), preset.mix())
+    }
+
+    /// Number of keys, including those inserted so far.
+    pub fn key_count(&self) -> u64 {
+        self.keys.key_count()
+    }
+
+    /// Draw the next operation and its key.
+    pub fn next(&mut self, rng: &mut StressRng) -> Op {
+        let operation = self.mix.next(rng);
+        let key = if operation == Operation::Insert {
+            let key = self.keys.key_count();
+            self.keys.set_key_count(key + 1);
+            key
+        } else {
+            self.keys.next(rng)
+        };
+        Op { operation, key }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram(keys: &mut KeyGenerator, draws: usize) -> Vec<usize> {
+        let mut rng = StressRng::new(1);
+        let mut counts = vec![0; keys.key_count() as usize];
+        for _ in 0..draws {
+            counts[keys.next(&mut rng) as usize] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn should_reproduce_keys_from_same_seed() {
+        let draw = || {
+            let mut rng = StressRng::new(9);
+            let mut keys = KeyGenerator::scrambled_zipfian(1000);
+            (0..100).map(|_| keys.next(&mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(), draw());
+    }
+
+    #[test]
+    fn should_favour_low_ranks_when_zipfian() {
+        let counts = histogram(&mut KeyGenerator::zipfian(100), 20_000);
+        assert!(counts[0] > counts[1]);
+        assert!(counts[1] > counts[50]);
+        // Key 0 alone gets a large share under theta = 0.99
+        assert!(counts[0] > 20_000 / 10);
+    }
+
+    #[test]
+    fn should_favour_highest_keys_when_latest() {
+        let counts = histogram(&mut KeyGenerator::latest(100), 20_000);
+        assert!(counts[99] > counts[0] * 10);
+    }
+
+    #[test]
+    fn should_send_hot_fraction_to_hot_set() {
+        let counts = histogram(&mut KeyGenerator::hotspot(100, 0.2, 0.8), 20_000);
+        let hot: usize = counts[..20].iter().sum();
+        assert!((15_000..17_000).contains(&hot), "hot = {}", hot);
+    }
+
+    #[test]
+    fn should_wrap_sequential_keys() {
+        let mut rng = StressRng::new(0);
+        let mut keys = KeyGenerator::sequential(3);
+        let drawn: Vec<_> = (0..5).map(|_| keys.next(&mut rng)).collect();
+        assert_eq!(drawn, [0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn should_cover_whole_range_when_uniform() {
+        let counts = histogram(&mut KeyGenerator::uniform(10), 10_000);
+        assert!(counts.iter().all(|&c| (800..1200).contains(&c)));
+    }
+
+    #[test]
+    fn should_keep_zipf_keys_in_range_after_growth() {
+        let mut rng = StressRng::new(4);
+        let mut keys = KeyGenerator::latest(10);
+        keys.set_key_count(1000);
+        assert!((0..1000).all(|_| keys.next(&mut rng) < 1000));
+    }
+
+    #[test]
+    fn should_generate_value_sizes_within_bounds() {
+        let mut rng = StressRng::new(2);
+        let uniform = ValueGenerator::new(SizeDistribution::Uniform { min: 10, max: 20 });
+        assert!((0..100).all(|_| (10..=20).contains(&uniform.next(&mut rng).len())));
+
+        let normal = ValueGenerator::new(SizeDistribution::Normal {
+            mean: 100.0,
+            std_dev: 50.0,
+            min: 64,
+            max: 128,
+        });
+        assert!((0..100).all(|_| (64..=128).contains(&normal.next(&mut rng).len())));
+    }
+
+    #[test]
+    fn should_repeat_payload_for_compression_ratio() {
+        let mut rng = StressRng::new(3);
+        let value = ValueGenerator::fixed(100)
+            .compression_ratio(0.25)
+            .next(&mut rng);
+        assert_eq!(value.len(), 100);
+        assert_eq!(value[..25], value[25..50]);
+        assert_eq!(value[..25], value[75..]);
+
+        let random = ValueGenerator::fixed(100).next(&mut rng);
+        assert_ne!(random[..25], random[25..50]);
+    }
+
+    #[test]
+    fn should_follow_ycsb_operation_mix() {
+        let mut rng = StressRng::new(5);
+        let mut workload = Workload::ycsb(YcsbPreset::B, 1000);
+        let updates = (0..10_000)
+            .filter(|_| workload.next(&mut rng).operation == Operation::Update)
+            .count();
+        assert!((400..600).contains(&updates), "updates = {}", updates);
+    }
+
+    #[test]
+    fn should_insert_past_key_space() {
+        let mut rng = StressRng::new(6);
+        let mut workload = Workload::ycsb(YcsbPreset::D, 100);
+        for _ in 0..1000 {
+            let op = workload.next(&mut rng);
+            if op.operation == Operation::Insert {
+                assert_eq!(op.key, workload.key_count() - 1);
+            }
+        }
+        assert!(workload.key_count() > 100);
+    }
+
+    #[test]
+    fn should_draw_scan_lengths_in_range() {
+        let mut rng = StressRng::new(7);
+        let mix = YcsbPreset::E.mix();
+        for _ in 0..1000 {
+            if let Operation::Scan { len } = mix.next(&mut rng) {
+                assert!((1..=100).contains(&len));
+            }
+        }
+    }
+
+    #[test]
+    fn should_parse_ycsb_preset() {
+        assert_eq!("ycsb-a".parse::<YcsbPreset>(), Ok(YcsbPreset::A));
+        assert_eq!("F".parse::<YcsbPreset>(), Ok(YcsbPreset::F));
+        assert!("g".parse::<YcsbPreset>().is_err());
+    }
+}
@@ -4,8 +4,13 @@
 //! benchmarks that are automatically discovered and run.
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, ItemFn};
+use quote::{quote, quote_spanned};
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, FnArg, GenericParam, ItemFn, LitInt, LitStr, ReturnType, Signature, Token,
+    Type,
+};
 
 /// Mark a function as a stress benchmark.
 ///
@@ -31,56 +36,367 @@ use syn::{parse_macro_input, ItemFn};
 ///
 /// - `#[stress_test]` - Basic benchmark
 /// - `#[stress_test(ignore)]` - Skip this benchmark unless explicitly requested
+/// - `#[stress_test(ignore = "needs a raw device")]` - Same, with a reason shown by `--list`
 /// - `#[stress_test(name = "custom_name")]` - Use a custom name instead of function name
+/// - `#[stress_test(runs = 3, warmup = 1)]` - Run count for this benchmark (overrides `--runs`/`--warmup`)
+/// - `#[stress_test(timeout = 1200)]` - Fail if a single run takes longer than 1200 seconds
+/// - `#[stress_test(tags("disk", "slow"), group = "io")]` - Tags and group for `--select`
+/// - `#[stress_test(memory = "512M", cpus = 2, open_files = 1024)]` - Resource limits,
+///   applied in an isolated child process (Linux)
+///
+/// The `--force`, `--max-runs`, `--max-warmup` and `--max-timeout` flags of
+/// the stress binary (or `BENCH_FORCE`, `BENCH_MAX_RUNS`, `BENCH_MAX_WARMUP`
+/// and `BENCH_MAX_TIMEOUT_SECS`) can override or cap the runs, warmup and
+/// timeout globally; resource limits always apply.
+///
+/// # Signature
+///
+/// The function must be a free, non-generic, non-async function taking exactly
+/// one `&mut StressContext` argument and returning `()`. Anything else is
+/// rejected at compile time with a diagnostic pointing at the offending part.
 #[proc_macro_attribute]
 pub fn stress_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemFn);
-    let fn_name = &input.sig.ident;
-    let fn_name_str = fn_name.to_string();
 
-    // Parse attributes
-    let attr_str = attr.to_string();
-    let is_ignored = attr_str.contains("ignore");
-    let custom_name = parse_custom_name(&attr_str).unwrap_or_else(|| fn_name_str.clone());
+    let mut args = StressTestArgs::default();
+    let parser = syn::meta::parser(|meta| args.parse(meta));
+    parse_macro_input!(attr with parser);
 
-    // Generate a unique identifier for the inventory submission
-    let submit_ident = syn::Ident::new(
-        &format!("__STRESS_BENCH_{}", fn_name_str.to_uppercase()),
-        fn_name.span(),
-    );
+    if let Err(err) = validate_signature(&input.sig) {
+        // Keep the function itself so the error is not followed by unrelated
+        // "cannot find function" diagnostics at call sites.
+        let err = err.to_compile_error();
+        return quote! { #input #err }.into();
+    }
 
+    let fn_name = &input.sig.ident;
+    let fn_name_str = fn_name.to_string();
+    let is_ignored = args.ignore;
+    let ignore_reason = option_tokens(args.ignore_reason);
+    let custom_name = args.name.unwrap_or(fn_name_str);
+    let runs = option_tokens(args.runs);
+    let warmup = option_tokens(args.warmup);
+    let timeout_secs = option_tokens(args.timeout_secs);
+    let tags = &args.tags;
+    let group = option_tokens(args.group);
+    let memory_limit = option_tokens(args.memory_limit);
+    let cpus = option_tokens(args.cpus);
+    let open_files = option_tokens(args.open_files);
+    // Spanned to the function name so `line!()` points at the definition
+    // rather than the attribute.
+    let line = quote_spanned! {fn_name.span()=> line!() };
+
+    // The entry lives inside an anonymous const block so that benchmarks with
+    // the same function name never produce colliding static identifiers.
     let expanded = quote! {
         #input
 
-        #[allow(non_upper_case_globals)]
-        #[::cntryl_stress::__private::linkme::distributed_slice(::cntryl_stress::__private::STRESS_BENCHMARKS)]
-        #[linkme(crate = ::cntryl_stress::__private::linkme)]
-        static #submit_ident: ::cntryl_stress::__private::BenchmarkEntry = ::cntryl_stress::__private::BenchmarkEntry {
-            name: #custom_name,
-            func: #fn_name,
-            ignored: #is_ignored,
-            module_path: module_path!(),
+        const _: () = {
+            #[::cntryl_stress::__private::linkme::distributed_slice(::cntryl_stress::__private::STRESS_BENCHMARKS)]
+            #[linkme(crate = ::cntryl_stress::__private::linkme)]
+            static __STRESS_BENCH_ENTRY: ::cntryl_stress::__private::BenchmarkEntry = ::cntryl_stress::__private::BenchmarkEntry {
+                name: #custom_name,
+                func: #fn_name,
+                ignored: #is_ignored,
+                ignore_reason: #ignore_reason,
+                module_path: module_path!(),
+                file: file!(),
+                line: #line,
+                runs: #runs,
+                warmup: #warmup,
+                timeout_secs: #timeout_secs,
+                tags: &[#(#tags),*],
+                group: #group,
+                memory_limit: #memory_limit,
+                cpus: #cpus,
+                open_files: #open_files,
+            };
         };
     };
 
     TokenStream::from(expanded)
 }
 
-fn parse_custom_name(attr: &str) -> Option<String> {
-    // Simple parsing for name = "value"
-    if let Some(start) = attr.find("name") {
-        let rest = &attr[start..];
-        if let Some(eq) = rest.find('=') {
-            let after_eq = &rest[eq + 1..];
-            if let Some(quote_start) = after_eq.find('"') {
-                let after_quote = &after_eq[quote_start + 1..];
-                if let Some(quote_end) = after_quote.find('"') {
-                    return Some(after_quote[..quote_end].to_string());
-                }
+/// Parsed arguments of `#[stress_test(...)]`.
+#[derive(Default)]
+struct StressTestArgs {
+    ignore: bool,
+    ignore_reason: Option<String>,
+    name: Option<String>,
+    runs: Option<usize>,
+    warmup: Option<usize>,
+    timeout_secs: Option<u64>,
+    tags: Vec<String>,
+    group: Option<String>,
+    memory_limit: Option<u64>,
+    cpus: Option<usize>,
+    open_files: Option<u64>,
+}
+
+impl StressTestArgs {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("ignore") {
+            self.ignore = true;
+            if meta.input.peek(Token![=]) {
+                let value: LitStr = meta.value()?.parse()?;
+                self.ignore_reason = Some(value.value());
+            }
+            Ok(())
+        } else if meta.path.is_ident("name") {
+            let value: LitStr = meta.value()?.parse()?;
+            if value.value().is_empty() {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "benchmark name must not be empty",
+                ));
+            }
+            self.name = Some(value.value());
+            Ok(())
+        } else if meta.path.is_ident("tags") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+            for tag in tags {
+                validate_label(&tag, "tag")?;
+                self.tags.push(tag.value());
             }
+            Ok(())
+        } else if meta.path.is_ident("group") {
+            let value: LitStr = meta.value()?.parse()?;
+            validate_label(&value, "group")?;
+            self.group = Some(value.value());
+            Ok(())
+        } else if meta.path.is_ident("runs") {
+            self.runs = Some(parse_positive(&meta, "runs")?);
+            Ok(())
+        } else if meta.path.is_ident("warmup") {
+            let value: LitInt = meta.value()?.parse()?;
+            self.warmup = Some(value.base10_parse()?);
+            Ok(())
+        } else if meta.path.is_ident("timeout") {
+            self.timeout_secs = Some(parse_positive(&meta, "timeout")?);
+            Ok(())
+        } else if meta.path.is_ident("memory") {
+            self.memory_limit = Some(parse_memory(&meta)?);
+            Ok(())
+        } else if meta.path.is_ident("cpus") {
+            self.cpus = Some(parse_positive(&meta, "cpus")?);
+            Ok(())
+        } else if meta.path.is_ident("open_files") {
+            self.open_files = Some(parse_positive(&meta, "open_files")?);
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unsupported stress_test attribute; expected one of `ignore`, `name`, `tags`, `group`, `runs`, `warmup`, `timeout`, `memory`, `cpus`, `open_files`",
+            ))
         }
     }
-    None
+}
+
+/// Tags and groups must be usable as atoms in `--select` expressions.
+fn validate_label(value: &LitStr, kind: &str) -> syn::Result<()> {
+    let label = value.value();
+    let valid = !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            value.span(),
+            format!(
+                "{} must be non-empty and contain only letters, digits, `_`, `-` or `.`",
+                kind
+            ),
+        ))
+    }
+}
+
+/// Parse `key = N` where `N` must be a non-zero integer.
+fn parse_positive<N>(meta: &ParseNestedMeta, key: &str) -> syn::Result<N>
+where
+    N: std::str::FromStr + PartialEq + From<u8>,
+    N::Err: std::fmt::Display,
+{
+    let value: LitInt = meta.value()?.parse()?;
+    let n: N = value.base10_parse()?;
+    if n == N::from(0) {
+        return Err(syn::Error::new(
+            value.span(),
+            format!("`{}` must be greater than zero", key),
+        ));
+    }
+    Ok(n)
+}
+
+/// Parse `memory = "512M"` (binary suffixes K/M/G/T) or `memory = BYTES`.
+fn parse_memory(meta: &ParseNestedMeta) -> syn::Result<u64> {
+    let value = meta.value()?;
+    if value.peek(LitInt) {
+        let lit: LitInt = value.parse()?;
+        return lit.base10_parse();
+    }
+
+    let lit: LitStr = value.parse()?;
+    let text = lit.value().trim().to_ascii_uppercase();
+    let digits = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let shift = match text[digits..]
+        .trim()
+        .trim_end_matches("IB")
+        .trim_end_matches('B')
+    {
+        "" => 0,
+        "K" => 10,
+        "M" => 20,
+        "G" => 30,
+        "T" => 40,
+        _ => 64,
+    };
+    let bytes = text[..digits]
+        .parse::<u64>()
+        .ok()
+        .filter(|&n| n > 0 && shift < 64)
+        .and_then(|n| n.checked_mul(1 << shift));
+    match bytes {
+        Some(bytes) => Ok(bytes),
+        None => Err(syn::Error::new(
+            lit.span(),
+            "`memory` must be a size such as \"512M\" or \"2G\"",
+        )),
+    }
+}
+
+/// Render an optional value as an `Option` expression.
+fn option_tokens<T: quote::ToTokens>(value: Option<T>) -> proc_macro2::TokenStream {
+    match value {
+        Some(v) => quote! { ::core::option::Option::Some(#v) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+/// Check that the annotated function can be registered as `fn(&mut StressContext)`.
+fn validate_signature(sig: &Signature) -> syn::Result<()> {
+    if let Some(token) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            token,
+            "#[stress_test] functions cannot be async; block on the future inside `ctx.measure`",
+        ));
+    }
+    if let Some(token) = &sig.unsafety {
+        return Err(syn::Error::new_spanned(
+            token,
+            "#[stress_test] functions cannot be unsafe",
+        ));
+    }
+    if let Some(abi) = &sig.abi {
+        return Err(syn::Error::new_spanned(
+            abi,
+            "#[stress_test] functions cannot declare an extern ABI",
+        ));
+    }
+    if let Some(param) = sig
+        .generics
+        .params
+        .iter()
+        .find(|p| !matches!(p, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new_spanned(
+            param,
+            "#[stress_test] functions cannot have type or const generic parameters",
+        ));
+    }
+    if let Some(where_clause) = &sig.generics.where_clause {
+        return Err(syn::Error::new_spanned(
+            where_clause,
+            "#[stress_test] functions cannot have a where clause",
+        ));
+    }
+    if let Some(variadic) = &sig.variadic {
+        return Err(syn::Error::new_spanned(
+            variadic,
+            "#[stress_test] functions cannot be variadic",
+        ));
+    }
+    if let ReturnType::Type(_, ty) = &sig.output {
+        let is_unit = matches!(&**ty, Type::Tuple(t) if t.elems.is_empty());
+        if !is_unit {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "#[stress_test] functions must not return a value",
+            ));
+        }
+    }
+
+    let mut inputs = sig.inputs.iter();
+    let first = match inputs.next() {
+        Some(arg) => arg,
+        None => return Err(syn::Error::new_spanned(
+            &sig.ident,
+            "#[stress_test] functions must take exactly one argument: `ctx: &mut StressContext`",
+        )),
+    };
+    if let Some(extra) = inputs.next() {
+        return Err(syn::Error::new_spanned(
+            extra,
+            "#[stress_test] functions must take exactly one argument: `ctx: &mut StressContext`",
+        ));
+    }
+
+    let pat_type = match first {
+        FnArg::Receiver(receiver) => {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                "#[stress_test] cannot be applied to methods; use a free function taking `&mut StressContext`",
+            ))
+        }
+        FnArg::Typed(pat_type) => pat_type,
+    };
+
+    let expected = "expected `&mut StressContext`";
+    match &*pat_type.ty {
+        Type::Reference(reference) if reference.mutability.is_some() => {
+            if is_context_type(&reference.elem) {
+                Ok(())
+            } else {
+                Err(syn::Error::new_spanned(
+                    &reference.elem,
+                    format!("unsupported argument type for #[stress_test]: {}", expected),
+                ))
+            }
+        }
+        Type::Reference(reference) => Err(syn::Error::new_spanned(
+            reference,
+            format!(
+                "#[stress_test] argument must be a mutable reference: {}",
+                expected
+            ),
+        )),
+        other => Err(syn::Error::new_spanned(
+            other,
+            format!("unsupported argument type for #[stress_test]: {}", expected),
+        )),
+    }
+}
+
+/// Whether `ty` names the benchmark context (`StressContext` or the deprecated `BenchContext`).
+fn is_context_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|seg| {
+                seg.arguments.is_none()
+                    && (seg.ident == "StressContext" || seg.ident == "BenchContext")
+            })
+            .unwrap_or(false),
+        Type::Paren(paren) => is_context_type(&paren.elem),
+        Type::Group(group) => is_context_type(&group.elem),
+        _ => false,
+    }
 }
 
 /// Generate the main function for running stress benchmarks.
@@ -117,6 +433,7 @@ fn parse_custom_name(attr: &str) -> Option<String> {
 /// - `--quiet` / `-q`: Quiet mode
 /// - `--include-ignored`: Include ignored benchmarks
 /// - `--list`: List benchmarks without running
+/// - `--format <pretty|terse|json>`: Output format for `--list`
 /// - `--output-dir <PATH>`: Output directory for JSON results
 /// - `--baseline <PATH>`: Baseline JSON for regression comparison
 /// - `--threshold <FLOAT>`: Regression threshold (default: 0.05)
@@ -0,0 +1,7 @@
+//! Compile-time diagnostics of `#[stress_test]`.
+
+#[test]
+fn should_reject_invalid_stress_tests_at_compile_time() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
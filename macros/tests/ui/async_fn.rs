@@ -0,0 +1,6 @@
+use cntryl_stress::{stress_test, StressContext};
+
+#[stress_test]
+async fn scan(_ctx: &mut StressContext) {}
+
+fn main() {}
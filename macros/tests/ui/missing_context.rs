@@ -0,0 +1,6 @@
+use cntryl_stress::stress_test;
+
+#[stress_test]
+fn scan() {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use cntryl_stress::{stress_test, StressContext};
+
+#[stress_test]
+fn scan(_ctx: &mut StressContext) -> u64 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use cntryl_stress::{stress_test, StressContext};
+
+#[stress_test]
+fn scan(ctx: &StressContext) {
+    let _ = ctx;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use cntryl_stress::stress_test;
+
+#[stress_test]
+fn scan(ctx: &mut String) {
+    ctx.clear();
+}
+
+fn main() {}
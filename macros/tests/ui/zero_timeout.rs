@@ -0,0 +1,6 @@
+use cntryl_stress::stress_test;
+
+#[stress_test(timeout = 0)]
+fn scan(_ctx: &mut cntryl_stress::StressContext) {}
+
+fn main() {}